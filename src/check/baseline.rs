@@ -0,0 +1,106 @@
+use crate::check::{
+    report::Report,
+    utils::{InvalidItem, ValidatorKind},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The findings that existed when a project adopted scopelint, which are not reported again.
+///
+/// Findings are matched by their kind, file, and name rather than their location, so they stay
+/// matched when code around them moves.
+pub struct Baseline {
+    findings: Vec<Entry>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Entry {
+    kind: ValidatorKind,
+    file: String,
+    name: String,
+}
+
+impl Entry {
+    fn of(item: &InvalidItem) -> Self {
+        Self { kind: item.kind(), file: item.file().to_string(), name: item.text().to_string() }
+    }
+}
+
+impl Baseline {
+    #[must_use]
+    /// Creates a baseline of the given findings.
+    pub fn new(items: &[InvalidItem]) -> Self {
+        let mut findings: Vec<Entry> = items.iter().map(Entry::of).collect();
+        findings.sort();
+        Self { findings }
+    }
+
+    /// Loads the baseline from the JSON file at `path`.
+    /// # Errors
+    /// Errors if the file cannot be read or is not a valid baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Writes the baseline to `path` as JSON.
+    /// # Errors
+    /// Errors if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+    }
+
+    /// Removes the findings in the baseline from `report`, and returns how many were removed. Each
+    /// entry removes at most one finding, so new findings with the same name are still reported.
+    pub fn remove_from(&self, report: &mut Report) -> usize {
+        let mut remaining: BTreeMap<&Entry, usize> = BTreeMap::new();
+        for entry in &self.findings {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        let before = report.count();
+        report.retain(|item| match remaining.get_mut(&Entry::of(item)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        });
+        before - report.count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_from() {
+        let item = |kind, name: &str, line| {
+            InvalidItem::new(kind, "./src/Counter.sol".to_string(), name.to_string(), line, 5)
+        };
+        let baseline = Baseline::new(&[
+            item(ValidatorKind::Constant, "bad_constant", 4),
+            item(ValidatorKind::Src, "increment", 10),
+        ]);
+
+        // Baselined findings are removed even if they moved, but new findings are kept, including
+        // a second finding with a baselined name.
+        let mut report = Report::default();
+        report.add_file(vec![
+            item(ValidatorKind::Constant, "bad_constant", 6),
+            item(ValidatorKind::Constant, "bad_constant", 7),
+            item(ValidatorKind::Constant, "increment", 8),
+            item(ValidatorKind::Src, "increment", 12),
+        ]);
+        assert_eq!(baseline.remove_from(&mut report), 2);
+        assert_eq!(
+            report.invalid_items(),
+            [
+                item(ValidatorKind::Constant, "bad_constant", 7),
+                item(ValidatorKind::Constant, "increment", 8)
+            ]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+/// The file the cache is stored in, relative to the project root.
+pub const CACHE_PATH: &str = "./.scopelint-cache";
+
+// The files that make up the configuration, so any change to them invalidates the cache.
+const CONFIG_PATHS: [&str; 3] = ["./foundry.toml", "./.scopelint.toml", "./.scopelintignore"];
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The files that passed all convention checks in a previous run, mapped to their content hash.
+///
+/// Only clean files are cached, so files with findings are always re-validated and reported.
+pub struct Cache {
+    version: String,
+    config: u64,
+    files: BTreeMap<PathBuf, u64>,
+}
+
+impl Cache {
+    // Creates an empty cache for the current scopelint version and project configuration, which
+    // includes the `.scopelint.toml` files of subdirectories at `dir_config_paths`.
+    fn new(dir_config_paths: &[PathBuf]) -> Self {
+        let config = config_hash(dir_config_paths);
+        Self { version: version(), config, files: BTreeMap::new() }
+    }
+
+    #[must_use]
+    /// Loads the cache from [`CACHE_PATH`]. An empty cache is returned if the file is missing or
+    /// invalid, or was written by a different scopelint version or for a different configuration,
+    /// including the `.scopelint.toml` files of subdirectories at `dir_config_paths`.
+    pub fn load(dir_config_paths: &[PathBuf]) -> Self {
+        let cache = Self::new(dir_config_paths);
+        let Ok(content) = fs::read_to_string(CACHE_PATH) else { return cache };
+        match serde_json::from_str::<Self>(&content) {
+            Ok(saved) if saved.version == cache.version && saved.config == cache.config => saved,
+            _ => cache,
+        }
+    }
+
+    /// Writes the cache to [`CACHE_PATH`].
+    /// # Errors
+    /// Errors if the cache file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        // Write to a temporary file first, so concurrent runs never read a partially written cache.
+        let tmp_path = format!("{CACHE_PATH}.{}", std::process::id());
+        fs::write(&tmp_path, serde_json::to_string(self)?)?;
+        fs::rename(tmp_path, CACHE_PATH)
+    }
+
+    #[must_use]
+    /// Returns `true` if `file` passed all checks when its contents had the given `hash`.
+    pub fn is_clean(&self, file: &Path, hash: u64) -> bool {
+        self.files.get(file) == Some(&hash)
+    }
+
+    /// Records whether `file` with the given content `hash` passed all checks.
+    pub fn update(&mut self, file: &Path, hash: u64, clean: bool) {
+        if clean {
+            self.files.insert(file.to_path_buf(), hash);
+        } else {
+            self.files.remove(file);
+        }
+    }
+}
+
+#[must_use]
+/// Returns the hash of a file's contents. The hash is only stable for a given build of scopelint,
+/// which is fine since the cache is invalidated whenever the version changes.
+pub fn hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+// Returns the hash of all configuration files, where missing files are treated as empty. The paths
+// of subdirectory configs are hashed too, so adding or removing one changes the hash.
+fn config_hash(dir_config_paths: &[PathBuf]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for path in CONFIG_PATHS {
+        fs::read_to_string(path).unwrap_or_default().hash(&mut hasher);
+    }
+    for path in dir_config_paths {
+        path.hash(&mut hasher);
+        fs::read_to_string(path).unwrap_or_default().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let mut cache = Cache::new(&[]);
+        let file = Path::new("./src/Counter.sol");
+        let content_hash = hash("contract Counter {}");
+        assert!(!cache.is_clean(file, content_hash));
+
+        cache.update(file, content_hash, true);
+        assert!(cache.is_clean(file, content_hash));
+        // Changed contents are not clean.
+        assert!(!cache.is_clean(file, hash("contract Counter { }")));
+
+        cache.update(file, content_hash, false);
+        assert!(!cache.is_clean(file, content_hash));
+    }
+}
@@ -0,0 +1,88 @@
+use std::{error::Error, fmt, io};
+
+/// The reasons `scopelint check` can fail.
+#[derive(Debug)]
+pub enum CheckError {
+    /// The `foundry.toml` or `.scopelint.toml` configuration could not be loaded.
+    Config(Box<dyn Error>),
+    /// One or more files do not follow the naming conventions, or could not be parsed.
+    ConventionsFailed,
+    /// One or more Solidity or TOML files are not formatted.
+    FormattingFailed,
+    /// No Solidity files were found to check, e.g. because of a mistyped path.
+    NoFiles,
+    /// More than one check failed, e.g. both the conventions and the formatting.
+    Multiple(Vec<Self>),
+    /// A file could not be read or written, or `forge` could not be run.
+    Io(io::Error),
+}
+
+impl CheckError {
+    #[must_use]
+    /// Returns the process exit code for the error. The code is a set of bits so scripts can tell
+    /// which checks failed: `1` for conventions, `2` for formatting, and `4` if the checks could
+    /// not be run at all. For example, `3` means both the conventions and the formatting checks
+    /// failed.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::ConventionsFailed => 1,
+            Self::FormattingFailed => 2,
+            Self::Config(_) | Self::NoFiles | Self::Io(_) => 4,
+            Self::Multiple(errors) => errors.iter().fold(0, |code, err| code | err.exit_code()),
+        }
+    }
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Config(err) => write!(f, "{err}"),
+            Self::ConventionsFailed => write!(f, "Convention checks failed"),
+            Self::FormattingFailed => write!(f, "Formatting validation failed"),
+            Self::NoFiles => write!(f, "No Solidity files found to check"),
+            Self::Multiple(_) => write!(f, "One or more checks failed, review above output"),
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+        }
+    }
+}
+
+impl Error for CheckError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Config(err) => Some(err.as_ref()),
+            Self::Io(err) => Some(err),
+            Self::ConventionsFailed |
+            Self::FormattingFailed |
+            Self::NoFiles |
+            Self::Multiple(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for CheckError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(CheckError::ConventionsFailed.exit_code(), 1);
+        assert_eq!(CheckError::FormattingFailed.exit_code(), 2);
+        assert_eq!(CheckError::NoFiles.exit_code(), 4);
+        assert_eq!(CheckError::Io(io::Error::other("forge not found")).exit_code(), 4);
+
+        let both =
+            CheckError::Multiple(vec![CheckError::ConventionsFailed, CheckError::FormattingFailed]);
+        assert_eq!(both.exit_code(), 3);
+        let io = CheckError::Multiple(vec![
+            CheckError::ConventionsFailed,
+            CheckError::Io(io::Error::other("forge not found")),
+        ]);
+        assert_eq!(io.exit_code(), 5);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    check::{
+        utils::{offset_to_line_col, FileKind, InvalidItem, ValidatorKind},
+        validators::{constant_names, line_length, test_names},
+    },
+    config::Config,
+};
+use regex::Regex;
+use solang_parser::{
+    lexer::{Lexer, Token},
+    pt::Comment,
+};
+use std::{path::Path, sync::LazyLock};
+
+// Matches the version requirement of a `pragma solidity` directive, e.g. `^0.8.17`.
+static RE_PRAGMA_SOLIDITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"pragma\s+solidity\s+([^;]+);").unwrap());
+
+#[must_use]
+/// Returns the Solidity version requirement declared by `pragma solidity` in `content`, e.g.
+/// `^0.8.17`, or `None` if there is no such pragma.
+pub fn pragma_version(content: &str) -> Option<&str> {
+    let version = RE_PRAGMA_SOLIDITY.captures(content)?.get(1)?;
+    Some(version.as_str().trim())
+}
+
+#[must_use]
+/// Runs the constant and test name checks on a source that could not be parsed, using only its
+/// tokens, and the line length check, which does not need them.
+///
+/// Returns the findings along with the comments in the source, so findings on disabled lines can
+/// still be removed.
+///
+/// Without a parse tree these checks are lenient: constants and immutables are the names assigned
+/// or declared right after the `constant` or `immutable` keyword, and tests are functions whose
+/// name starts with `test`. Constants are not checked when a [`Config::constant_name_rule`] is set,
+/// since rules need the variable definition.
+pub fn validate(
+    file: &Path,
+    content: &str,
+    file_kind: Option<FileKind>,
+    config: &Config,
+) -> (Vec<InvalidItem>, Vec<Comment>) {
+    let mut comments = Vec::new();
+    let mut errors = Vec::new();
+    // Lexing continues after invalid tokens, recording them in `errors`, which are already
+    // reported by the parse error.
+    let tokens: Vec<(usize, Token)> = Lexer::new(content, 0, &mut comments, &mut errors)
+        .filter_map(Result::ok)
+        .map(|(start, token, _end)| (start, token))
+        .collect();
+
+    let mut invalid_items = config
+        .max_line_length
+        .map_or_else(Vec::new, |limit| line_length::validate_lines(file, content, limit));
+    let file = file.display().to_string();
+    if config.constant_name_rule.is_none() {
+        invalid_items.extend(validate_constants(&file, content, &tokens, config));
+    }
+    if file_kind == Some(FileKind::Test) {
+        invalid_items.extend(validate_tests(&file, content, &tokens, config));
+    }
+    (invalid_items, comments)
+}
+
+fn validate_constants(
+    file: &str,
+    content: &str,
+    tokens: &[(usize, Token)],
+    config: &Config,
+) -> Vec<InvalidItem> {
+    let mut invalid_items = Vec::new();
+    // The pattern for the declaration being read, and the identifier right before this token.
+    let mut pattern: Option<&Regex> = None;
+    let mut last_identifier: Option<(usize, &str)> = None;
+    for (start, token) in tokens {
+        match token {
+            Token::Constant => pattern = Some(constant_names::constant_name_pattern(config)),
+            Token::Immutable => pattern = Some(constant_names::immutable_name_pattern(config)),
+            Token::Identifier(name) => {
+                last_identifier = Some((*start, name));
+                continue
+            }
+            Token::Assign | Token::Semicolon => {
+                if let (Some(pattern), Some((start, name))) = (pattern.take(), last_identifier) {
+                    if !pattern.is_match(name) {
+                        let (line, col) = offset_to_line_col(content, start);
+                        let (kind, text) = (ValidatorKind::Constant, name.to_string());
+                        invalid_items.push(InvalidItem::new(kind, file.into(), text, line, col));
+                    }
+                }
+            }
+            // A parameter list or body means this was not a variable declaration, e.g. the
+            // `constant` state mutability of functions in old Solidity versions.
+            Token::OpenParenthesis | Token::OpenCurlyBrace => pattern = None,
+            _ => (),
+        }
+        last_identifier = None;
+    }
+    invalid_items
+}
+
+fn validate_tests(
+    file: &str,
+    content: &str,
+    tokens: &[(usize, Token)],
+    config: &Config,
+) -> Vec<InvalidItem> {
+    let mut invalid_items = Vec::new();
+    for (i, window) in tokens.windows(2).enumerate() {
+        let [(_, Token::Function), (start, Token::Identifier(name))] = window else { continue };
+        if !name.starts_with("test") {
+            continue
+        }
+
+        // The visibility is among the tokens up to the body, or the `;` of functions without one.
+        let is_internal_or_private = tokens[i + 2..]
+            .iter()
+            .take_while(|(_, token)| !matches!(token, Token::OpenCurlyBrace | Token::Semicolon))
+            .any(|(_, token)| matches!(token, Token::Internal | Token::Private));
+        let kind = if is_internal_or_private {
+            ValidatorKind::TestVisibility
+        } else if !test_names::is_valid_test_name(name, config) {
+            ValidatorKind::Test
+        } else {
+            continue
+        };
+        let (line, col) = offset_to_line_col(content, *start);
+        invalid_items.push(InvalidItem::new(kind, file.into(), name.to_string(), line, col));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pragma_version() {
+        assert_eq!(pragma_version("pragma solidity ^0.8.17;\ncontract C {}"), Some("^0.8.17"));
+        assert_eq!(pragma_version("pragma solidity >=0.8.0 <0.9.0 ;"), Some(">=0.8.0 <0.9.0"));
+        assert_eq!(pragma_version("pragma abicoder v2;\ncontract C {}"), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            pragma solidity ^0.8.17;
+
+            contract CounterTest {
+                uint256 public constant GOOD_CONSTANT = 1;
+                uint256 constant private bad_constant = 1 # 2;
+                address immutable badImmutable;
+                uint256 number;
+
+                function setUp() public {}
+                function test_Increment() public {}
+                function testIncrement() public {}
+                function test_Helper() internal returns (uint256) {}
+                function total() public view returns (uint256) {}
+            }
+        ";
+        let config = Config::default();
+        let file = Path::new("./test/Counter.t.sol");
+        let findings = |file_kind| -> Vec<String> {
+            let (items, _comments) = validate(file, content, file_kind, &config);
+            items.iter().map(InvalidItem::description).collect()
+        };
+
+        let expected = vec![
+            "Invalid constant or immutable name in ./test/Counter.t.sol:6:42: bad_constant",
+            "Invalid constant or immutable name in ./test/Counter.t.sol:7:35: badImmutable",
+            "Invalid test name in ./test/Counter.t.sol:12:26: testIncrement",
+            "Non-public test function in ./test/Counter.t.sol:13:26: test_Helper",
+        ];
+        assert_eq!(findings(Some(FileKind::Test)), expected);
+
+        // Test names are only checked in test files.
+        assert_eq!(findings(Some(FileKind::Src)), expected[..2]);
+
+        // Long lines are checked too, when enabled.
+        let config = Config { max_line_length: Some(60), ..Config::default() };
+        let (items, _comments) = validate(file, content, Some(FileKind::Test), &config);
+        let long_lines: Vec<String> = items
+            .iter()
+            .filter(|item| item.kind() == ValidatorKind::LongLine)
+            .map(InvalidItem::description)
+            .collect();
+        let expected = vec![
+            "Line too long in ./test/Counter.t.sol:6:61: 62 characters long, over the limit of 60",
+            "Line too long in ./test/Counter.t.sol:13:61: 68 characters long, over the limit of 60",
+            "Line too long in ./test/Counter.t.sol:14:61: 65 characters long, over the limit of 60",
+        ];
+        assert_eq!(long_lines, expected);
+    }
+}
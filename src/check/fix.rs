@@ -0,0 +1,269 @@
+use crate::{
+    check::{
+        utils::{offset_to_line, parse, FileKind, Parsed},
+        validators::constant_names::name_pattern,
+    },
+    config::Config,
+};
+use regex::Regex;
+use solang_parser::{
+    lexer::{Lexer, Token},
+    pt::{ContractPart, SourceUnitPart, VariableAttribute, VariableDefinition, Visibility},
+};
+use std::{collections::HashSet, fs, io, path::PathBuf};
+
+/// A rename of an invalid constant or immutable name to a valid one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    /// File name.
+    pub file: String,
+    /// Line number of the variable definition.
+    pub line: usize,
+    /// The current, invalid, name.
+    pub old_name: String,
+    /// The name that will replace it.
+    pub new_name: String,
+}
+
+/// Fixes the invalid constant and immutable names in `files`, writing the changes back to disk.
+///
+/// With `dry_run`, no files are changed, but the renames that would be made are still returned.
+/// Files that cannot be parsed are skipped, since they are reported by the validation instead.
+/// # Errors
+/// Errors if a file cannot be read or written.
+pub fn fix_files(files: &[PathBuf], config: &Config, dry_run: bool) -> io::Result<Vec<Rename>> {
+    let mut renames = Vec::new();
+    for file in files {
+        let content = fs::read_to_string(file)?;
+        let Ok((pt, comments)) = parse(&content) else { continue };
+
+        let kind = FileKind::of(file, &config.paths);
+        let config = config.for_file(file);
+        let file_renames =
+            plan(&Parsed { file, kind, content: &content, pt: &pt, comments: &comments, config });
+        if !file_renames.is_empty() {
+            if !dry_run {
+                fs::write(file, apply(&content, &file_renames))?;
+            }
+            renames.extend(file_renames);
+        }
+    }
+    Ok(renames)
+}
+
+#[must_use]
+/// Returns the renames that fix the invalid constant and immutable names in a file.
+///
+/// Names are only renamed when the conversion to `SCREAMING_SNAKE_CASE` is unambiguous, the new
+/// name is not already used in the file, and the variable is a `private` contract member. Other
+/// variables can be used by other files, through imports or inheritance, which are not updated.
+pub fn plan(parsed: &Parsed) -> Vec<Rename> {
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let identifiers = identifiers(content);
+
+    let mut variables: Vec<&VariableDefinition> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::VariableDefinition(v) => variables.push(v),
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::VariableDefinition(v) = el {
+                        variables.push(v);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mut renames: Vec<Rename> = Vec::new();
+    for v in variables {
+        let Some(name) = v.name.as_ref().map(|name| &name.name) else { continue };
+        let Some(pattern) = fixable_pattern(v, config) else { continue };
+        if renames.iter().any(|r| &r.old_name == name) {
+            continue
+        }
+
+        let new_name = to_screaming_snake_case(name)
+            .filter(|new_name| pattern.is_match(new_name))
+            .filter(|new_name| !identifiers.contains(new_name))
+            .filter(|new_name| renames.iter().all(|r| &r.new_name != new_name));
+        if let Some(new_name) = new_name {
+            renames.push(Rename {
+                file: file.display().to_string(),
+                line: offset_to_line(content, v.loc.start()),
+                old_name: name.clone(),
+                new_name,
+            });
+        }
+    }
+    renames
+}
+
+#[must_use]
+/// Applies the `renames` to every occurrence of the old names in `content`. Comments and strings
+/// are left unchanged.
+pub fn apply(content: &str, renames: &[Rename]) -> String {
+    let mut fixed = String::with_capacity(content.len());
+    let mut last_end = 0;
+    for (start, name, end) in identifier_spans(content) {
+        if let Some(rename) = renames.iter().find(|r| r.old_name == name) {
+            fixed.push_str(&content[last_end..start]);
+            fixed.push_str(&rename.new_name);
+            last_end = end;
+        }
+    }
+    fixed.push_str(&content[last_end..]);
+    fixed
+}
+
+// Returns the pattern an invalid, private constant or immutable must be renamed to match, or `None`
+// if `v` cannot be fixed.
+fn fixable_pattern<'a>(v: &VariableDefinition, config: &'a Config) -> Option<&'a Regex> {
+    // The name a custom rule accepts is unknown, so there is nothing to rename to.
+    if config.constant_name_rule.is_some() {
+        return None
+    }
+    let pattern = name_pattern(v, config)?;
+    // Only contract members can be private, so this also skips file-level constants.
+    let is_private =
+        v.attrs.iter().any(|a| matches!(a, VariableAttribute::Visibility(Visibility::Private(_))));
+    let name = &v.name.as_ref().unwrap().name;
+    (is_private && !pattern.is_match(name)).then_some(pattern)
+}
+
+// Converts a `camelCase` or `snake_case` name to `SCREAMING_SNAKE_CASE`. Returns `None` when the
+// word boundaries are ambiguous, e.g. the acronym in `maxURLLength`.
+fn to_screaming_snake_case(name: &str) -> Option<String> {
+    if name.contains('$') {
+        return None
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    for segment in name.split('_') {
+        // Segments without lowercase letters, including the empty segments from leading or
+        // trailing underscores, are kept as is.
+        if !segment.chars().any(|c| c.is_ascii_lowercase()) {
+            words.push(segment.to_string());
+            continue
+        }
+
+        // Otherwise each uppercase letter starts a new word.
+        let mut word = String::new();
+        let mut prev_is_upper = false;
+        for c in segment.chars() {
+            let is_upper = c.is_ascii_uppercase();
+            if is_upper && prev_is_upper {
+                return None
+            }
+            if is_upper && !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            word.push(c.to_ascii_uppercase());
+            prev_is_upper = is_upper;
+        }
+        words.push(word);
+    }
+    Some(words.join("_"))
+}
+
+// Returns the start offset, name, and end offset of each identifier in `content`.
+fn identifier_spans(content: &str) -> Vec<(usize, String, usize)> {
+    let mut comments = Vec::new();
+    let mut errors = Vec::new();
+    Lexer::new(content, 0, &mut comments, &mut errors)
+        .filter_map(|token| match token {
+            Ok((start, Token::Identifier(name), end)) => Some((start, name.to_string(), end)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn identifiers(content: &str) -> HashSet<String> {
+    identifier_spans(content).into_iter().map(|(_, name, _)| name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_to_screaming_snake_case() {
+        let fixable_names = [
+            ("myConstant", "MY_CONSTANT"),
+            ("maxUint256", "MAX_UINT256"),
+            ("uint256Max", "UINT256_MAX"),
+            ("bad_constant", "BAD_CONSTANT"),
+            ("VERY_bad_constant", "VERY_BAD_CONSTANT"),
+            ("_myConstant_", "_MY_CONSTANT_"),
+            ("ConstantValue", "CONSTANT_VALUE"),
+        ];
+
+        let ambiguous_names = ["maxURLLength", "VARIABLe", "VARIABLE_nAME", "$myConstant"];
+
+        for (name, expected) in fixable_names {
+            assert_eq!(to_screaming_snake_case(name).as_deref(), Some(expected), "{name}");
+        }
+
+        for name in ambiguous_names {
+            assert_eq!(to_screaming_snake_case(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_plan_and_apply() {
+        let content = r#"
+            uint256 constant fileLevelMax = 5;
+
+            contract MyContract {
+                uint256 private constant maxSupply = 100;
+                uint256 private immutable startTime;
+                uint256 public constant publicConstant = 1;
+                uint256 internal constant internalConstant = 1;
+                uint256 constant defaultConstant = 1;
+                uint256 private constant maxURLLength = 2;
+                uint256 private constant alreadyTaken = 3;
+                uint256 constant ALREADY_TAKEN_ = 4;
+                uint256 ALREADY_TAKEN;
+
+                constructor() {
+                    // The maxSupply in this comment is not renamed.
+                    startTime = block.timestamp + maxSupply;
+                }
+
+                function supply() external pure returns (string memory) {
+                    return "maxSupply";
+                }
+            }
+        "#;
+        let (pt, comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let config = Config::default();
+        let file = Path::new("./src/MyContract.sol");
+        let renames = plan(&Parsed {
+            file,
+            kind: Some(FileKind::Src),
+            content,
+            pt: &pt,
+            comments: &comments,
+            config: &config,
+        });
+
+        let names: Vec<(&str, &str, usize)> =
+            renames.iter().map(|r| (r.old_name.as_str(), r.new_name.as_str(), r.line)).collect();
+        assert_eq!(names, vec![("maxSupply", "MAX_SUPPLY", 5), ("startTime", "START_TIME", 6)]);
+
+        let fixed = apply(content, &renames);
+        assert!(fixed.contains("uint256 private constant MAX_SUPPLY = 100;"));
+        assert!(fixed.contains("START_TIME = block.timestamp + MAX_SUPPLY;"));
+        assert!(fixed.contains("// The maxSupply in this comment is not renamed."));
+        assert!(fixed.contains(r#"return "maxSupply";"#));
+        assert!(fixed.contains("uint256 public constant publicConstant = 1;"));
+
+        // Other files can use non-private and file-level constants, so they are not renamed.
+        assert!(fixed.contains("uint256 internal constant internalConstant = 1;"));
+        assert!(fixed.contains("uint256 constant defaultConstant = 1;"));
+        assert!(fixed.contains("uint256 constant fileLevelMax = 5;"));
+    }
+}
@@ -1,10 +1,47 @@
+use crate::config::{CheckOpts, Config, OutputFormat, Verbosity, CONFIG_FILE};
 use colored::Colorize;
-use std::{error::Error, ffi::OsStr, fs};
-use walkdir::WalkDir;
+use error::CheckError;
+use globset::GlobBuilder;
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+use solang_parser::{
+    diagnostics::Diagnostic,
+    pt::{Comment, Loc, SourceUnit},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Contains the baseline of findings that are not reported again.
+pub mod baseline;
+
+/// Contains the on-disk cache of files that passed all convention checks.
+pub mod cache;
+
+/// Contains the error type returned when a check fails.
+pub mod error;
+
+/// Contains the lenient checks that still run on files that cannot be parsed.
+pub mod fallback;
+
+/// Contains the logic to automatically fix invalid names.
+pub mod fix;
 
 /// Contains all the types and methods to generate a report of all the invalid items found.
 pub mod report;
 
+/// Contains the index of the contracts in the project, used by checks that resolve names across
+/// files.
+pub mod symbols;
+
+/// Contains the per-phase timings printed with `--timings`.
+pub mod timings;
+
 /// Contains helper methods, traits, etc. used by the validators and report generation.
 pub mod utils;
 
@@ -12,19 +49,45 @@ pub mod utils;
 pub mod validators;
 
 /// Validates the code formatting, and print details on any conventions that are not being followed.
+/// With `--stdin`, only the conventions of the source read from stdin are validated.
 /// # Errors
-/// Returns an error if the formatting or convention validations fail.
-pub fn run(taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>> {
+/// Returns an error if the formatting or convention validations fail. When both fail, the errors
+/// are returned together as [`CheckError::Multiple`].
+///
+/// With `--timings`, the time spent in each phase of the check is printed at the end.
+pub fn run(opts: &CheckOpts, taplo_opts: taplo::formatter::Options) -> Result<(), CheckError> {
+    let mut timings = opts.timings.then(timings::Timings::default);
+    let result = run_checks(opts, taplo_opts, timings.as_mut());
+    if let Some(timings) = timings {
+        eprint!("{}:\n{timings}", "timings".bold());
+    }
+    result
+}
+
+fn run_checks(
+    opts: &CheckOpts,
+    taplo_opts: taplo::formatter::Options,
+    mut timings: Option<&mut timings::Timings>,
+) -> Result<(), CheckError> {
+    if opts.stdin {
+        return validate_stdin(opts, timings)
+    }
+
     // We run the formatting check separate to just indicate whether or not the user needs to format
     // the codebase, whereas the other validators return granular information about what to fix
     // since they currently can't be fixed automatically.
-    let valid_names = validate_conventions();
-    let valid_fmt = validators::formatting::validate(taplo_opts);
+    let valid_names = validate_conventions(opts, timings.as_deref_mut());
+    if opts.count || (opts.fail_fast && valid_names.is_err()) {
+        return valid_names
+    }
+    let valid_fmt = timings::time(timings, timings::Phase::Formatting, || {
+        validators::formatting::validate(opts, taplo_opts)
+    });
 
-    if valid_names.is_ok() && valid_fmt.is_ok() {
-        Ok(())
-    } else {
-        Err("One or more checks failed, review above output".into())
+    match (valid_names, valid_fmt) {
+        (Ok(()), Ok(())) => Ok(()),
+        (Err(err), Ok(())) | (Ok(()), Err(err)) => Err(err),
+        (Err(names_err), Err(fmt_err)) => Err(CheckError::Multiple(vec![names_err, fmt_err])),
     }
 }
 
@@ -32,48 +95,872 @@ pub fn run(taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>>
 // ======== Validations ========
 // =============================
 
-fn validate_conventions() -> Result<(), Box<dyn Error>> {
-    let paths = ["./src", "./script", "./test"];
-    let results = validate(paths)?;
+fn validate_conventions(
+    opts: &CheckOpts,
+    mut timings: Option<&mut timings::Timings>,
+) -> Result<(), CheckError> {
+    let mut config = load_config(opts)?;
+    let found = timings::time(timings.as_deref_mut(), timings::Phase::Walk, || {
+        find_checked_files(&config, opts)
+    })?;
+    // Vyper files are expected in mixed projects, but are noted so their findings are not assumed
+    // to be clean.
+    if found.vyper_files > 0 && opts.verbosity() != Verbosity::Quiet {
+        let count = found.vyper_files;
+        let files = if count == 1 { "file" } else { "files" };
+        eprintln!("{}: Skipped {count} Vyper {files}, only Solidity is supported", "note".bold());
+    }
+    // Files outside the project directories, e.g. passed as paths, cannot be categorized, so they
+    // are noted to explain why most checks do not apply to them.
+    let count =
+        found.solidity_files.iter().filter(|file| is_outside_project(file, &config)).count();
+    if count > 0 && opts.verbosity() != Verbosity::Quiet {
+        let files = if count == 1 { "file" } else { "files" };
+        let dirs = "outside the src, script, and test directories";
+        eprintln!(
+            "{}: Only the checks for all files apply to {count} Solidity {files} {dirs}",
+            "note".bold()
+        );
+    }
+    let files = found.solidity_files;
+    // Finding no files usually means a mistyped path or running outside of a project, which would
+    // otherwise pass silently.
+    if files.is_empty() && !opts.allow_empty {
+        let hint = "pass `--allow-empty` if this is expected";
+        eprintln!("{}: No Solidity files found to check, {hint}", "error".bold().red());
+        return Err(CheckError::NoFiles)
+    }
+    // With `--dry-run`, the renames are only printed, and any that are pending fail the check.
+    let mut has_pending_fixes = false;
+    if opts.fix {
+        let renames = fix::fix_files(&files, &config, opts.dry_run)?;
+        has_pending_fixes = opts.dry_run && !renames.is_empty();
+        if opts.output_format() == OutputFormat::Text && !opts.count {
+            print_renames(opts, &renames);
+        }
+    }
+
+    // Some checks depend on the contracts in other files, even when only some files are checked.
+    // All project files are then parsed before validation, and the index of their contracts is
+    // built from the parse trees, which are reused to validate the checked files.
+    let mut sources = HashMap::new();
+    if config.test_function_names == Some(true) || config.shadowing == Some(true) {
+        let project_files = find_files(&config, None, opts).solidity_files;
+        let unique: HashSet<&PathBuf> = project_files.iter().chain(&files).collect();
+        sources = read_sources(unique.into_iter().collect(), timings.as_deref_mut())?;
+        let symbols =
+            Arc::new(symbols::SymbolIndex::build(project_files.iter().filter_map(|file| {
+                let (pt, _comments) = sources[file].parsed.as_ref().ok()?;
+                Some((pt, utils::FileKind::of(file, &config.paths)))
+            })));
+        // The configs of subdirectories inherit these checks, so they need the contracts too.
+        for (_, dir_config) in &mut config.dir_configs {
+            dir_config.symbols = Arc::clone(&symbols);
+        }
+        config.symbols = symbols;
+    }
 
-    if !results.is_valid() {
-        eprint!("{results}");
-        eprintln!("{}: Convention checks failed, see details above", "error".bold().red());
-        return Err("Invalid names found".into())
+    let verbosity = report_verbosity(opts);
+    // Files depend on other files when `test_function_names` or `shadowing` is enabled, so a cached
+    // result could be stale even if the file did not change. Files are also not cached when kinds
+    // are disabled with `--disable`, since they may not be clean once it is removed.
+    let use_cache = !opts.no_cache &&
+        opts.disable.is_empty() &&
+        config.test_function_names != Some(true) &&
+        config.shadowing != Some(true);
+    let dir_config_paths: Vec<PathBuf> =
+        config.dir_configs.iter().map(|(dir, _)| dir.join(CONFIG_FILE)).collect();
+    let mut cache = use_cache.then(|| cache::Cache::load(&dir_config_paths));
+    let mut results =
+        validate(&files, &config, &sources, verbosity, opts.fail_fast, cache.as_mut(), timings)?;
+    if let Some(cache) = cache {
+        // A missing cache only slows down the next run, so it does not fail the check.
+        if let Err(err) = cache.save() {
+            eprintln!(
+                "{}: Failed to write {}: {err}",
+                "warning".bold().yellow(),
+                cache::CACHE_PATH
+            );
+        }
+    }
+    apply_baseline(opts, verbosity, &mut results)?;
+    print_report(opts, verbosity, &results)?;
+    if has_pending_fixes {
+        return Err(CheckError::ConventionsFailed)
     }
     Ok(())
 }
 
-// Core validation method that walks the directory and validates all Solidity files.
-fn validate(paths: [&str; 3]) -> Result<report::Report, Box<dyn Error>> {
+// Returns `true` if `file` is not in the src, script, or test directories of its config, and is
+// not a test, so it is none of the file kinds.
+fn is_outside_project(file: &Path, config: &Config) -> bool {
+    let paths = &config.for_file(file).paths;
+    utils::FileKind::of(file, paths).is_none() &&
+        ![&paths.src, &paths.script, &paths.test].into_iter().any(|dir| file.starts_with(dir))
+}
+
+// Prints the renames made by `--fix`, or each rename it would make with `--dry-run`.
+fn print_renames(opts: &CheckOpts, renames: &[fix::Rename]) {
+    if !opts.dry_run {
+        let fixed = "fixed".bold().green();
+        eprintln!("{fixed}: Renamed {}", describe_renames(renames));
+        return
+    }
+
+    for rename in renames {
+        let fix::Rename { file, line, old_name, new_name } = rename;
+        eprintln!("{}: {old_name} -> {new_name} in {file}:{line}", "would rename".bold());
+    }
+    let fixable = "fixable".bold().yellow();
+    eprintln!("{fixable}: Would rename {}", describe_renames(renames));
+}
+
+// Describes the number of renames, e.g. "1 constant or immutable name".
+fn describe_renames(renames: &[fix::Rename]) -> String {
+    let names = if renames.len() == 1 { "name" } else { "names" };
+    format!("{} constant or immutable {names}", renames.len())
+}
+
+// Validates the conventions of a single source read from stdin. The `--stdin-filename` is used to
+// determine the file kind and to label the findings.
+fn validate_stdin(
+    opts: &CheckOpts,
+    timings: Option<&mut timings::Timings>,
+) -> Result<(), CheckError> {
+    let config = load_config(opts)?;
+    let file = opts.stdin_filename.as_deref().map_or_else(|| PathBuf::from("<stdin>"), relative);
     let mut results = report::Report::default();
+    if !file.ancestors().any(|path| config.is_ignored(path, path != file)) {
+        let content = io::read_to_string(io::stdin())?;
+        let kind = utils::FileKind::of(&file, &config.paths);
+        let config = config.for_file(&file);
+        results.add_file(validate_source_timed(&file, &content, kind, config, timings));
+    }
+
+    let verbosity = report_verbosity(opts);
+    apply_baseline(opts, verbosity, &mut results)?;
+    print_report(opts, verbosity, &results)
+}
+
+// Returns the verbosity of the report. Only the text output has verbosity levels, the other
+// formats are always complete.
+const fn report_verbosity(opts: &CheckOpts) -> Verbosity {
+    match opts.output_format() {
+        OutputFormat::Text => opts.verbosity(),
+        OutputFormat::Json |
+        OutputFormat::Github |
+        OutputFormat::Sarif |
+        OutputFormat::Checkstyle |
+        OutputFormat::Junit => Verbosity::Normal,
+    }
+}
+
+// Removes the findings in the `--baseline` file from `results`. With `--write-baseline`, the
+// current findings are recorded in the file first, so none of them are reported.
+fn apply_baseline(
+    opts: &CheckOpts,
+    verbosity: Verbosity,
+    results: &mut report::Report,
+) -> Result<(), CheckError> {
+    let Some(path) = &opts.baseline else { return Ok(()) };
+    let baseline = if opts.write_baseline {
+        let baseline = baseline::Baseline::new(results.invalid_items());
+        baseline.save(path).map(|()| baseline)
+    } else {
+        baseline::Baseline::load(path)
+    }
+    .map_err(|err| {
+        eprintln!("{}: Failed to use baseline {}: {err}", "error".bold().red(), path.display());
+        CheckError::Io(err)
+    })?;
+
+    let removed = baseline.remove_from(results);
+    if opts.output_format() == OutputFormat::Text && verbosity != Verbosity::Quiet {
+        let path = path.display();
+        if opts.write_baseline {
+            eprintln!("{}: Recorded {removed} findings in {path}", "baseline".bold());
+        } else {
+            eprintln!("{}: Ignored {removed} findings recorded in {path}", "baseline".bold());
+        }
+    }
+    Ok(())
+}
+
+// Formats a path the same way as the paths found in the project, e.g. `src/Counter.sol` and
+// `/path/to/project/src/Counter.sol` become `./src/Counter.sol`, so it is matched against the
+// project directories and reported the same way however it was passed. Absolute paths outside the
+// current directory are unchanged.
+fn relative(file: &Path) -> PathBuf {
+    if file.is_absolute() {
+        return strip_current_dir(file).map_or_else(|| file.to_path_buf(), |rest| relative(&rest))
+    }
+    if file.starts_with(".") {
+        file.to_path_buf()
+    } else {
+        Path::new(".").join(file)
+    }
+}
+
+// Returns the absolute `file` relative to the current directory, or `None` if it is outside of it.
+// Both are canonicalized if needed, since either may go through a symlink, e.g. `/tmp` on macOS.
+fn strip_current_dir(file: &Path) -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    if let Ok(rest) = file.strip_prefix(&cwd) {
+        return Some(rest.to_path_buf())
+    }
+    let file = file.canonicalize().ok()?;
+    file.strip_prefix(cwd.canonicalize().ok()?).ok().map(Path::to_path_buf)
+}
+
+// Loads the project config, printing any error since it prevents all convention checks. Kinds
+// disabled with `--disable` are added to the ones disabled in the config.
+fn load_config(opts: &CheckOpts) -> Result<Config, CheckError> {
+    let mut config = Config::load().map_err(|err| {
+        eprintln!("{}: {err}", "error".bold().red());
+        CheckError::Config(err)
+    })?;
+    config.disabled.extend(&opts.disable);
+    for (_, dir_config) in &mut config.dir_configs {
+        dir_config.disabled.extend(&opts.disable);
+    }
+    Ok(config)
+}
+
+// Prints the report in the requested format, and returns an error if there are any findings. The
+// text format ends with the number of issues and checked files, unless `--quiet` is used. With
+// `--count`, only the number of findings is printed, and any finding fails the check.
+fn print_report(
+    opts: &CheckOpts,
+    verbosity: Verbosity,
+    results: &report::Report,
+) -> Result<(), CheckError> {
+    if opts.count {
+        println!("{}", results.count());
+        if !results.is_valid() {
+            return Err(CheckError::ConventionsFailed)
+        }
+        return Ok(())
+    }
+
+    match opts.output_format() {
+        OutputFormat::Text => {
+            if !results.is_valid() {
+                let details = if verbosity == Verbosity::Quiet {
+                    "run without `--quiet` for details"
+                } else {
+                    // The alternate format also prints the source line of each finding.
+                    if verbosity == Verbosity::Verbose {
+                        eprint!("{results:#}");
+                    } else {
+                        eprint!("{results}");
+                    }
+                    eprintln!("{}: {}", "summary".bold(), results.summary());
+                    "see details above"
+                };
+                if results.has_errors() {
+                    eprintln!("{}: Convention checks failed, {details}", "error".bold().red());
+                } else if let Some(max) = too_many_warnings(opts, results) {
+                    let count = results.warning_count();
+                    let warnings = if count == 1 { "warning" } else { "warnings" };
+                    let error = "error".bold().red();
+                    eprintln!(
+                        "{error}: Convention checks found {count} {warnings}, more than the maximum \
+                         of {max}, {details}"
+                    );
+                } else {
+                    let warning = "warning".bold().yellow();
+                    eprintln!("{warning}: Convention checks found warnings, {details}");
+                }
+            }
+            // Printed even on success, so scripts can confirm that the checks ran.
+            if verbosity != Verbosity::Quiet {
+                eprintln!("{}", results.status());
+            }
+        }
+        OutputFormat::Json => println!("{}", results.to_json().map_err(io::Error::from)?),
+        OutputFormat::Github => print!("{}", results.to_github_annotations()),
+        OutputFormat::Sarif => println!("{}", results.to_sarif().map_err(io::Error::from)?),
+        OutputFormat::Checkstyle => print!("{}", results.to_checkstyle()),
+        OutputFormat::Junit => print!("{}", results.to_junit()),
+    }
+
+    // Findings with the warning severity are reported, but only fail the check above
+    // `--max-warnings`.
+    if results.has_errors() || too_many_warnings(opts, results).is_some() {
+        return Err(CheckError::ConventionsFailed)
+    }
+    Ok(())
+}
+
+// Returns the `--max-warnings` limit if `results` has more warnings than it allows.
+fn too_many_warnings(opts: &CheckOpts, results: &report::Report) -> Option<usize> {
+    opts.max_warnings.filter(|&max| results.warning_count() > max)
+}
+
+// Core validation method that validates all the given Solidity files. Files are parsed and
+// validated in parallel, then merged into the report in order of their path. With
+// `Verbosity::Verbose`, each file is printed along with how long it took to check.
+//
+// Files that the `cache` records as clean with the same contents are skipped, and the cache is
+// updated with the results of this run. With `fail_fast`, files are validated in order and the
+// remaining files are skipped after the first one with any findings. The time spent in each phase
+// is added to `timings`, if given.
+fn validate(
+    files: &[PathBuf],
+    config: &Config,
+    sources: &HashMap<PathBuf, Source>,
+    verbosity: Verbosity,
+    fail_fast: bool,
+    mut cache: Option<&mut cache::Cache>,
+    mut timings: Option<&mut timings::Timings>,
+) -> Result<report::Report, CheckError> {
+    let cached = cache.as_deref();
+    let timed = timings.is_some();
+    let validate_timed = |file: &PathBuf| {
+        let start = Instant::now();
+        validate_file(file, config, sources.get(file), cached, timed)
+            .map(|result| (result, start.elapsed()))
+    };
+    let items = if fail_fast {
+        let mut items = Vec::new();
+        for file in files {
+            let (result, elapsed) = validate_timed(file)?;
+            let is_clean = result.items.is_empty();
+            items.push((result, elapsed));
+            if !is_clean {
+                break
+            }
+        }
+        items
+    } else {
+        files.par_iter().map(validate_timed).collect::<io::Result<Vec<(FileResult, Duration)>>>()?
+    };
+    let checked = items.len();
+
+    let mut results = report::Report::default();
+    for (file, (result, elapsed)) in files.iter().zip(items) {
+        if verbosity == Verbosity::Verbose {
+            // Files of no kind, e.g. helpers or dependencies, only get the checks for all files.
+            let status = if result.cached {
+                " (cached)"
+            } else if utils::FileKind::of(file, &config.for_file(file).paths).is_none() {
+                " (not a src, script, or test file)"
+            } else {
+                ""
+            };
+            eprintln!("{} {} in {elapsed:.2?}{status}", "checked".bold(), file.display());
+        }
+        if let Some(cache) = cache.as_deref_mut() {
+            cache.update(file, result.hash, result.items.is_empty());
+        }
+        if let Some(timings) = timings.as_deref_mut() {
+            timings.merge(result.timings);
+        }
+        results.add_file(result.items);
+    }
+    if verbosity == Verbosity::Verbose {
+        eprintln!("{} {checked} files", "checked".bold());
+    }
+    Ok(results)
+}
+
+// The files found in the project or the given paths.
+#[derive(Default)]
+struct FoundFiles {
+    // The sorted paths of the Solidity files to check.
+    solidity_files: Vec<PathBuf>,
+    // The number of Vyper files that were found and skipped.
+    vyper_files: usize,
+}
+
+// Returns `true` if the walk enters `dent`, which is not a skipped or ignored directory, and not an
+// ignored file.
+fn is_walked(config: &Config, dent: &ignore::DirEntry) -> bool {
+    let is_dir = dent.file_type().is_some_and(|file_type| file_type.is_dir());
+    let is_skipped = dent.depth() > 0 && is_dir && config.is_skipped_dir(dent.file_name());
+    !is_skipped && !config.is_ignored(dent.path(), is_dir)
+}
+
+// Returns a walker over `root` that skips the directories and files that are not walked, see
+// `is_walked`, along with the paths that `.gitignore` files exclude and `.git` directories, unless
+// `--no-ignore` is given. Like in git, `.gitignore` files only apply inside a git repository, and
+// roots that are files are never ignored.
+fn walk(config: &Config, root: &Path, opts: &CheckOpts) -> ignore::Walk {
+    // The filter must own what it uses, so it gets a config with just the settings it needs.
+    let filter = Config {
+        skip_dirs: config.skip_dirs.clone(),
+        ignore: config.ignore.clone(),
+        ..Config::default()
+    };
+    let no_ignore = opts.no_ignore;
+    WalkBuilder::new(root)
+        .standard_filters(false)
+        .git_ignore(!no_ignore)
+        .git_exclude(!no_ignore)
+        .git_global(!no_ignore)
+        .parents(!no_ignore)
+        .follow_links(opts.follow_symlinks)
+        .filter_entry(move |dent| {
+            let is_git_dir = dent.depth() > 0 && dent.file_name() == ".git";
+            (no_ignore || !is_git_dir) && is_walked(&filter, dent)
+        })
+        .build()
+}
+
+// Returns the paths of a symlink and of the ancestor it links to, if `err` is a symlink loop.
+fn loop_paths(err: &ignore::Error) -> Option<(&Path, &Path)> {
+    match err {
+        ignore::Error::Loop { ancestor, child } => Some((child, ancestor)),
+        ignore::Error::WithPath { err, .. } | ignore::Error::WithDepth { err, .. } => {
+            loop_paths(err)
+        }
+        _ => None,
+    }
+}
+
+// Returns `true` if `path` contains glob syntax, e.g. `src/**/*.sol`.
+fn is_glob(path: &str) -> bool {
+    path.contains(['*', '?', '[', '{'])
+}
+
+// Expands the glob patterns among `paths`, e.g. `src/**/*.sol` or `test/Foo*.sol`, into the files
+// they match. Paths that exist are kept as they are, even if they contain glob syntax. Patterns
+// are matched against the walked paths, so `*` does not match `/`, and skipped or ignored
+// directories are not searched. Patterns that match no files are skipped with a warning, so the
+// result is empty if none match. Returns `None` if no paths were given.
+fn expand_globs(config: &Config, opts: &CheckOpts) -> Result<Option<Vec<PathBuf>>, CheckError> {
+    if opts.paths.is_empty() {
+        return Ok(None)
+    }
+    let mut expanded = Vec::new();
+    for path in &opts.paths {
+        let pattern = path.to_string_lossy();
+        if path.exists() || !is_glob(&pattern) {
+            expanded.push(path.clone());
+            continue
+        }
+        let relative_pattern = relative(path);
+        let glob = GlobBuilder::new(&relative_pattern.to_string_lossy())
+            .literal_separator(true)
+            .build()
+            .map_err(|err| CheckError::Config(format!("Invalid glob `{pattern}`: {err}").into()))?
+            .compile_matcher();
+        // Only the directory before the first component with glob syntax is walked.
+        let base: PathBuf = relative_pattern
+            .components()
+            .take_while(|component| !is_glob(&component.as_os_str().to_string_lossy()))
+            .collect();
+        let matches: Vec<PathBuf> = walk(config, &base, opts)
+            .filter_map(Result::ok)
+            .filter(|dent| dent.file_type().is_some_and(|file_type| file_type.is_file()))
+            .filter(|dent| glob.is_match(dent.path()))
+            .map(ignore::DirEntry::into_path)
+            .collect();
+        if matches.is_empty() {
+            eprintln!("{}: No files match {pattern}", "warning".bold().yellow());
+        }
+        expanded.extend(matches);
+    }
+    Ok(Some(expanded))
+}
+
+// Returns the files to check, from `--files-from`, `--since`, or the paths, which default to the
+// project directories.
+fn find_checked_files(config: &Config, opts: &CheckOpts) -> Result<FoundFiles, CheckError> {
+    if let Some(list) = &opts.files_from {
+        return Ok(find_listed_files(config, list, opts)?)
+    }
+    if let Some(since) = &opts.since {
+        if let Some(found) = find_changed_files(config, since, opts)? {
+            return Ok(found)
+        }
+    }
+    let roots = expand_globs(config, opts)?;
+    Ok(find_files(config, roots.as_deref(), opts))
+}
 
-    for path in paths {
-        for result in WalkDir::new(path) {
+// Returns the sorted paths of all Solidity files in `roots`, excluding dependency directories such
+// as `lib` and any that match `.scopelintignore` or `.gitignore`. Each root can be a file or a
+// directory, and the project directories and any remapped directories are used when `roots` is
+// `None`. Empty `roots` find no files.
+//
+// With `--follow-symlinks`, symlinks are followed like regular files and directories. The walker
+// reports a symlink to an ancestor directory as an error instead of walking it forever, and files
+// reached through several paths are only returned once.
+fn find_files(config: &Config, roots: Option<&[PathBuf]>, opts: &CheckOpts) -> FoundFiles {
+    let paths = &config.paths;
+    let roots: Vec<PathBuf> = roots.map_or_else(
+        || {
+            [&paths.src, &paths.script, &paths.test]
+                .into_iter()
+                .chain(&config.remapped_dirs)
+                .map(PathBuf::from)
+                .collect()
+        },
+        // Match the format of the project directories, so the kind of each file can be inferred.
+        |roots| roots.iter().map(|root| relative(root)).collect(),
+    );
+
+    let mut found = FoundFiles::default();
+    let mut vyper_files = Vec::new();
+    for path in roots {
+        // Skip ignored directories entirely, so their contents are never walked. Roots are never
+        // skipped, so dependencies can still be checked by passing their path explicitly.
+        for result in walk(config, &path, opts) {
             let dent = match result {
                 Ok(dent) => dent,
                 Err(err) => {
-                    eprintln!("{err}");
+                    if let Some((path, ancestor)) = loop_paths(&err) {
+                        let path = path.display();
+                        let ancestor = ancestor.display();
+                        let warning = "warning".bold().yellow();
+                        eprintln!(
+                            "{warning}: Skipping {path}, which links to its ancestor {ancestor}"
+                        );
+                    } else {
+                        eprintln!("{err}");
+                    }
                     continue
                 }
             };
 
-            if !dent.file_type().is_file() || dent.path().extension() != Some(OsStr::new("sol")) {
+            if !dent.file_type().is_some_and(|file_type| file_type.is_file()) {
                 continue
             }
+            if utils::is_solidity_file(dent.path()) {
+                found.solidity_files.push(dent.into_path());
+            } else if utils::is_vyper_file(dent.path()) {
+                vyper_files.push(dent.into_path());
+            }
+        }
+    }
+    if opts.follow_symlinks {
+        dedup_links(&mut found.solidity_files);
+        dedup_links(&mut vyper_files);
+    }
+    found.solidity_files.sort();
+    found.vyper_files = vyper_files.len();
+    found
+}
+
+// Removes the paths that lead to the same file as another path in `files` through symlinks. The
+// shortest path to each file is kept, which is usually the one without symlinks, so the kind of the
+// file is inferred from where it actually lives.
+fn dedup_links(files: &mut Vec<PathBuf>) {
+    files.sort_by_key(|file| (file.components().count(), file.clone()));
+    let mut seen = HashSet::new();
+    files.retain(|file| seen.insert(fs::canonicalize(file).unwrap_or_else(|_| file.clone())));
+}
+
+// Returns the sorted paths of the Solidity files listed in `list`, one per line, or in stdin when
+// `list` is `-`. Listed paths that do not exist or are not Solidity files are skipped with a
+// warning, and ignored files are skipped like in `find_files`.
+fn find_listed_files(config: &Config, list: &Path, opts: &CheckOpts) -> io::Result<FoundFiles> {
+    let content = if list == Path::new("-") {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(list)?
+    };
 
-            // Get the parse tree (pt) of the file.
-            let file = dent.path();
+    let mut roots = Vec::new();
+    for line in content.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let path = Path::new(line);
+        if !path.is_file() || !utils::is_solidity_file(path) {
+            let warning = "warning".bold().yellow();
+            eprintln!("{warning}: Skipping {line}, which is not a Solidity file");
+            continue
+        }
+        roots.push(path.to_path_buf());
+    }
+    // An empty list checks nothing, instead of falling back to the project directories.
+    Ok(find_files(config, Some(&roots), opts))
+}
+
+// Returns the sorted paths of the Solidity files that a full check would find, and that changed
+// since the git ref `since`: files with uncommitted changes, untracked files, and files changed in
+// commits since `since`. Deleted files are skipped, and renamed files have their new name. Returns
+// `None` with a warning outside of a git repository, so all files are checked instead.
+fn find_changed_files(
+    config: &Config,
+    since: &str,
+    opts: &CheckOpts,
+) -> Result<Option<FoundFiles>, CheckError> {
+    let git = |args: &[&str]| Command::new("git").args(args).output();
+    let is_repo =
+        git(&["rev-parse", "--is-inside-work-tree"]).is_ok_and(|out| out.status.success());
+    if !is_repo {
+        let warning = "warning".bold().yellow();
+        eprintln!(
+            "{warning}: Not in a git repository, checking all files instead of the changed ones"
+        );
+        return Ok(None)
+    }
+
+    let fail = |err: String| {
+        let err = format!("Cannot list the files changed since `{since}`: {err}");
+        eprintln!("{}: {err}", "error".bold().red());
+        CheckError::Config(err.into())
+    };
+    // A ref starting with a dash would be read as an option.
+    if since.starts_with('-') {
+        return Err(fail("not a git ref".to_string()))
+    }
+    let mut changed = HashSet::new();
+    let diff = ["diff", "--name-only", "--relative", "--diff-filter=d", since, "--"];
+    for args in [&diff[..], &["ls-files", "--others", "--exclude-standard"]] {
+        let output = git(args)?;
+        if !output.status.success() {
+            return Err(fail(String::from_utf8_lossy(&output.stderr).trim().to_string()))
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        changed.extend(stdout.lines().map(|line| relative(Path::new(line))));
+    }
+
+    // Only the files a full check would find are checked, so skipped and ignored files stay so.
+    let mut found = find_files(config, None, opts);
+    found.solidity_files.retain(|file| changed.contains(file));
+    found.vyper_files = 0;
+    Ok(Some(found))
+}
+
+// The result of validating a single file.
+struct FileResult {
+    items: Vec<utils::InvalidItem>,
+    // The hash of the file contents, used to update the cache.
+    hash: u64,
+    // Whether validation was skipped because the file was clean in a previous run.
+    cached: bool,
+    // The time spent in each phase, which is empty unless timings are recorded.
+    timings: timings::Timings,
+}
+
+// A file that was read and parsed before validation, so the symbol index and the validators share
+// its parse tree.
+struct Source {
+    content: String,
+    parsed: Result<(SourceUnit, Vec<Comment>), Vec<Diagnostic>>,
+}
+
+// Reads and parses `files` in parallel, adding the time spent parsing to `timings`, if given.
+fn read_sources(
+    files: Vec<&PathBuf>,
+    timings: Option<&mut timings::Timings>,
+) -> io::Result<HashMap<PathBuf, Source>> {
+    let sources = files
+        .into_par_iter()
+        .map(|file| {
             let content = fs::read_to_string(file)?;
-            let (pt, _comments) = solang_parser::parse(&content, 0).expect("Parsing failed");
+            let start = Instant::now();
+            let parsed = utils::parse(&content);
+            Ok((file.clone(), Source { content, parsed }, start.elapsed()))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    if let Some(timings) = timings {
+        for (_, _, elapsed) in &sources {
+            timings.add(timings::Phase::Parse, *elapsed);
+        }
+    }
+    Ok(sources.into_iter().map(|(file, source, _)| (file, source)).collect())
+}
+
+// Reads a single file and runs all validators on it, recording the time of each phase if `timed`.
+// Files in `source` were already read and parsed, so only the validators run on them.
+fn validate_file(
+    file: &Path,
+    config: &Config,
+    source: Option<&Source>,
+    cache: Option<&cache::Cache>,
+    timed: bool,
+) -> io::Result<FileResult> {
+    let kind = utils::FileKind::of(file, &config.paths);
+    let mut timings = timed.then(timings::Timings::default);
+    if let Some(Source { content, parsed }) = source {
+        let config = config.for_file(file);
+        let items = validate_parsed(file, content, parsed, kind, config, timings.as_mut());
+        let hash = cache::hash(content);
+        return Ok(FileResult { items, hash, cached: false, timings: timings.unwrap_or_default() })
+    }
 
-            // Run all checks.
-            results.add_items(validators::test_names::validate(file, &content, &pt));
-            results.add_items(validators::src_names_internal::validate(file, &content, &pt));
-            results
-                .add_items(validators::script_one_pubic_run_method::validate(file, &content, &pt));
-            results.add_items(validators::constant_names::validate(file, &content, &pt));
+    let content = fs::read_to_string(file)?;
+    let hash = cache::hash(&content);
+    if cache.is_some_and(|cache| cache.is_clean(file, hash)) {
+        let timings = timings::Timings::default();
+        return Ok(FileResult { items: Vec::new(), hash, cached: true, timings })
+    }
+
+    let items =
+        validate_source_timed(file, &content, kind, config.for_file(file), timings.as_mut());
+    Ok(FileResult { items, hash, cached: false, timings: timings.unwrap_or_default() })
+}
+
+#[must_use]
+/// Runs all validators that apply to `file_kind` on the Solidity source in `content`, without
+/// reading from the filesystem. The `file` is only used to label the findings.
+///
+/// Use a `file_kind` of `None` for files that are not scripts, source contracts, or tests, such as
+/// test helpers. Only the validators that apply to all files are run on them. Unparsable sources
+/// are reported with the parse error, and only the lenient checks in [`fallback`] run on them.
+/// Findings of disabled kinds are not reported, and each finding has the severity configured for
+/// its kind.
+pub fn validate_source(
+    file: &Path,
+    content: &str,
+    file_kind: Option<utils::FileKind>,
+    config: &Config,
+) -> Vec<utils::InvalidItem> {
+    validate_source_timed(file, content, file_kind, config, None)
+}
+
+// Implements `validate_source`, adding the time spent parsing and in each validator to `timings`,
+// if given.
+fn validate_source_timed(
+    file: &Path,
+    content: &str,
+    file_kind: Option<utils::FileKind>,
+    config: &Config,
+    mut timings: Option<&mut timings::Timings>,
+) -> Vec<utils::InvalidItem> {
+    let parse_result =
+        timings::time(timings.as_deref_mut(), timings::Phase::Parse, || utils::parse(content));
+    validate_parsed(file, content, &parse_result, file_kind, config, timings)
+}
+
+// Runs all validators on a source that was already parsed, like `validate_source`, adding the time
+// spent in each validator to `timings`, if given.
+fn validate_parsed(
+    file: &Path,
+    content: &str,
+    parse_result: &Result<(SourceUnit, Vec<Comment>), Vec<Diagnostic>>,
+    file_kind: Option<utils::FileKind>,
+    config: &Config,
+    mut timings: Option<&mut timings::Timings>,
+) -> Vec<utils::InvalidItem> {
+    // Run all enabled checks on the parse tree (pt) of the file. Validators that report several
+    // kinds may still report disabled kinds, so those findings are removed below.
+    let (mut items, disabled_lines) = match parse_result {
+        Ok((pt, comments)) => {
+            let parsed = utils::Parsed { file, kind: file_kind, content, pt, comments, config };
+            let mut items = Vec::new();
+            for (index, validator) in validators::REGISTRY.iter().enumerate() {
+                if validator.kinds.iter().any(|&kind| config.is_enabled(kind)) {
+                    let phase = timings::Phase::Validator(index);
+                    items.extend(timings::time(timings.as_deref_mut(), phase, || {
+                        (validator.validate)(&parsed)
+                    }));
+                }
+            }
+            (items, utils::disabled_lines(content, comments))
         }
+        // Report the parse error so the remaining files are still validated, and check what can
+        // be checked without a parse tree.
+        Err(diagnostics) => {
+            let (mut items, comments) = timings::time(timings, timings::Phase::Fallback, || {
+                fallback::validate(file, content, file_kind, config)
+            });
+            items.insert(0, parse_error(file, content, diagnostics));
+            (items, utils::disabled_lines(content, &comments))
+        }
+    };
+    items.retain(|item| config.is_enabled(item.kind()));
+
+    // Remove any findings that were disabled with a comment.
+    items.retain(|item| !disabled_lines.contains(&item.line()));
+    items
+        .into_iter()
+        .map(|item| {
+            let severity = config.severity(item.kind());
+            let template = config.message_template(item.kind());
+            item.with_severity(severity).with_template(template).with_source(content)
+        })
+        .collect()
+}
+
+// Converts the diagnostics from a failed parse into an invalid item. Only the first diagnostic is
+// reported, since the parser's error recovery often produces cascading errors after it. The version
+// declared with `pragma solidity` is included, since newer syntax is a common cause.
+fn parse_error(file: &Path, content: &str, diagnostics: &[Diagnostic]) -> utils::InvalidItem {
+    let (mut text, (line, col)) = diagnostics.first().map_or_else(
+        || ("unknown error".to_string(), (0, 0)),
+        |diagnostic| match diagnostic.loc {
+            Loc::File(_, start, _) => {
+                (diagnostic.message.clone(), utils::offset_to_line_col(content, start))
+            }
+            _ => (diagnostic.message.clone(), (0, 0)),
+        },
+    );
+    if let Some(version) = fallback::pragma_version(content) {
+        text = format!("{text} (file declares `pragma solidity {version}`)");
+    }
+    let file = file.display().to_string();
+    utils::InvalidItem::new(utils::ValidatorKind::Parse, file, text, line, col)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::{FileKind, InvalidItem, ValidatorKind};
+
+    #[test]
+    fn test_validate_source() {
+        let content = r"
+            contract Counter {
+                uint256 constant badConstant = 1;
+                function increment() internal {}
+                function _reset() external {} // scopelint-disable-line
+            }
+        ";
+        let config = Config::default();
+        let file = Path::new("Counter.sol");
+        let kinds = |file_kind| -> Vec<ValidatorKind> {
+            validate_source(file, content, file_kind, &config)
+                .iter()
+                .map(InvalidItem::kind)
+                .collect()
+        };
+
+        // The file kind determines which validators run, regardless of the file name.
+        assert_eq!(kinds(Some(FileKind::Src)), vec![ValidatorKind::Src, ValidatorKind::Constant]);
+        assert_eq!(kinds(Some(FileKind::Test)), vec![ValidatorKind::Constant]);
+        assert_eq!(kinds(None), vec![ValidatorKind::Constant]);
+
+        // Unparsable sources are reported with the parse error, and the lenient checks still run.
+        let items = validate_source(file, "contract {", Some(FileKind::Src), &config);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind(), ValidatorKind::Parse);
+        let unparsable = "pragma solidity ^0.9.0;\ncontract C { uint256 constant bad = 1 # 2; }";
+        let items = validate_source(file, unparsable, Some(FileKind::Src), &config);
+        assert_eq!(
+            items.iter().map(InvalidItem::description).collect::<Vec<_>>(),
+            [
+                "Failed to parse Counter.sol:2:39: unrecognised token '#' (file declares `pragma solidity ^0.9.0`)",
+                "Invalid constant or immutable name in Counter.sol:2:31: bad",
+            ]
+        );
+
+        // Disabled kinds are not reported.
+        let config = Config { disabled: vec![ValidatorKind::Constant], ..Config::default() };
+        let items = validate_source(file, content, Some(FileKind::Src), &config);
+        assert_eq!(
+            items.iter().map(InvalidItem::kind).collect::<Vec<_>>(),
+            vec![ValidatorKind::Src]
+        );
+        let config = Config { enabled: Some(vec![ValidatorKind::Constant]), ..Config::default() };
+        let items = validate_source(file, content, Some(FileKind::Src), &config);
+        assert_eq!(
+            items.iter().map(InvalidItem::kind).collect::<Vec<_>>(),
+            vec![ValidatorKind::Constant]
+        );
+
+        // Script findings are reported on the contract name, so they can be disabled too.
+        let script = "contract Deploy {\n    function deploy() public {}\n}";
+        let items = validate_source(file, script, Some(FileKind::Script), &Config::default());
+        assert_eq!(
+            items.iter().map(InvalidItem::kind).collect::<Vec<_>>(),
+            [ValidatorKind::Script]
+        );
+        let script = format!("// scopelint-disable-next-line\n{script}");
+        assert!(
+            validate_source(file, &script, Some(FileKind::Script), &Config::default()).is_empty()
+        );
     }
-    Ok(results)
 }
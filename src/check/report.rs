@@ -1,34 +1,566 @@
-use super::utils::InvalidItem;
-use std::fmt;
+use super::utils::{InvalidItem, ValidatorKind};
+use crate::config::Severity;
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::json;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
 
 /// A collection of invalid items to generate a report from.
-#[derive(Default)]
+#[derive(Default, Serialize)]
 pub struct Report {
     /// A list of invalid items.
     invalid_items: Vec<InvalidItem>,
+    /// The number of files that were checked.
+    #[serde(skip)]
+    files: usize,
+    /// The kind, file, line, and details of the invalid items added so far, to skip duplicates.
+    #[serde(skip)]
+    seen: HashSet<(ValidatorKind, String, usize, String)>,
 }
 
+// Items are printed sorted by file and location, so the output is stable across runs.
+// The alternate format, e.g. `{report:#}`, also prints the snippet of each item, if any.
 impl fmt::Display for Report {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut sorted_items = self.invalid_items.clone();
-        sorted_items.sort();
-
-        for item in sorted_items {
+        for item in self.sorted_items() {
+            if item.severity() == Severity::Warning {
+                write!(f, "{}: ", "warning".yellow())?;
+            }
             writeln!(f, "{}", item.description())?;
+            if let Some(snippet) = item.snippet().filter(|_| f.alternate()) {
+                writeln!(f, "{snippet}")?;
+            }
         }
         Ok(())
     }
 }
 
 impl Report {
-    /// Extends the report with a list of invalid items.
+    /// Extends the report with a list of invalid items, skipping the items with the same kind,
+    /// file, line, and details as one already added, even if their column, severity, or source
+    /// line differ. The details start with the invalid name for most kinds, and are compared in
+    /// full so that e.g. each missing `@param` of a function is still reported.
+    ///
+    /// No two validators report the same kind for a file, and the fallback checks only run on
+    /// files that cannot be parsed, so validators do not overlap. This keeps the report free of
+    /// duplicates if a validator reports the same name twice, or if a new validator overlaps.
     pub fn add_items(&mut self, items: Vec<InvalidItem>) {
-        self.invalid_items.extend(items);
+        for item in items {
+            let key = (item.kind(), item.file().to_string(), item.line(), item.text().to_string());
+            if self.seen.insert(key) {
+                self.invalid_items.push(item);
+            }
+        }
+    }
+
+    /// Extends the report with the invalid items of a checked file, and counts the file.
+    pub fn add_file(&mut self, items: Vec<InvalidItem>) {
+        self.files += 1;
+        self.add_items(items);
+    }
+
+    /// Removes the invalid items for which `keep` returns `false`. The number of checked files is
+    /// unchanged.
+    pub fn retain(&mut self, keep: impl FnMut(&InvalidItem) -> bool) {
+        self.invalid_items.retain(keep);
+    }
+
+    #[must_use]
+    /// Returns the invalid items, in the order they were added.
+    pub fn invalid_items(&self) -> &[InvalidItem] {
+        &self.invalid_items
+    }
+
+    /// Returns the report serialized as JSON, with items in the same order as the `Display` output.
+    /// # Errors
+    /// Errors if serialization fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&Self {
+            invalid_items: self.sorted_items(),
+            files: self.files,
+            seen: HashSet::new(),
+        })
+    }
+
+    /// Returns the report as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+    /// log, with a rule for each kind of validator.
+    /// # Errors
+    /// Errors if serialization fails.
+    pub fn to_sarif(&self) -> serde_json::Result<String> {
+        let rules: Vec<serde_json::Value> = ValidatorKind::ALL
+            .iter()
+            .map(|kind| {
+                json!({
+                    "id": kind,
+                    "shortDescription": { "text": kind.label() },
+                    "fullDescription": { "text": kind.description() },
+                })
+            })
+            .collect();
+        let results: Vec<serde_json::Value> =
+            self.sorted_items().iter().map(sarif_result).collect();
+
+        serde_json::to_string_pretty(&json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "scopelint",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://github.com/ScopeLift/scopelint",
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }],
+        }))
+    }
+
+    #[must_use]
+    /// Returns the report as [Checkstyle](https://checkstyle.org) XML, with the items grouped by
+    /// file. The `source` of each item is its kind, e.g. `scopelint.Constant`.
+    pub fn to_checkstyle(&self) -> String {
+        let mut files: BTreeMap<String, Vec<InvalidItem>> = BTreeMap::new();
+        for item in self.sorted_items() {
+            files.entry(item.file().trim_start_matches("./").to_string()).or_default().push(item);
+        }
+
+        let mut lines = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+            r#"<checkstyle version="4.3">"#.to_string(),
+        ];
+        for (file, items) in files {
+            lines.push(format!(r#"  <file name="{}">"#, escape_xml(&file)));
+            for item in items {
+                // Items without a line, such as script interface findings, apply to the whole file.
+                let location = if item.line() == 0 {
+                    String::new()
+                } else {
+                    format!(r#" line="{}" column="{}""#, item.line(), item.col())
+                };
+                let severity = match item.severity() {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                let message = escape_xml(&item.message());
+                let source = format!("scopelint.{:?}", item.kind());
+                lines.push(format!(
+                    r#"    <error{location} severity="{severity}" message="{message}" source="{source}"/>"#
+                ));
+            }
+            lines.push("  </file>".to_string());
+        }
+        lines.push("</checkstyle>\n".to_string());
+        lines.join("\n")
+    }
+
+    #[must_use]
+    /// Returns the report as JUnit XML, with a test suite for each file with findings and a test
+    /// case for each finding. Errors are failing test cases, whose `type` is their kind, e.g.
+    /// `Constant`, and whose message includes the file and line. Warnings do not fail the check,
+    /// so they are passing test cases with the message in `<system-out>`. Reports without findings
+    /// are a single passing test suite, so CI systems show that the check ran.
+    pub fn to_junit(&self) -> String {
+        let mut files: BTreeMap<String, Vec<InvalidItem>> = BTreeMap::new();
+        for item in self.sorted_items() {
+            files.entry(item.file().trim_start_matches("./").to_string()).or_default().push(item);
+        }
+
+        let failures = |items: &[InvalidItem]| {
+            items.iter().filter(|item| item.severity() == Severity::Error).count()
+        };
+        // The passing test case of a report without findings counts as a test too.
+        let tests = self.invalid_items.len().max(1);
+        let mut lines = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+            format!(
+                r#"<testsuites name="scopelint" tests="{tests}" failures="{}">"#,
+                failures(&self.invalid_items)
+            ),
+        ];
+        if files.is_empty() {
+            lines.push(r#"  <testsuite name="scopelint" tests="1" failures="0">"#.to_string());
+            lines.push(r#"    <testcase name="check" classname="scopelint"/>"#.to_string());
+            lines.push("  </testsuite>".to_string());
+        }
+        for (file, items) in files {
+            let (file, tests, failures) = (escape_xml(&file), items.len(), failures(&items));
+            lines.push(format!(
+                r#"  <testsuite name="{file}" tests="{tests}" failures="{failures}">"#
+            ));
+            for item in items {
+                // Items without a line, such as script interface findings, apply to the whole file.
+                let name = if item.line() == 0 {
+                    format!("{:?}", item.kind())
+                } else {
+                    format!("{:?} at {}:{}", item.kind(), item.line(), item.col())
+                };
+                let message = escape_xml(&item.description());
+                lines.push(format!(r#"    <testcase name="{name}" classname="{file}">"#));
+                match item.severity() {
+                    Severity::Error => lines.push(format!(
+                        r#"      <failure type="{:?}" message="{message}"/>"#,
+                        item.kind()
+                    )),
+                    Severity::Warning => {
+                        lines.push(format!("      <system-out>warning: {message}</system-out>"));
+                    }
+                }
+                lines.push("    </testcase>".to_string());
+            }
+            lines.push("  </testsuite>".to_string());
+        }
+        lines.push("</testsuites>\n".to_string());
+        lines.join("\n")
+    }
+
+    #[must_use]
+    /// Returns the report as GitHub Actions workflow commands, one per line.
+    pub fn to_github_annotations(&self) -> String {
+        let mut annotations = String::new();
+        for item in self.sorted_items() {
+            annotations.push_str(&item.github_annotation());
+            annotations.push('\n');
+        }
+        annotations
+    }
+
+    #[must_use]
+    /// Returns the number of invalid items of each kind, e.g. `Constant: 3, Script: 1, Test: 5`,
+    /// with kinds in alphabetical order. Kinds with errors are colored red, and kinds with only
+    /// warnings are colored yellow.
+    pub fn summary(&self) -> String {
+        let mut counts: BTreeMap<ValidatorKind, (usize, Severity)> = BTreeMap::new();
+        for item in &self.invalid_items {
+            let (count, severity) = counts.entry(item.kind()).or_insert((0, Severity::Warning));
+            *count += 1;
+            *severity = (*severity).max(item.severity());
+        }
+        let counts: Vec<String> = counts
+            .iter()
+            .map(|(kind, (count, severity))| {
+                let count = format!("{kind:?}: {count}");
+                match severity {
+                    Severity::Error => count.red().to_string(),
+                    Severity::Warning => count.yellow().to_string(),
+                }
+            })
+            .collect();
+        counts.join(", ")
+    }
+
+    #[must_use]
+    /// Returns a line with the number of issues and checked files, e.g.
+    /// `scopelint: 2 issues across 10 files`. The format is the same regardless of the counts, so
+    /// scripts can parse it.
+    pub fn status(&self) -> String {
+        format!("scopelint: {} issues across {} files", self.invalid_items.len(), self.files)
+    }
+
+    fn sorted_items(&self) -> Vec<InvalidItem> {
+        let mut sorted_items = self.invalid_items.clone();
+        sorted_items.sort();
+        sorted_items
+    }
+
+    /// Returns the number of issues found, of any severity.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.invalid_items.len()
     }
 
     /// Returns true if no issues were found.
     #[must_use]
-    pub fn is_valid(&self) -> bool {
+    pub const fn is_valid(&self) -> bool {
         self.invalid_items.is_empty()
     }
+
+    /// Returns true if any issue has the error severity, which fails the check.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.invalid_items.iter().any(|item| item.severity() == Severity::Error)
+    }
+
+    /// Returns the number of issues with the warning severity, which only fail the check above
+    /// `--max-warnings`.
+    #[must_use]
+    pub fn warning_count(&self) -> usize {
+        self.invalid_items.iter().filter(|item| item.severity() == Severity::Warning).count()
+    }
+}
+
+// Escapes the characters that are not allowed in XML attribute values.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Returns the SARIF result for an item, located at its line and column when it has one.
+fn sarif_result(item: &InvalidItem) -> serde_json::Value {
+    // SARIF expects URIs relative to the project root, without a leading `./`.
+    let mut location =
+        json!({ "artifactLocation": { "uri": item.file().trim_start_matches("./") } });
+    if item.line() != 0 {
+        location["region"] = json!({ "startLine": item.line(), "startColumn": item.col() });
+    }
+    let rule_index = ValidatorKind::ALL.iter().position(|kind| *kind == item.kind());
+    json!({
+        "ruleId": item.kind(),
+        "ruleIndex": rule_index,
+        "level": item.severity(),
+        "message": { "text": item.message() },
+        "locations": [{ "physicalLocation": location }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let item = |kind: ValidatorKind, line: usize| {
+            InvalidItem::new(kind, "./src/Counter.sol".to_string(), "name".to_string(), line, 1)
+        };
+        let mut report = Report::default();
+        assert_eq!(report.summary(), "");
+
+        report.add_items(vec![
+            item(ValidatorKind::Test, 1),
+            item(ValidatorKind::Constant, 2),
+            item(ValidatorKind::Test, 3),
+            item(ValidatorKind::Script, 0),
+            item(ValidatorKind::Constant, 4),
+            item(ValidatorKind::Constant, 5),
+        ]);
+        assert_eq!(report.summary(), "Constant: 3, Script: 1, Test: 2");
+    }
+
+    #[test]
+    fn test_status() {
+        let mut report = Report::default();
+        assert_eq!(report.status(), "scopelint: 0 issues across 0 files");
+
+        let item = |line: usize| {
+            let file = "./src/Counter.sol".to_string();
+            InvalidItem::new(ValidatorKind::Src, file, "name".to_string(), line, 1)
+        };
+        report.add_file(vec![item(1), item(2)]);
+        report.add_file(Vec::new());
+        assert_eq!(report.status(), "scopelint: 2 issues across 2 files");
+    }
+
+    #[test]
+    fn test_add_items_deduplicates() {
+        let item = |kind: ValidatorKind, text: &str, line: usize, col: usize| {
+            let file = "./src/Counter.sol".to_string();
+            InvalidItem::new(kind, file, text.to_string(), line, col)
+        };
+        let constant = |line: usize| item(ValidatorKind::Constant, "MAX_COUNT", line, 1);
+        let mut report = Report::default();
+
+        // Identical items are only reported once, also when they are added separately.
+        report.add_items(vec![constant(1), constant(1)]);
+        report.add_items(vec![constant(1)]);
+        assert_eq!(report.count(), 1);
+
+        // Items that only differ in the column, severity, or source line are duplicates.
+        report.add_items(vec![
+            item(ValidatorKind::Constant, "MAX_COUNT", 1, 5),
+            constant(1).with_severity(Severity::Warning),
+            constant(1).with_source("uint256 constant MAX_COUNT = 1;"),
+        ]);
+        assert_eq!(report.count(), 1);
+        assert_eq!(report.invalid_items()[0].col(), 1);
+
+        // Items of another kind, at another line, or with other details are not duplicates.
+        report.add_items(vec![
+            item(ValidatorKind::LongName, "MAX_COUNT", 1, 1),
+            constant(2),
+            item(ValidatorKind::NatspecTag, "increment has no `@param a`", 3, 12),
+            item(ValidatorKind::NatspecTag, "increment has no `@param b`", 3, 12),
+        ]);
+        assert_eq!(report.count(), 5);
+    }
+
+    #[test]
+    fn test_has_errors() {
+        let item = |severity: Severity, line: usize| {
+            let file = "./src/Counter.sol".to_string();
+            InvalidItem::new(ValidatorKind::Src, file, "name".to_string(), line, 1)
+                .with_severity(severity)
+        };
+        let mut report = Report::default();
+        assert!(!report.has_errors());
+
+        // Warnings alone are not errors.
+        report.add_items(vec![item(Severity::Warning, 1)]);
+        assert!(!report.is_valid() && !report.has_errors());
+        assert_eq!(report.warning_count(), 1);
+        report.add_items(vec![item(Severity::Error, 2)]);
+        assert!(report.has_errors());
+        assert_eq!(report.warning_count(), 1);
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let mut report = Report::default();
+        report.add_items(vec![
+            InvalidItem::new(
+                ValidatorKind::Test,
+                "./test/A.t.sol".to_string(),
+                "t".to_string(),
+                3,
+                5,
+            ),
+            InvalidItem::new(
+                ValidatorKind::Script,
+                "./script/A.s.sol".to_string(),
+                "s".to_string(),
+                0,
+                0,
+            ),
+        ]);
+        let sarif: serde_json::Value = serde_json::from_str(&report.to_sarif().unwrap()).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), ValidatorKind::ALL.len());
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "Script");
+        let rule_index = usize::try_from(results[0]["ruleIndex"].as_u64().unwrap()).unwrap();
+        assert_eq!(rules[rule_index]["id"], "Script");
+        // Items without a line have no region.
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(*location, json!({ "artifactLocation": { "uri": "script/A.s.sol" } }));
+
+        assert_eq!(results[1]["message"]["text"], "Invalid test name: t");
+        let region = &results[1]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(*region, json!({ "startLine": 3, "startColumn": 5 }));
+    }
+
+    #[test]
+    fn test_to_checkstyle() {
+        let item = |kind: ValidatorKind, file: &str, text: &str, line: usize| {
+            InvalidItem::new(kind, file.to_string(), text.to_string(), line, 5)
+        };
+        let mut report = Report::default();
+        report.add_items(vec![
+            item(ValidatorKind::Test, "./test/A.t.sol", "t", 3),
+            item(ValidatorKind::Constant, "./test/A.t.sol", "c", 2)
+                .with_severity(Severity::Warning),
+            item(ValidatorKind::Script, "./script/A.s.sol", "[\"run\", \"a<b>\"] & more", 0),
+        ]);
+
+        let expected = [
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<checkstyle version="4.3">"#,
+            r#"  <file name="script/A.s.sol">"#,
+            r#"    <error severity="error" message="Invalid script interface: [&quot;run&quot;, &quot;a&lt;b&gt;&quot;] &amp; more" source="scopelint.Script"/>"#,
+            "  </file>",
+            r#"  <file name="test/A.t.sol">"#,
+            r#"    <error line="2" column="5" severity="warning" message="Invalid constant or immutable name: c" source="scopelint.Constant"/>"#,
+            r#"    <error line="3" column="5" severity="error" message="Invalid test name: t" source="scopelint.Test"/>"#,
+            "  </file>",
+            "</checkstyle>",
+            "",
+        ];
+        assert_eq!(report.to_checkstyle(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b> & \"c\" 'd'"), "a&lt;b&gt; &amp; &quot;c&quot; &apos;d&apos;");
+        // Parsers replace line breaks and tabs in attribute values with spaces, unless escaped.
+        assert_eq!(escape_xml("a\r\nb\tc"), "a&#13;&#10;b&#9;c");
+    }
+
+    #[test]
+    fn test_to_junit() {
+        // A clean report is a single passing test, which the totals count.
+        let expected = [
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<testsuites name="scopelint" tests="1" failures="0">"#,
+            r#"  <testsuite name="scopelint" tests="1" failures="0">"#,
+            r#"    <testcase name="check" classname="scopelint"/>"#,
+            "  </testsuite>",
+            "</testsuites>",
+            "",
+        ];
+        let mut report = Report::default();
+        assert_eq!(report.to_junit(), expected.join("\n"));
+
+        let item = |kind: ValidatorKind, file: &str, text: &str, line: usize| {
+            InvalidItem::new(kind, file.to_string(), text.to_string(), line, 5)
+        };
+        report.add_items(vec![
+            item(ValidatorKind::Test, "./test/A.t.sol", "t", 3),
+            item(ValidatorKind::Constant, "./test/A.t.sol", "c", 2)
+                .with_severity(Severity::Warning),
+            item(ValidatorKind::Script, "./script/A.s.sol", "a<b> & more", 0),
+        ]);
+
+        // Warnings are passing test cases, so they are not counted as failures.
+        let expected = [
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<testsuites name="scopelint" tests="3" failures="2">"#,
+            r#"  <testsuite name="script/A.s.sol" tests="1" failures="1">"#,
+            r#"    <testcase name="Script" classname="script/A.s.sol">"#,
+            r#"      <failure type="Script" message="Invalid script interface in ./script/A.s.sol: a&lt;b&gt; &amp; more"/>"#,
+            "    </testcase>",
+            "  </testsuite>",
+            r#"  <testsuite name="test/A.t.sol" tests="2" failures="1">"#,
+            r#"    <testcase name="Constant at 2:5" classname="test/A.t.sol">"#,
+            "      <system-out>warning: Invalid constant or immutable name in ./test/A.t.sol:2:5: c</system-out>",
+            "    </testcase>",
+            r#"    <testcase name="Test at 3:5" classname="test/A.t.sol">"#,
+            r#"      <failure type="Test" message="Invalid test name in ./test/A.t.sol:3:5: t"/>"#,
+            "    </testcase>",
+            "  </testsuite>",
+            "</testsuites>",
+            "",
+        ];
+        assert_eq!(report.to_junit(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_to_junit_warnings() {
+        // A report with only warnings has no failures.
+        let mut report = Report::default();
+        let file = "./src/A.sol".to_string();
+        report.add_items(vec![InvalidItem::new(
+            ValidatorKind::LongName,
+            file,
+            "n".to_string(),
+            1,
+            5,
+        )
+        .with_severity(Severity::Warning)]);
+        let junit = report.to_junit();
+        let lines: Vec<&str> = junit.lines().collect();
+        assert_eq!(lines[1], r#"<testsuites name="scopelint" tests="1" failures="0">"#);
+        assert_eq!(lines[2], r#"  <testsuite name="src/A.sol" tests="1" failures="0">"#);
+        assert!(lines[4].starts_with("      <system-out>warning: "), "{junit}");
+        assert!(!junit.contains("<failure"), "{junit}");
+    }
 }
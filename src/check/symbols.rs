@@ -0,0 +1,236 @@
+use crate::check::utils::{FileKind, Name, VisibilitySummary};
+use solang_parser::pt::{
+    ContractDefinition, ContractPart, FunctionTy, SourceUnit, SourceUnitPart, VariableAttribute,
+    VariableDefinition, Visibility,
+};
+use std::collections::{HashMap, HashSet};
+
+/// The members of a contract that other contracts and files can reference.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContractSymbols {
+    /// The kind of the file that declares the contract.
+    pub kind: Option<FileKind>,
+    /// The names of the contracts it inherits from.
+    pub bases: Vec<String>,
+    /// The names of its public and external functions, including public variable getters.
+    pub functions: HashSet<String>,
+    /// The names of its state variables that are not private, which derived contracts inherit.
+    pub variables: HashSet<String>,
+}
+
+impl ContractSymbols {
+    #[must_use]
+    /// Collects the symbols of a contract declared in a file of the given kind.
+    pub fn of(c: &ContractDefinition, kind: Option<FileKind>) -> Self {
+        let bases = c
+            .base
+            .iter()
+            .filter_map(|base| base.name.identifiers.last())
+            .map(|identifier| identifier.name.clone())
+            .collect();
+        let mut functions = HashSet::new();
+        let mut variables = HashSet::new();
+        for el in &c.parts {
+            match el {
+                ContractPart::FunctionDefinition(f)
+                    if f.ty == FunctionTy::Function && f.is_public_or_external() =>
+                {
+                    functions.insert(f.name());
+                }
+                ContractPart::VariableDefinition(v) => {
+                    let Some(name) = &v.name else { continue };
+                    if has_visibility(v, |v| matches!(v, Visibility::Public(_))) {
+                        functions.insert(name.name.clone());
+                    }
+                    if !has_visibility(v, |v| matches!(v, Visibility::Private(_))) {
+                        variables.insert(name.name.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self { kind, bases, functions, variables }
+    }
+}
+
+fn has_visibility(v: &VariableDefinition, f: impl Fn(&Visibility) -> bool) -> bool {
+    v.attrs.iter().any(|a| matches!(a, VariableAttribute::Visibility(visibility) if f(visibility)))
+}
+
+/// The symbols of all contracts in the project, keyed by contract name, for the validators that
+/// resolve names across files.
+///
+/// The index is built once per check, before the files are validated, and shared by all
+/// validators and directory configs. Contracts with the same name in several files are resolved to
+/// the last one found.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SymbolIndex {
+    contracts: HashMap<String, ContractSymbols>,
+}
+
+impl SymbolIndex {
+    #[must_use]
+    /// Builds the index from the parse trees of the project files, with the kind of each file.
+    /// Files that cannot be parsed are left out by the caller, since they are reported separately.
+    pub fn build<'a>(files: impl IntoIterator<Item = (&'a SourceUnit, Option<FileKind>)>) -> Self {
+        let mut index = Self::default();
+        for (pt, kind) in files {
+            index.insert_source_unit(pt, kind);
+        }
+        index
+    }
+
+    /// Adds the contracts of a parsed file of the given kind, replacing any with the same name.
+    pub fn insert_source_unit(&mut self, pt: &SourceUnit, kind: Option<FileKind>) {
+        for element in &pt.0 {
+            if let SourceUnitPart::ContractDefinition(c) = element {
+                if let Some(name) = &c.name {
+                    self.contracts.insert(name.name.clone(), ContractSymbols::of(c, kind));
+                }
+            }
+        }
+    }
+
+    /// Adds a single contract, replacing any with the same name.
+    pub fn insert(&mut self, name: &str, symbols: ContractSymbols) {
+        self.contracts.insert(name.to_string(), symbols);
+    }
+
+    #[must_use]
+    /// Returns the symbols of the contract `name`, if it is in the index.
+    pub fn get(&self, name: &str) -> Option<&ContractSymbols> {
+        self.contracts.get(name)
+    }
+
+    /// Returns the names and symbols of all contracts in the index, in no particular order.
+    pub fn contracts(&self) -> impl Iterator<Item = (&str, &ContractSymbols)> {
+        self.contracts.iter().map(|(name, symbols)| (name.as_str(), symbols))
+    }
+
+    #[must_use]
+    /// Returns the contracts that `name` inherits from, directly or not, each once and nearest
+    /// first along each line of inheritance. Bases that are not in the index are skipped.
+    pub fn ancestors<'a>(&'a self, name: &'a str) -> Vec<(&'a str, &'a ContractSymbols)> {
+        let Some(symbols) = self.get(name) else { return Vec::new() };
+        ancestors(name, symbols, |name| self.contracts.get_key_value(name))
+    }
+
+    #[must_use]
+    /// Returns the index as seen from a file, where the contracts of `pt` take precedence over
+    /// the contracts in the index, which can be out of date, e.g. for sources read from stdin.
+    pub fn scope<'a>(&'a self, pt: &SourceUnit, kind: Option<FileKind>) -> Scope<'a> {
+        let mut local = Self::default();
+        local.insert_source_unit(pt, kind);
+        Scope { index: self, local }
+    }
+}
+
+/// The symbols visible from one file, see [`SymbolIndex::scope`].
+#[derive(Debug)]
+pub struct Scope<'a> {
+    index: &'a SymbolIndex,
+    local: SymbolIndex,
+}
+
+impl Scope<'_> {
+    #[must_use]
+    /// Returns the symbols of the contract `name`, looking in the file first.
+    pub fn get(&self, name: &str) -> Option<&ContractSymbols> {
+        self.local.get(name).or_else(|| self.index.get(name))
+    }
+
+    #[must_use]
+    /// Returns the contracts that `name` inherits from, like [`SymbolIndex::ancestors`].
+    pub fn ancestors<'a>(&'a self, name: &'a str) -> Vec<(&'a str, &'a ContractSymbols)> {
+        let Some(symbols) = self.get(name) else { return Vec::new() };
+        ancestors(name, symbols, |name| {
+            self.local
+                .contracts
+                .get_key_value(name)
+                .or_else(|| self.index.contracts.get_key_value(name))
+        })
+    }
+}
+
+fn ancestors<'a>(
+    name: &'a str,
+    symbols: &'a ContractSymbols,
+    lookup: impl Fn(&str) -> Option<(&'a String, &'a ContractSymbols)>,
+) -> Vec<(&'a str, &'a ContractSymbols)> {
+    let mut ancestors = Vec::new();
+    let mut visited = HashSet::from([name]);
+    let mut pending: Vec<&str> = symbols.bases.iter().map(String::as_str).collect();
+    while let Some(name) = pending.pop() {
+        // Skips contracts that were already visited, including the contract itself, which guards
+        // against inheritance cycles.
+        let Some((name, base)) = lookup(name).filter(|(name, _)| visited.insert(name.as_str()))
+        else {
+            continue
+        };
+        ancestors.push((name.as_str(), base));
+        pending.extend(base.bases.iter().map(String::as_str));
+    }
+    ancestors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(content: &str, kind: Option<FileKind>) -> SymbolIndex {
+        let (pt, _comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let mut index = SymbolIndex::default();
+        index.insert_source_unit(&pt, kind);
+        index
+    }
+
+    #[test]
+    fn test_contract_symbols() {
+        let index = index(
+            r"
+            contract Counter is Ownable, utils.Pausable {
+                uint256 public number;
+                uint256 internal limit;
+                uint256 private secret;
+                function increment() external {}
+                function _decrement() internal {}
+                constructor() {}
+            }
+        ",
+            Some(FileKind::Src),
+        );
+        let symbols = index.get("Counter").expect("Counter is indexed");
+        assert_eq!(symbols.kind, Some(FileKind::Src));
+        assert_eq!(symbols.bases, vec!["Ownable", "Pausable"]);
+        let functions = HashSet::from(["number".to_string(), "increment".to_string()]);
+        assert_eq!(symbols.functions, functions);
+        let variables = HashSet::from(["number".to_string(), "limit".to_string()]);
+        assert_eq!(symbols.variables, variables);
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let index = index(
+            r"
+            contract Base is Counter {}
+            contract Left is Base {}
+            contract Right is Base {}
+            contract Counter is Left, Right, Unknown {}
+        ",
+            None,
+        );
+        // Each ancestor is listed once, the cycle through `Counter` is not followed, and unknown
+        // bases are skipped.
+        let names: Vec<&str> =
+            index.ancestors("Counter").into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["Right", "Base", "Left"]);
+        assert!(index.ancestors("Unknown").is_empty());
+
+        // The contracts of the file in scope take precedence.
+        let (pt, _comments) = solang_parser::parse("contract Right {}", 0).expect("Parsing failed");
+        let scope = index.scope(&pt, None);
+        let names: Vec<&str> =
+            scope.ancestors("Counter").into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["Right", "Left", "Base"]);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::check::validators::REGISTRY;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// A phase of `scopelint check` whose time is recorded with `--timings`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    /// Walking the directories to find the files to check.
+    Walk,
+    /// Parsing the files.
+    Parse,
+    /// Running a validator, identified by its index in [`REGISTRY`].
+    Validator(usize),
+    /// Running the lenient checks on files that cannot be parsed.
+    Fallback,
+    /// Checking the formatting of the project.
+    Formatting,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Walk => write!(f, "walk"),
+            Self::Parse => write!(f, "parse"),
+            Self::Validator(index) => {
+                let kinds: Vec<String> =
+                    REGISTRY[*index].kinds.iter().map(|kind| format!("{kind:?}")).collect();
+                write!(f, "validator {}", kinds.join("/"))
+            }
+            Self::Fallback => write!(f, "fallback checks"),
+            Self::Formatting => write!(f, "formatting"),
+        }
+    }
+}
+
+/// The total time spent in each [`Phase`]. The time of phases that run on each file, such as
+/// parsing, is accumulated across files.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    phases: BTreeMap<Phase, Duration>,
+}
+
+impl Timings {
+    /// Adds `elapsed` to the time spent in `phase`.
+    pub fn add(&mut self, phase: Phase, elapsed: Duration) {
+        *self.phases.entry(phase).or_default() += elapsed;
+    }
+
+    /// Adds the times recorded in `other`, e.g. for a single file.
+    pub fn merge(&mut self, other: Self) {
+        for (phase, elapsed) in other.phases {
+            self.add(phase, elapsed);
+        }
+    }
+}
+
+/// Runs `f`, adding the time it took to `phase` if `timings` are being recorded.
+pub fn time<T>(timings: Option<&mut Timings>, phase: Phase, f: impl FnOnce() -> T) -> T {
+    let Some(timings) = timings else { return f() };
+    let start = Instant::now();
+    let result = f();
+    timings.add(phase, start.elapsed());
+    result
+}
+
+impl fmt::Display for Timings {
+    /// Formats the timings as a table, with the slowest phase first.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut phases: Vec<(String, Duration)> =
+            self.phases.iter().map(|(phase, elapsed)| (phase.to_string(), *elapsed)).collect();
+        phases.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+
+        let width = phases.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max(5);
+        writeln!(f, "{:<width$}  {:>10}", "phase", "time")?;
+        for (name, elapsed) in phases {
+            writeln!(f, "{name:<width$}  {:>10}", format!("{elapsed:.2?}"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut timings = Timings::default();
+        timings.add(Phase::Parse, Duration::from_millis(3));
+        timings.add(Phase::Walk, Duration::from_millis(1));
+        let mut file = Timings::default();
+        file.add(Phase::Parse, Duration::from_millis(2));
+        file.add(Phase::Validator(0), Duration::from_millis(4));
+        timings.merge(file);
+
+        // Times are accumulated per phase, and the slowest phase comes first.
+        let expected = [
+            "phase                                         time",
+            "parse                                       5.00ms",
+            "validator Test/TestFuzz/TestVisibility      4.00ms",
+            "walk                                        1.00ms",
+            "",
+        ];
+        assert_eq!(timings.to_string(), expected.join("\n"));
+    }
+}
@@ -1,44 +1,553 @@
-// The `.extension()` method only looks after the last dot in the file name, so it will return
-// Some("sol") for both "Foo.sol" and "Foo.t.sol". This is not what we want here, so we just check
-// extensions manually with `ends_with`.
-#![allow(clippy::case_sensitive_file_extension_comparisons)]
-
-use solang_parser::pt::{
-    FunctionAttribute, FunctionDefinition, FunctionTy, SourceUnit, Visibility,
+use crate::config::{Config, FoundryPaths, Severity};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use solang_parser::{
+    diagnostics::Diagnostic,
+    pt::{
+        CatchClause, CodeLocation, Comment, Expression, FunctionAttribute, FunctionDefinition,
+        FunctionTy, Loc, SourceUnit, Statement, Type, Visibility,
+    },
 };
-use std::path::Path;
+use std::{cmp::Ordering, collections::HashSet, ffi::OsStr, path::Path, str::FromStr};
 
 // =======================================
 // ======== For validator methods ========
 // ===============================-=======
 
 /// The type of validator that found the invalid item.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum ValidatorKind {
+    /// An abstract contract.
+    AbstractContract,
+    /// An inline assembly block without a comment explaining it.
+    Assembly,
     /// A constant or immutable variable.
     Constant,
+    /// A constructor parameter.
+    ConstructorParameter,
+    /// A src file that is not named after any of its contracts.
+    ContractFileName,
+    /// A src file with more than one contract.
+    ContractPerFile,
+    /// A function with the same signature as another function in the same contract.
+    DuplicateFunction,
+    /// A test function without any statements.
+    EmptyTest,
+    /// An enum.
+    Enum,
+    /// An enum member.
+    EnumMember,
+    /// A custom error.
+    Error,
+    /// An event.
+    Event,
+    /// An indexed event parameter.
+    EventParameter,
+    /// An interface.
+    Interface,
+    /// A library.
+    Library,
+    /// A line longer than the configured `max_line_length`.
+    LongLine,
+    /// A name longer than the configured `max_identifier_length`.
+    LongName,
+    /// A public or external function without a NatSpec comment.
+    MissingDoc,
+    /// A public or external function that is not `view` or `pure`, but emits no event.
+    MissingEvent,
+    /// A function without an explicit visibility.
+    MissingVisibility,
+    /// A mock contract in the test directory.
+    MockContractName,
+    /// A modifier.
+    Modifier,
+    /// A mutable state variable named like a constant.
+    MutableConstant,
+    /// A NatSpec tag that does not match the signature of the function it documents.
+    NatspecTag,
+    /// A file that could not be parsed.
+    Parse,
     /// A script file.
     Script,
+    /// A function in a test file named like `setUp` with different casing.
+    SetUp,
+    /// A state variable with the same name as one it inherits.
+    Shadowing,
     /// A source contract.
     Src,
+    /// A public or external function in a source contract.
+    SrcPublic,
+    /// A mutable state variable.
+    StateVariable,
+    /// An upgradeable contract without a `__gap` array.
+    StorageGap,
+    /// A struct.
+    Struct,
     /// A test contract.
     Test,
+    /// A test contract that does not inherit from the required base contract.
+    TestBase,
+    /// The name of a test contract.
+    TestContractName,
+    /// A test named as a fuzz test without parameters, or the other way around.
+    TestFuzz,
+    /// An internal or private function in a test file without a leading underscore.
+    TestHelper,
+    /// A test function declared `public` instead of `external`.
+    TestPublic,
+    /// A test that references a function the contract under test does not have.
+    TestSubject,
+    /// A test function that is not public or external.
+    TestVisibility,
+    /// An immutable variable that is never assigned.
+    UnassignedImmutable,
+}
+
+impl ValidatorKind {
+    /// Every kind of validator, in sort order.
+    pub const ALL: [Self; 42] = [
+        Self::AbstractContract,
+        Self::Assembly,
+        Self::Constant,
+        Self::ConstructorParameter,
+        Self::ContractFileName,
+        Self::ContractPerFile,
+        Self::DuplicateFunction,
+        Self::EmptyTest,
+        Self::Enum,
+        Self::EnumMember,
+        Self::Error,
+        Self::Event,
+        Self::EventParameter,
+        Self::Interface,
+        Self::Library,
+        Self::LongLine,
+        Self::LongName,
+        Self::MissingDoc,
+        Self::MissingEvent,
+        Self::MissingVisibility,
+        Self::MockContractName,
+        Self::Modifier,
+        Self::MutableConstant,
+        Self::NatspecTag,
+        Self::Parse,
+        Self::Script,
+        Self::SetUp,
+        Self::Shadowing,
+        Self::Src,
+        Self::SrcPublic,
+        Self::StateVariable,
+        Self::StorageGap,
+        Self::Struct,
+        Self::Test,
+        Self::TestBase,
+        Self::TestContractName,
+        Self::TestFuzz,
+        Self::TestHelper,
+        Self::TestPublic,
+        Self::TestSubject,
+        Self::TestVisibility,
+        Self::UnassignedImmutable,
+    ];
+
+    #[must_use]
+    /// Returns the kind with the given name, as in the JSON output. Case, dashes, and underscores
+    /// are ignored, so `src-public` and `SRC_PUBLIC` both name `SrcPublic`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalize = |name: &str| name.replace(['-', '_'], "").to_ascii_lowercase();
+        let name = normalize(name);
+        Self::ALL.into_iter().find(|kind| normalize(&format!("{kind:?}")) == name)
+    }
+
+    #[must_use]
+    /// Returns the label describing invalid items of this kind.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::AbstractContract => "Invalid abstract contract name",
+            Self::Assembly => "Assembly block without a comment",
+            Self::Constant => "Invalid constant or immutable name",
+            Self::ConstructorParameter => "Invalid constructor parameter name",
+            Self::ContractFileName => "Contract not named after its file",
+            Self::ContractPerFile => "Multiple contracts in one file",
+            Self::DuplicateFunction => "Duplicate function",
+            Self::EmptyTest => "Empty test function",
+            Self::Enum => "Invalid enum name",
+            Self::EnumMember => "Invalid enum member name",
+            Self::Error => "Invalid custom error name",
+            Self::Event => "Invalid event name",
+            Self::EventParameter => "Invalid indexed event parameter name",
+            Self::Interface => "Invalid interface name",
+            Self::Library => "Invalid library name",
+            Self::LongLine => "Line too long",
+            Self::LongName => "Identifier too long",
+            Self::MissingDoc => "Missing NatSpec comment",
+            Self::MissingEvent => "State-changing function without an event",
+            Self::MissingVisibility => "Missing function visibility",
+            Self::MockContractName => "Invalid mock contract name",
+            Self::Modifier => "Invalid modifier name",
+            Self::MutableConstant => "Invalid mutable variable name",
+            Self::NatspecTag => "Mismatched NatSpec tag",
+            Self::Parse => "Failed to parse file",
+            Self::Script => "Invalid script interface",
+            Self::SetUp => "Misspelled setUp function",
+            Self::Shadowing => "Shadowed state variable",
+            Self::Src => "Invalid src method name",
+            Self::SrcPublic => "Invalid public or external src method name",
+            Self::StateVariable => "Invalid state variable name",
+            Self::StorageGap => "Missing storage gap",
+            Self::Struct => "Invalid struct name",
+            Self::Test => "Invalid test name",
+            Self::TestBase => "Missing test base contract",
+            Self::TestContractName => "Invalid test contract name",
+            Self::TestFuzz => "Invalid fuzz test name",
+            Self::TestHelper => "Invalid test helper name",
+            Self::TestPublic => "Public test function",
+            Self::TestSubject => "Test of unknown function",
+            Self::TestVisibility => "Non-public test function",
+            Self::UnassignedImmutable => "Unassigned immutable",
+        }
+    }
+
+    #[must_use]
+    /// Returns the severity of findings of this kind when `[severity]` does not set one. Only the
+    /// findings that are a matter of taste are warnings.
+    pub const fn default_severity(self) -> Severity {
+        match self {
+            Self::LongName | Self::MutableConstant => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    #[must_use]
+    /// Returns a description of the convention this kind of validator enforces.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::AbstractContract => "Abstract contract names match `abstract_contract_pattern`.",
+            Self::Assembly => "Inline assembly blocks have a comment explaining them.",
+            Self::Constant => "Constant and immutable names are in `ALL_CAPS`.",
+            Self::ConstructorParameter => {
+                "Constructor parameter names match `constructor_parameter_pattern`."
+            }
+            Self::ContractFileName => "Src files are named after one of their contracts.",
+            Self::ContractPerFile => "Src files declare at most one contract.",
+            Self::DuplicateFunction => {
+                "Functions in the same contract do not have the same name and parameter types."
+            }
+            Self::EmptyTest => "Test functions have at least one statement.",
+            Self::Enum => "Enum names are in `PascalCase`.",
+            Self::EnumMember => "Enum member names match `enum_member_pattern`.",
+            Self::Error => "Custom error names are in `PascalCase`.",
+            Self::Event => "Event names are in `PascalCase`.",
+            Self::EventParameter => {
+                "Indexed event parameter names match `indexed_event_parameter_pattern`."
+            }
+            Self::Interface => "Interface names are prefixed with `I`, e.g. `IERC20`.",
+            Self::Library => "Library names are in `PascalCase`.",
+            Self::LongLine => "Lines are not longer than `max_line_length`.",
+            Self::LongName => {
+                "Constant, function, and variable names are within `max_identifier_length`."
+            }
+            Self::MissingDoc => "Public and external src functions have a NatSpec comment.",
+            Self::MissingEvent => {
+                "Public and external src functions that are not `view` or `pure` emit an event."
+            }
+            Self::MissingVisibility => "Functions declare their visibility explicitly.",
+            Self::MockContractName => {
+                "Mock contracts start or end with `Mock`, as set by `mock_contract_names`."
+            }
+            Self::Modifier => "Modifier names are in `mixedCase`.",
+            Self::MutableConstant => {
+                "State variables named like constants are declared `constant` or `immutable`."
+            }
+            Self::NatspecTag => {
+                "NatSpec comments have a `@param` per parameter and a `@return` per return value."
+            }
+            Self::Parse => "Solidity files can be parsed.",
+            Self::Script => "Scripts have a single public `run` method, excluding `setUp`.",
+            Self::SetUp => {
+                "Test files have no functions named `setup` or `SetUp` instead of `setUp`."
+            }
+            Self::Shadowing => "State variables do not reuse the name of an inherited variable.",
+            Self::Src => "Internal and private src function names start with an underscore.",
+            Self::SrcPublic => {
+                "Public and external src function names do not start with an underscore."
+            }
+            Self::StateVariable => "Mutable state variable names are in `mixedCase`.",
+            Self::StorageGap => "Upgradeable src contracts declare a `__gap` array.",
+            Self::Struct => "Struct names are in `PascalCase`.",
+            Self::Test => "Test names match the test naming convention.",
+            Self::TestBase => "Contracts with tests inherit from `test_base_contract`.",
+            Self::TestContractName => "Contracts with tests are named `<Name>Test`.",
+            Self::TestFuzz => "Tests with parameters are named `testFuzz_*`, and others are not.",
+            Self::TestHelper => "Internal and private functions in test files start with `_`.",
+            Self::TestPublic => "Test functions are `external` rather than `public`.",
+            Self::TestSubject => {
+                "Tests named `test_<Function>_*` reference a function of the contract under test."
+            }
+            Self::TestVisibility => "Functions named like tests are public or external.",
+            Self::UnassignedImmutable => {
+                "Immutable variables are assigned where declared or in the constructor."
+            }
+        }
+    }
+}
+
+impl FromStr for ValidatorKind {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| format!("unknown kind `{name}`"))
+    }
 }
 
-/// A single invalid item found by a validator.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+/// A single invalid item found by a validator. Items are ordered by their location, i.e. by file,
+/// line, and column, and then by kind.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
 pub struct InvalidItem {
     kind: ValidatorKind,
     file: String, // File name.
     text: String, // Details to show about the invalid item.
     line: usize,  // Line number.
+    col: usize,   // Column number, counted in characters.
+    severity: Severity,
+    #[serde(skip)]
+    source_line: Option<String>, // The source line at `line`, shown in verbose output.
+    #[serde(skip)]
+    template: Option<String>, // Replaces `text` when shown, see `with_template`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>, // The signature of the function, see `with_signature`.
+}
+
+impl Ord for InvalidItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.file, self.line, self.col, self.kind, &self.text, self.severity).cmp(&(
+            &other.file,
+            other.line,
+            other.col,
+            other.kind,
+            &other.text,
+            other.severity,
+        ))
+    }
+}
+
+impl PartialOrd for InvalidItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl InvalidItem {
     #[must_use]
-    /// Creates a new `InvalidItem`.
-    pub const fn new(kind: ValidatorKind, file: String, text: String, line: usize) -> Self {
-        Self { kind, file, text, line }
+    /// Creates a new `InvalidItem`. Use `0` for the `line` and `col` if the item spans multiple
+    /// lines.
+    pub const fn new(
+        kind: ValidatorKind,
+        file: String,
+        text: String,
+        line: usize,
+        col: usize,
+    ) -> Self {
+        Self {
+            kind,
+            file,
+            text,
+            line,
+            col,
+            severity: Severity::Error,
+            source_line: None,
+            template: None,
+            signature: None,
+        }
+    }
+
+    #[must_use]
+    /// Returns the item with the line at its location in `content`, the source it was found in, so
+    /// it can be shown in a [`snippet`](Self::snippet). Items that span multiple lines are
+    /// unchanged.
+    pub fn with_source(mut self, content: &str) -> Self {
+        if let Some(index) = self.line.checked_sub(1) {
+            let content = content.strip_prefix(BOM).unwrap_or(content);
+            self.source_line = content.lines().nth(index).map(str::to_string);
+        }
+        self
+    }
+
+    #[must_use]
+    /// Returns the source line of the item with the identifier at its column underlined, similar to
+    /// rustc diagnostics, or `None` if the item has no source line, e.g. because it spans multiple
+    /// lines.
+    pub fn snippet(&self) -> Option<String> {
+        let source_line = self.source_line.as_ref()?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        // Keep tabs in the padding so the markers line up with the source line in terminals.
+        let padding: String = source_line
+            .chars()
+            .take(self.col.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = source_line
+            .chars()
+            .skip(self.col.saturating_sub(1))
+            .take_while(|&c| c.is_alphanumeric() || c == '_' || c == '$')
+            .count()
+            .max(1);
+        let bar = "|".blue().bold();
+        let markers = "^".repeat(width);
+        let markers = match self.severity {
+            Severity::Error => markers.red().bold(),
+            Severity::Warning => markers.yellow().bold(),
+        };
+        let line = self.line.to_string().blue().bold();
+        Some(format!(
+            "{gutter} {bar}\n{line} {bar} {source_line}\n{gutter} {bar} {padding}{markers}"
+        ))
+    }
+
+    #[must_use]
+    /// Returns the item with the given severity, which is `Severity::Error` by default.
+    pub const fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    #[must_use]
+    /// Returns the item with its details shown with the given template, configured with
+    /// `[messages]` in `.scopelint.toml`, or with the built-in details if `None`. The details are
+    /// unchanged in the JSON output and the baseline, so they do not depend on the wording.
+    ///
+    /// The placeholders in [`TEMPLATE_PLACEHOLDERS`] are replaced by the item's values, and `{{`
+    /// and `}}` by literal braces.
+    pub fn with_template(mut self, template: Option<&str>) -> Self {
+        self.template = template.map(str::to_string);
+        self
+    }
+
+    #[must_use]
+    /// Returns the item with the signature of the function it is about, e.g.
+    /// `transfer(address,uint256)`, which tells overloads apart. The signature replaces the name
+    /// that the details start with when they are shown, but not in the baseline.
+    pub fn with_signature(mut self, signature: String) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
+    #[must_use]
+    /// Returns the details shown for the invalid item, which are the configured template with its
+    /// placeholders replaced, or the built-in details if no template is configured.
+    pub fn details(&self) -> String {
+        let Some(template) = &self.template else { return self.text_with_signature() };
+        render_template(template, |placeholder| match placeholder {
+            "name" => Some(self.name().to_string()),
+            "signature" => Some(self.signature.clone().unwrap_or_else(|| self.name().to_string())),
+            "text" => Some(self.text.clone()),
+            "label" => Some(self.kind.label().to_string()),
+            "file" => Some(self.file.clone()),
+            "line" => Some(self.line.to_string()),
+            "col" => Some(self.col.to_string()),
+            _ => None,
+        })
+        // Templates are validated when the config is loaded, so this only falls back for items
+        // created with an unchecked template.
+        .unwrap_or_else(|_| self.text_with_signature())
+    }
+
+    // Returns the built-in details, with the name they start with replaced by the signature of the
+    // function, if the item has one.
+    fn text_with_signature(&self) -> String {
+        match &self.signature {
+            Some(signature) if !self.name().is_empty() && self.text.starts_with(self.name()) => {
+                format!("{signature}{}", &self.text[self.name().len()..])
+            }
+            _ => self.text.clone(),
+        }
+    }
+
+    // Returns the name that the details start with, e.g. `count` for `count shadows ...`, or an
+    // empty string if they do not start with a name.
+    fn name(&self) -> &str {
+        let text = self.text.trim_start_matches('`');
+        let end = text
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(text.len());
+        &text[..end]
+    }
+
+    #[must_use]
+    /// Returns a short message describing the invalid item, without its location.
+    pub fn message(&self) -> String {
+        format!("{}: {}", self.kind.label(), self.details())
+    }
+
+    #[must_use]
+    /// Returns a GitHub Actions workflow command that annotates the invalid item in PRs, see
+    /// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions>.
+    pub fn github_annotation(&self) -> String {
+        // GitHub expects paths relative to the repository root, without a leading `./`.
+        let file = escape_github_property(self.file.trim_start_matches("./"));
+        let message = escape_github_data(&self.message());
+        let command = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        if self.line == 0 {
+            format!("::{command} file={file}::{message}")
+        } else {
+            format!("::{command} file={file},line={},col={}::{message}", self.line, self.col)
+        }
+    }
+
+    #[must_use]
+    /// Returns the type of validator that found the invalid item.
+    pub const fn kind(&self) -> ValidatorKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the severity of the invalid item.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[must_use]
+    /// Returns the path of the file containing the invalid item.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    #[must_use]
+    /// Returns the details of the invalid item, which start with the invalid name for most kinds.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    /// Returns the signature of the function the invalid item is about, if any.
+    pub fn signature(&self) -> Option<&str> {
+        self.signature.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the line number of the invalid item, or `0` if it spans multiple lines.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    /// Returns the column number of the invalid item, or `0` if it spans multiple lines.
+    pub const fn col(&self) -> usize {
+        self.col
+    }
+
+    #[must_use]
+    /// Returns the location of the invalid item as `file:line:col`, or just the file if it spans
+    /// multiple lines. This format is clickable in most terminals and editors.
+    pub fn location(&self) -> String {
+        if self.line == 0 {
+            self.file.clone()
+        } else {
+            format!("{}:{}:{}", self.file, self.line, self.col)
+        }
     }
 
     #[must_use]
@@ -46,62 +555,187 @@ impl InvalidItem {
     /// findings.
     pub fn description(&self) -> String {
         match self.kind {
-            ValidatorKind::Test => {
-                format!("Invalid test name in {} on line {}: {}", self.file, self.line, self.text)
-            }
-            ValidatorKind::Constant => {
-                format!(
-                    "Invalid constant or immutable name in {} on line {}: {}",
-                    self.file, self.line, self.text
-                )
-            }
-            ValidatorKind::Script => {
-                format!("Invalid script interface in {}: {}", self.file, self.text)
-            }
-            ValidatorKind::Src => {
-                format!(
-                    "Invalid src method name in {} on line {}: {}",
-                    self.file, self.line, self.text
-                )
+            ValidatorKind::Parse => {
+                format!("Failed to parse {}: {}", self.location(), self.details())
             }
+            _ => format!("{} in {}: {}", self.kind.label(), self.location(), self.details()),
+        }
+    }
+}
+
+/// The placeholders that message templates can use, see [`InvalidItem::with_template`].
+///
+/// `{text}` is the built-in details, `{name}` the name they start with, if any, and `{signature}`
+/// the signature of the function, or the name for items that are not about a function.
+pub const TEMPLATE_PLACEHOLDERS: [&str; 7] =
+    ["name", "signature", "text", "label", "file", "line", "col"];
+
+/// Replaces each `{placeholder}` in `template` with its value, and `{{` and `}}` with literal
+/// braces.
+/// # Errors
+/// Errors with a description of the problem if a placeholder has no value or is not closed.
+pub fn render_template(
+    template: &str,
+    value: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        rendered.push_str(&rest[..start]);
+        let brace = &rest[start..=start];
+        rest = &rest[start + 1..];
+        if let Some(after) = rest.strip_prefix(brace) {
+            rendered.push_str(brace);
+            rest = after;
+        } else if brace == "}" {
+            return Err("unmatched `}`, use `}}` for a literal brace".to_string())
+        } else {
+            let end = rest.find('}').ok_or_else(|| "unclosed `{`".to_string())?;
+            let placeholder = &rest[..end];
+            let value = value(placeholder)
+                .ok_or_else(|| format!("unknown placeholder `{{{placeholder}}}`"))?;
+            rendered.push_str(&value);
+            rest = &rest[end + 1..];
         }
     }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// Escapes the message of a GitHub Actions workflow command.
+#[must_use]
+pub fn escape_github_data(data: &str) -> String {
+    data.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+// Escapes a property value of a GitHub Actions workflow command.
+fn escape_github_property(property: &str) -> String {
+    escape_github_data(property).replace(':', "%3A").replace(',', "%2C")
 }
 
 /// Categories of file kinds found in forge projects.
+///
 /// Two additional file kinds are not included here: `ScriptHelpers` and `TestHelpers`. These are
 /// not currently used in any checks so they are excluded for now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileKind {
     /// Executable script files live in the `scripts` directory and end with `.s.sol`.
     Script,
-    /// Core contracts live in the `src` directory and end with `.sol`.
+    /// Core contracts live in the `src` directory and end with `.sol`, excluding `.t.sol` files.
     Src,
-    /// Contracts with test methods live in the `test` directory and end with `.t.sol`.
+    /// Contracts with test methods end with `.t.sol`. These usually live in the `test` directory,
+    /// but the extension takes precedence over the directory, so e.g. `src/Counter.t.sol` is a
+    /// test file and not a src file.
     Test,
 }
 
+// Returns `true` if the file name of `file` ends with `suffix` and has a stem before it, e.g.
+// `Counter.t.sol` but not `.t.sol`. The `.extension()` method only looks after the last dot in the
+// file name, so it returns `sol` for both `Counter.sol` and `Counter.t.sol`. File names that are
+// not valid UTF-8 never match.
+fn has_suffix(file: &Path, suffix: &str) -> bool {
+    file.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|name| name.len() > suffix.len() && name.ends_with(suffix))
+}
+
+impl FileKind {
+    #[must_use]
+    /// Returns the kind of `file` based on where it lives in the project, or `None` if it is not
+    /// any of the kinds, e.g. a test helper contract.
+    pub fn of(file: &Path, paths: &FoundryPaths) -> Option<Self> {
+        [Self::Script, Self::Src, Self::Test].into_iter().find(|&kind| kind.matches(file, paths))
+    }
+
+    #[must_use]
+    /// Returns `true` if `file` is of this kind. The `paths` specify where each kind of file lives
+    /// in the project.
+    pub fn matches(self, file: &Path, paths: &FoundryPaths) -> bool {
+        match self {
+            Self::Script => file.starts_with(&paths.script) && has_suffix(file, ".s.sol"),
+            Self::Src => {
+                file.starts_with(&paths.src) &&
+                    is_solidity_file(file) &&
+                    !has_suffix(file, ".t.sol")
+            }
+            Self::Test => has_suffix(file, ".t.sol"),
+        }
+    }
+}
+
+#[must_use]
+/// Returns `true` if `file` is a Solidity file, i.e. its name ends with `.sol`.
+pub fn is_solidity_file(file: &Path) -> bool {
+    has_suffix(file, ".sol")
+}
+
+#[must_use]
+/// Returns `true` if `file` is a Vyper contract or interface, i.e. its name ends with `.vy` or
+/// `.vyi`. These are never checked, since scopelint only supports Solidity.
+pub fn is_vyper_file(file: &Path) -> bool {
+    has_suffix(file, ".vy") || has_suffix(file, ".vyi")
+}
+
 /// Provides a method to check if a file is of a given kind.
 pub trait IsFileKind {
-    /// Returns `true` if the file is of the given kind, `false` otherwise.
-    fn is_file_kind(&self, kind: FileKind) -> bool;
+    /// Returns `true` if the file is of the given kind, `false` otherwise. The `paths` specify
+    /// where each kind of file lives in the project.
+    fn is_file_kind(&self, kind: FileKind, paths: &FoundryPaths) -> bool;
 }
 
 impl IsFileKind for Path {
-    fn is_file_kind(&self, kind: FileKind) -> bool {
-        let path = self.to_str().unwrap();
-        match kind {
-            FileKind::Script => path.starts_with("./script") && path.ends_with(".s.sol"),
-            FileKind::Src => path.starts_with("./src") && path.ends_with(".sol"),
-            FileKind::Test => path.starts_with("./test") && path.ends_with(".t.sol"),
-        }
+    fn is_file_kind(&self, kind: FileKind, paths: &FoundryPaths) -> bool {
+        kind.matches(self, paths)
     }
 }
 
+/// A parsed Solidity file, along with the project information needed to validate it.
+pub struct Parsed<'a> {
+    /// Path to the file, used when reporting findings.
+    pub file: &'a Path,
+    /// The kind of the file, which determines the validators that apply to it.
+    pub kind: Option<FileKind>,
+    /// Contents of the file.
+    pub content: &'a str,
+    /// The parse tree of the file.
+    pub pt: &'a SourceUnit,
+    /// The comments in the file, including NatSpec doc comments.
+    pub comments: &'a [Comment],
+    /// The configuration of the project the file belongs to.
+    pub config: &'a Config,
+}
+
+impl Parsed<'_> {
+    #[must_use]
+    /// Returns `true` if the file is of the given kind, `false` otherwise.
+    pub fn is_file_kind(&self, kind: FileKind) -> bool {
+        self.kind == Some(kind)
+    }
+}
+
+#[must_use]
+/// Returns `true` if `name` is in `PascalCase`, i.e. it starts with an uppercase letter and only
+/// contains letters and digits.
+pub fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) &&
+        name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[must_use]
+/// Returns `true` if `name` is in `mixedCase`, i.e. it starts with a lowercase letter and only
+/// contains letters and digits.
+pub fn is_mixed_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) &&
+        name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
 /// Provides a method to return the name of a function.
 pub trait Name {
     /// Returns the name of the function for standard functions, or `constructor`, `fallback` or
     /// `receive` for other function types.
     fn name(&self) -> String;
+    /// Returns the location of the function name, or of the whole function if it has no name.
+    fn name_loc(&self) -> Loc;
 }
 
 /// Provides methods to return visibility information about a function.
@@ -110,6 +744,8 @@ pub trait VisibilitySummary {
     fn is_internal_or_private(&self) -> bool;
     /// Returns `true` if the function is public or external, `false` otherwise.
     fn is_public_or_external(&self) -> bool;
+    /// Returns `true` if the function is public, `false` otherwise.
+    fn is_public(&self) -> bool;
 }
 
 impl Name for FunctionDefinition {
@@ -121,6 +757,37 @@ impl Name for FunctionDefinition {
             FunctionTy::Function | FunctionTy::Modifier => self.name.as_ref().unwrap().name.clone(),
         }
     }
+
+    fn name_loc(&self) -> Loc {
+        self.name.as_ref().map_or(self.loc, |name| name.loc)
+    }
+}
+
+#[must_use]
+/// Returns the signature of a function in `content`, e.g. `transfer(address,uint256)`, which is the
+/// same for any two definitions that would conflict.
+///
+/// Types are written as in the source without whitespace, except for the `uint` and `int` aliases
+/// which are expanded to `uint256` and `int256`. Data locations and parameter names are omitted.
+pub fn signature(content: &str, f: &FunctionDefinition) -> String {
+    let types: Vec<String> = f
+        .params
+        .iter()
+        .filter_map(|(_, param)| param.as_ref())
+        .map(|param| type_name(content, &param.ty))
+        .collect();
+    format!("{}({})", f.name(), types.join(","))
+}
+
+fn type_name(content: &str, ty: &Expression) -> String {
+    match ty {
+        Expression::Type(_, Type::Uint(bits)) => format!("uint{bits}"),
+        Expression::Type(_, Type::Int(bits)) => format!("int{bits}"),
+        _ => {
+            let loc = ty.loc();
+            content[loc.start()..loc.end()].chars().filter(|c| !c.is_whitespace()).collect()
+        }
+    }
 }
 
 impl VisibilitySummary for FunctionDefinition {
@@ -141,24 +808,118 @@ impl VisibilitySummary for FunctionDefinition {
             _ => false,
         })
     }
+
+    fn is_public(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|a| matches!(a, FunctionAttribute::Visibility(Visibility::Public(_))))
+    }
 }
 
 #[must_use]
-/// Converts the start offset of a `Loc` to `(line, col)`. Modified from <https://github.com/foundry-rs/foundry/blob/45b9dccdc8584fb5fbf55eb190a880d4e3b0753f/fmt/src/helpers.rs#L54-L70>
+/// Converts a byte offset into `content`, such as `Loc::start()`, to its line number, where the
+/// first line is `1`. Offsets past the end of the content return the last line.
 pub fn offset_to_line(content: &str, start: usize) -> usize {
-    debug_assert!(content.len() > start);
+    offset_to_line_col(content, start).0
+}
+
+#[must_use]
+/// Converts a byte offset into `content` to its `(line, col)`, both starting at `1`.
+///
+/// Columns count characters rather than bytes, matching how editors and terminals locate a
+/// `file:line:col` position in files with multi-byte characters. A leading UTF-8 BOM is not part of
+/// the first line, and `\r\n` line endings are a single line break, as in editors.
+pub fn offset_to_line_col(content: &str, start: usize) -> (usize, usize) {
+    // Use the bytes rather than the chars, since offsets are byte offsets and multi-byte
+    // characters would otherwise shift the count. This also works for non char boundary offsets.
+    let before = &content.as_bytes()[..start.min(content.len())];
+    let line = before.split(|&b| b == b'\n').count();
+    // The `\r` of a `\r\n` ends the previous line, so it never comes before an offset on the same
+    // line, and only the BOM needs to be skipped.
+    let bom_len = if before.starts_with(BOM.as_bytes()) { BOM.len() } else { 0 };
+    let line_start = before.iter().rposition(|&b| b == b'\n').map_or(bom_len, |i| i + 1);
+    // Each char has exactly one byte that is not a UTF-8 continuation byte (`0b10xxxxxx`).
+    let col = before[line_start..].iter().filter(|&&b| b & 0xC0 != 0x80).count() + 1;
+    (line, col)
+}
+
+const BOM: &str = "\u{feff}";
 
-    let mut line_counter = 1; // First line is `1`.
-    for (offset, c) in content.chars().enumerate() {
-        if c == '\n' {
-            line_counter += 1;
+/// Calls `f` with `statement` and each statement nested in it, such as the branches of an `if` or
+/// the body of a loop, in source order.
+pub fn for_each_statement<'a>(statement: &'a Statement, f: &mut impl FnMut(&'a Statement)) {
+    f(statement);
+    match statement {
+        Statement::Block { statements, .. } => {
+            for statement in statements {
+                for_each_statement(statement, f);
+            }
+        }
+        Statement::If(_, _, then, otherwise) => {
+            for_each_statement(then, f);
+            if let Some(otherwise) = otherwise {
+                for_each_statement(otherwise, f);
+            }
+        }
+        Statement::While(_, _, body) | Statement::DoWhile(_, body, _) => {
+            for_each_statement(body, f);
+        }
+        Statement::For(_, init, _, next, body) => {
+            for statement in [init, next, body].into_iter().flatten() {
+                for_each_statement(statement, f);
+            }
         }
-        if offset > start {
-            return line_counter
+        Statement::Try(_, _, returns, catches) => {
+            if let Some((_, body)) = returns {
+                for_each_statement(body, f);
+            }
+            for catch in catches {
+                match catch {
+                    CatchClause::Simple(_, _, body) | CatchClause::Named(_, _, _, body) => {
+                        for_each_statement(body, f);
+                    }
+                }
+            }
         }
+        _ => {}
     }
+}
 
-    unreachable!("content.len() > start")
+/// Parses Solidity source into its parse tree and comments.
+///
+/// Unlike `solang_parser::parse`, this accepts sources that start with a UTF-8 BOM, which editors
+/// on Windows often add. The BOM is parsed as whitespace, so the returned locations are still byte
+/// offsets into `content`.
+/// # Errors
+/// Errors with the parser diagnostics if the source is not valid Solidity.
+pub fn parse(content: &str) -> Result<(SourceUnit, Vec<Comment>), Vec<Diagnostic>> {
+    let Some(rest) = content.strip_prefix(BOM) else { return solang_parser::parse(content, 0) };
+    // The BOM is replaced with the same number of bytes, so offsets are unchanged.
+    solang_parser::parse(&format!("{}{rest}", " ".repeat(BOM.len())), 0)
+}
+
+#[must_use]
+/// Returns the line numbers that findings should be ignored on.
+///
+/// Lines are disabled with `// scopelint-disable-line` and `// scopelint-disable-next-line`
+/// comments. Any text after the directive, such as the reason for disabling the check, is ignored.
+pub fn disabled_lines(content: &str, comments: &[Comment]) -> HashSet<usize> {
+    let mut lines = HashSet::new();
+    for comment in comments {
+        if let Comment::Line(loc, text) = comment {
+            let directive = text.trim_start_matches('/').split_whitespace().next();
+            match directive {
+                Some("scopelint-disable-line") => {
+                    lines.insert(offset_to_line(content, loc.start()));
+                }
+                Some("scopelint-disable-next-line") => {
+                    lines.insert(offset_to_line(content, loc.start()) + 1);
+                }
+                _ => (),
+            }
+        }
+    }
+    lines
 }
 
 // ===========================
@@ -181,7 +942,7 @@ pub struct ExpectedFindings {
     pub test: usize,
 }
 
-type ValidatorFn = dyn Fn(&Path, &str, &SourceUnit) -> Vec<InvalidItem>;
+type ValidatorFn = dyn Fn(&Parsed) -> Vec<InvalidItem>;
 
 impl ExpectedFindings {
     #[must_use]
@@ -204,14 +965,19 @@ impl ExpectedFindings {
     /// # Panics
     /// In practice this should not panic unless one of validations fails.
     pub fn assert_eq(&self, content: &str, validate: &ValidatorFn) {
-        let (pt, _comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let (pt, comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let config = Config::default();
+        let validate_file = |file: &str| {
+            let file = Path::new(file);
+            let kind = FileKind::of(file, &config.paths);
+            validate(&Parsed { file, kind, content, pt: &pt, comments: &comments, config: &config })
+        };
 
-        let invalid_items_script_helper =
-            validate(Path::new("./script/MyContract.sol"), content, &pt);
-        let invalid_items_script = validate(Path::new("./script/MyContract.s.sol"), content, &pt);
-        let invalid_items_src = validate(Path::new("./src/MyContract.sol"), content, &pt);
-        let invalid_items_test_helper = validate(Path::new("./test/MyContract.sol"), content, &pt);
-        let invalid_items_test = validate(Path::new("./test/MyContract.t.sol"), content, &pt);
+        let invalid_items_script_helper = validate_file("./script/MyContract.sol");
+        let invalid_items_script = validate_file("./script/MyContract.s.sol");
+        let invalid_items_src = validate_file("./src/MyContract.sol");
+        let invalid_items_test_helper = validate_file("./test/MyContract.sol");
+        let invalid_items_test = validate_file("./test/MyContract.t.sol");
 
         assert_eq!(invalid_items_script_helper.len(), self.script_helper);
         assert_eq!(invalid_items_script.len(), self.script);
@@ -220,3 +986,332 @@ impl ExpectedFindings {
         assert_eq!(invalid_items_test.len(), self.test);
     }
 }
+
+#[cfg(test)]
+/// Returns the invalid items the validator finds in `content`, as if it were the given `file` of a
+/// project with the given `config`. The file kind is determined from the file name.
+/// # Panics
+/// Panics if the content cannot be parsed.
+pub(crate) fn findings_with_config(
+    validate: &ValidatorFn,
+    file: &str,
+    content: &str,
+    config: &Config,
+) -> Vec<InvalidItem> {
+    let (pt, comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+    let file = Path::new(file);
+    let kind = FileKind::of(file, &config.paths);
+    validate(&Parsed { file, kind, content, pt: &pt, comments: &comments, config })
+}
+
+#[cfg(test)]
+/// Returns the messages of the invalid items, see [`findings_with_config`].
+pub(crate) fn messages_with_config(
+    validate: &ValidatorFn,
+    file: &str,
+    content: &str,
+    config: &Config,
+) -> Vec<String> {
+    findings_with_config(validate, file, content, config).iter().map(InvalidItem::message).collect()
+}
+
+#[cfg(test)]
+/// Returns the descriptions of the invalid items, including their location, see
+/// [`findings_with_config`].
+pub(crate) fn descriptions_with_config(
+    validate: &ValidatorFn,
+    file: &str,
+    content: &str,
+    config: &Config,
+) -> Vec<String> {
+    findings_with_config(validate, file, content, config)
+        .iter()
+        .map(InvalidItem::description)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solang_parser::pt::SourceUnitPart;
+
+    #[test]
+    fn test_snippet() {
+        colored::control::set_override(false);
+        let content = "\u{feff}contract C {\r\n\tuint256 constant bad_name = 1;\r\n}\r\n";
+        let item = |line, col| {
+            InvalidItem::new(ValidatorKind::Constant, "./src/C.sol".into(), "x".into(), line, col)
+                .with_source(content)
+        };
+
+        // The identifier at the column is underlined, and tabs are kept so the markers line up.
+        let expected = "  |\n2 | \tuint256 constant bad_name = 1;\n  | \t                 ^^^^^^^^";
+        assert_eq!(item(2, 19).snippet().unwrap(), expected);
+
+        // The BOM is not part of the first line, and other characters are underlined alone.
+        assert_eq!(item(1, 12).snippet().unwrap(), "  |\n1 | contract C {\n  |            ^");
+
+        // Items that span multiple lines have no snippet.
+        assert_eq!(item(0, 0).snippet(), None);
+    }
+
+    #[test]
+    fn test_with_template() {
+        let details = "MAX shadows the state variable inherited from `Base`".to_string();
+        let item = InvalidItem::new(ValidatorKind::Constant, "./src/C.sol".into(), details, 3, 5);
+        let template = "{name} breaks {{C-1}}, see {file}:{line}:{col} ({label}: {text})";
+        let expected = "Invalid constant or immutable name in ./src/C.sol:3:5: MAX breaks {C-1}, \
+                        see ./src/C.sol:3:5 (Invalid constant or immutable name: MAX shadows the \
+                        state variable inherited from `Base`)";
+        assert_eq!(item.clone().with_template(Some(template)).description(), expected);
+
+        // Without a template, the built-in details are shown.
+        assert_eq!(item.clone().with_template(None).details(), item.text());
+    }
+
+    #[test]
+    fn test_with_signature() {
+        let content = "function transfer(address to, uint amount, bytes memory data) {}";
+        let (pt, _comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let SourceUnitPart::FunctionDefinition(f) = &pt.0[0] else { panic!("Not a function") };
+        assert_eq!(signature(content, f), "transfer(address,uint256,bytes)");
+
+        // The signature replaces the name that the details start with, but not the text.
+        let text = "transfer has no NatSpec comment".to_string();
+        let item = InvalidItem::new(ValidatorKind::MissingDoc, "./src/C.sol".into(), text, 3, 5)
+            .with_signature(signature(content, f));
+        assert_eq!(item.details(), "transfer(address,uint256,bytes) has no NatSpec comment");
+        assert_eq!(item.text(), "transfer has no NatSpec comment");
+        assert_eq!(item.signature(), Some("transfer(address,uint256,bytes)"));
+        let item = item.with_template(Some("{signature} needs docs ({name})"));
+        assert_eq!(item.details(), "transfer(address,uint256,bytes) needs docs (transfer)");
+
+        // Details that do not start with the name are unchanged.
+        let text = "`@param to` is not a parameter of transfer".to_string();
+        let item = InvalidItem::new(ValidatorKind::NatspecTag, "./src/C.sol".into(), text, 3, 5)
+            .with_signature(signature(content, f));
+        assert_eq!(item.details(), item.text());
+    }
+
+    #[test]
+    fn test_render_template() {
+        let value = |placeholder: &str| (placeholder == "name").then(|| "MAX".to_string());
+        assert_eq!(render_template("{name} is invalid", value).unwrap(), "MAX is invalid");
+        assert_eq!(render_template("{{name}}", value).unwrap(), "{name}");
+        assert_eq!(render_template("{other}", value).unwrap_err(), "unknown placeholder `{other}`");
+        assert_eq!(render_template("{name", value).unwrap_err(), "unclosed `{`");
+        assert!(render_template("name}", value).is_err());
+    }
+
+    #[test]
+    fn test_invalid_item_order() {
+        let item = |kind: ValidatorKind, file: &str, line: usize, col: usize| {
+            InvalidItem::new(kind, file.to_string(), "name".to_string(), line, col)
+        };
+        let mut items = vec![
+            item(ValidatorKind::Constant, "./src/B.sol", 1, 1),
+            item(ValidatorKind::Test, "./src/A.sol", 2, 1),
+            item(ValidatorKind::Constant, "./src/A.sol", 3, 1),
+            item(ValidatorKind::Constant, "./src/A.sol", 2, 5),
+            item(ValidatorKind::Src, "./src/A.sol", 2, 1),
+            item(ValidatorKind::Script, "./src/A.sol", 0, 0),
+        ];
+        items.sort();
+
+        // Items are sorted by file, line, and column, and items at the same location by kind.
+        let expected = vec![
+            item(ValidatorKind::Script, "./src/A.sol", 0, 0),
+            item(ValidatorKind::Src, "./src/A.sol", 2, 1),
+            item(ValidatorKind::Test, "./src/A.sol", 2, 1),
+            item(ValidatorKind::Constant, "./src/A.sol", 2, 5),
+            item(ValidatorKind::Constant, "./src/A.sol", 3, 1),
+            item(ValidatorKind::Constant, "./src/B.sol", 1, 1),
+        ];
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn test_validator_kind_from_name() {
+        assert_eq!(ValidatorKind::from_name("Constant"), Some(ValidatorKind::Constant));
+        assert_eq!(ValidatorKind::from_name("constant"), Some(ValidatorKind::Constant));
+        assert_eq!(ValidatorKind::from_name("src-public"), Some(ValidatorKind::SrcPublic));
+        assert_eq!(ValidatorKind::from_name("SRC_PUBLIC"), Some(ValidatorKind::SrcPublic));
+        assert_eq!(ValidatorKind::from_name("constants"), None);
+    }
+
+    #[test]
+    fn test_validator_kind_all() {
+        assert!(ValidatorKind::ALL.is_sorted());
+        for kind in ValidatorKind::ALL {
+            assert!(!kind.label().is_empty() && kind.description().ends_with('.'), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn test_offset_to_line() {
+        let content = "a\nbc\n\nd";
+        let expected_lines = [1, 1, 2, 2, 2, 3, 4, 4];
+        for (offset, expected) in expected_lines.into_iter().enumerate() {
+            assert_eq!(offset_to_line(content, offset), expected, "offset {offset}");
+        }
+
+        // Offsets at or past the end of the content are on the last line.
+        assert_eq!(offset_to_line(content, 100), 4);
+        assert_eq!(offset_to_line("", 0), 1);
+
+        // Multi-byte characters take up multiple byte offsets.
+        let content = "// héllo wörld 🌍\nuint256 constant x = 1;";
+        let offset = content.find("uint256").unwrap();
+        assert_eq!(offset_to_line(content, offset - 1), 1);
+        assert_eq!(offset_to_line(content, offset), 2);
+    }
+
+    #[test]
+    fn test_is_pascal_case() {
+        let allowed_names = ["Transfer", "ERC20Transfer", "T", "Transfer2", "OwnershipTransferred"];
+        let disallowed_names =
+            ["transfer", "Transfer_Event", "_Transfer", "TRANSFER_EVENT", "$T", ""];
+
+        for name in allowed_names {
+            assert!(is_pascal_case(name), "{name}");
+        }
+        for name in disallowed_names {
+            assert!(!is_pascal_case(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_is_mixed_case() {
+        let allowed_names = ["onlyOwner", "whenNotPaused", "only", "onlyRole2"];
+        let disallowed_names = ["OnlyOwner", "only_owner", "_onlyOwner", "ONLY_OWNER", "$only", ""];
+
+        for name in allowed_names {
+            assert!(is_mixed_case(name), "{name}");
+        }
+        for name in disallowed_names {
+            assert!(!is_mixed_case(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_offset_to_line_col() {
+        let content = "a\nbc\n\nd";
+        let expected = [(1, 1), (1, 2), (2, 1), (2, 2), (2, 3), (3, 1), (4, 1), (4, 2)];
+        for (offset, expected) in expected.into_iter().enumerate() {
+            assert_eq!(offset_to_line_col(content, offset), expected, "offset {offset}");
+        }
+
+        // Columns count characters, not bytes.
+        let content = "contract C { /* ü 🌍 */ uint256 constant x = 1; }";
+        let offset = content.find("uint256").unwrap();
+        assert_eq!(offset_to_line_col(content, offset), (1, 24));
+
+        // A leading BOM does not shift the columns of the first line.
+        let content = "\u{feff}contract C {}\nuint256 constant x = 1;";
+        assert_eq!(offset_to_line_col(content, content.find('C').unwrap()), (1, 10));
+        assert_eq!(offset_to_line_col(content, content.find("uint256").unwrap()), (2, 1));
+    }
+
+    #[test]
+    fn test_offset_to_line_col_crlf() {
+        let content = "a\r\nbc\r\n\r\nd";
+        let expected = [
+            (1, 1),
+            (1, 2),
+            (1, 3),
+            (2, 1),
+            (2, 2),
+            (2, 3),
+            (2, 4),
+            (3, 1),
+            (3, 2),
+            (4, 1),
+            (4, 2),
+        ];
+        for (offset, expected) in expected.into_iter().enumerate() {
+            assert_eq!(offset_to_line_col(content, offset), expected, "offset {offset}");
+        }
+
+        let content = "\u{feff}contract C {\r\n    uint256 constant x = 1;\r\n}\r\n";
+        let offset = content.find('x').unwrap();
+        assert_eq!(offset_to_line_col(content, offset), (2, 22));
+        assert_eq!(offset_to_line(content, content.len()), 4);
+    }
+
+    #[test]
+    fn test_parse() {
+        let content = "\u{feff}contract C {\r\n    uint256 constant x = 1;\r\n}\r\n";
+        let (pt, _comments) = parse(content).expect("Parsing failed");
+        let SourceUnitPart::ContractDefinition(c) = &pt.0[0] else { panic!("Expected a contract") };
+        let name = c.name.as_ref().unwrap();
+        assert_eq!(&content[name.loc.start()..name.loc.end()], "C");
+        assert!(parse("\u{feff}contract {").is_err());
+    }
+
+    #[test]
+    fn test_file_kind_of() {
+        let paths = Config::default().paths;
+        let kind_of = |file: &str| FileKind::of(Path::new(file), &paths);
+        assert_eq!(kind_of("./script/Counter.s.sol"), Some(FileKind::Script));
+        assert_eq!(kind_of("./src/Counter.sol"), Some(FileKind::Src));
+        assert_eq!(kind_of("./test/Counter.t.sol"), Some(FileKind::Test));
+
+        // Test files are identified by their extension, regardless of the directory.
+        assert_eq!(kind_of("./src/Counter.t.sol"), Some(FileKind::Test));
+        assert_eq!(kind_of("./src/helpers/Counter.t.sol"), Some(FileKind::Test));
+
+        // Helpers are not any of the kinds.
+        assert_eq!(kind_of("./script/ScriptHelpers.sol"), None);
+        assert_eq!(kind_of("./test/TestHelpers.sol"), None);
+
+        // Scripts need the `.s.sol` extension, and other directories and extensions are not kinds.
+        assert_eq!(kind_of("./script/Deploy.sol"), None);
+        assert_eq!(kind_of("./src/Deploy.s.sol"), Some(FileKind::Src));
+        assert_eq!(kind_of("./lib/Counter.sol"), None);
+        assert_eq!(kind_of("./src/Counter.vy"), None);
+        assert_eq!(kind_of("./src/Counter.sol.bak"), None);
+
+        // The extension needs a file name before it.
+        assert_eq!(kind_of("./test/.t.sol"), None);
+        assert_eq!(kind_of("./src/.sol"), None);
+        assert!(Path::new("./src/Counter.t.sol").is_file_kind(FileKind::Test, &paths));
+    }
+
+    #[test]
+    fn test_is_solidity_file() {
+        assert!(is_solidity_file(Path::new("./src/Counter.sol")));
+        assert!(is_solidity_file(Path::new("Counter.t.sol")));
+        assert!(!is_solidity_file(Path::new("./src/Counter.vy")));
+        assert!(!is_solidity_file(Path::new("./src/sol")));
+        assert!(!is_solidity_file(Path::new("./src/Counter.SOL")));
+        assert!(is_vyper_file(Path::new("./src/Counter.vy")));
+        assert!(is_vyper_file(Path::new("./src/ICounter.vyi")));
+        assert!(!is_vyper_file(Path::new("./src/Counter.sol")));
+
+        // File names that are not valid UTF-8 are neither, instead of panicking.
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let file = Path::new(OsStr::from_bytes(b"./src/Counter\xff.sol"));
+            assert!(!is_solidity_file(file));
+            assert_eq!(FileKind::of(file, &Config::default().paths), None);
+        }
+    }
+
+    #[test]
+    fn test_disabled_lines() {
+        let content = r"
+            contract MyContract {
+                // scopelint-disable-next-line
+                uint256 constant disabledNextLine = 1;
+                uint256 constant disabledLine = 2; // scopelint-disable-line
+                uint256 constant notDisabled = 3; // scopelint-disable-line-typo
+                // scopelint-disable-next-line With a reason.
+                uint256 constant alsoDisabled = 4;
+            }
+        ";
+        let (_pt, comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let expected_lines = HashSet::from([4, 5, 8]);
+        assert_eq!(disabled_lines(content, &comments), expected_lines);
+    }
+}
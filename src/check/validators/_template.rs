@@ -1,20 +1,30 @@
-use crate::check::utils::{offset_to_line, InvalidItem, Validator};
-use solang_parser::pt::{
-    ContractPart, SourceUnit, SourceUnitPart, VariableAttribute, VariableDefinition,
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
 };
-use std::path::Path;
+use solang_parser::pt::{ContractPart, SourceUnitPart, VariableAttribute, VariableDefinition};
 
-const fn is_matching_file(_file: &Path) -> bool {
+const fn is_matching_file(_parsed: &Parsed) -> bool {
     true // Update the matching condition, see helpers in `src/check/utils.rs`.
 }
 
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MyKind, // Add one explanation for each kind the validator reports.
+    rationale: "<why the convention is enforced>.",
+    valid: &["<a name or code that follows the convention>"],
+    invalid: &["<a name or code that is reported>"],
+}];
+
 #[must_use]
 /// Validates that <explain validator>.
-pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem> {
-    if !is_matching_file(file) {
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
         return Vec::new()
     }
 
+    let Parsed { file, content, pt, .. } = parsed;
+
     let mut invalid_items: Vec<InvalidItem> = Vec::new();
     // Only edit below here to add your own validation logic.
     for element in &pt.0 {
@@ -25,7 +35,8 @@ pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem>
     invalid_items
 }
 
-// Add any helper methods here. The `validate` method should be the only public method.
+// Add any helper methods here. The `validate` method and `EXPLANATIONS` should be the only public
+// items.
 
 #[cfg(test)]
 mod tests {
@@ -34,12 +45,12 @@ mod tests {
 
     #[test]
     fn test_validate() {
-        let content = r#"
+        let content = r"
             contract MyContract {
                 // Fill in one or more sample contracts. See `script_one_pubic_run_method.rs` for
                 // an example of testing more contracts.
             }
-        "#;
+        ";
 
         let expected_findings = ExpectedFindings::new(0);
         expected_findings.assert_eq(content, &validate);
@@ -0,0 +1,104 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use regex::Regex;
+use solang_parser::pt::{ContractDefinition, ContractTy, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.abstract_contract_pattern.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::AbstractContract,
+    rationale: "A naming pattern tells abstract contracts apart from deployable ones. Nothing is \
+                checked unless `abstract_contract_pattern` is set, and these examples use \
+                `^(Abstract|Base)[A-Z]`.",
+    valid: &["abstract contract BaseVault {}", "abstract contract AbstractToken {}"],
+    invalid: &["abstract contract Vault {}"],
+}];
+
+#[must_use]
+/// Validates that abstract contract names match `abstract_contract_pattern`, e.g. to require a
+/// `Base` or `Abstract` prefix. Nothing is checked unless the pattern is configured.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(pattern) = &config.abstract_contract_pattern else { return Vec::new() };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            if let Some(invalid_item) = validate_name(file, content, pattern, c) {
+                invalid_items.push(invalid_item);
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    pattern: &Regex,
+    c: &ContractDefinition,
+) -> Option<InvalidItem> {
+    let name = c.name.as_ref()?;
+    if !matches!(c.ty, ContractTy::Abstract(_)) || pattern.is_match(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::AbstractContract,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, pattern: Option<&str>) -> Vec<String> {
+        let config = Config {
+            abstract_contract_pattern: pattern.map(|pattern| Regex::new(pattern).unwrap()),
+            ..Config::default()
+        };
+        descriptions_with_config(&validate, "./src/MyContract.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // These match the pattern and should be valid.
+            abstract contract BaseCounter {}
+            abstract contract AbstractToken {}
+
+            // These do not match the pattern and should be invalid.
+            abstract contract Counter {}
+            abstract contract CounterBase {}
+
+            // Only abstract contracts are checked.
+            contract Token {}
+            interface IToken {}
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Invalid abstract contract name in ./src/MyContract.sol:7:31: Counter",
+            "Invalid abstract contract name in ./src/MyContract.sol:8:31: CounterBase",
+        ];
+        assert_eq!(findings(content, Some(r"^(Abstract|Base)[A-Z]\w*$")), expected);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::check::{
+    utils::{for_each_statement, offset_to_line_col, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{
+    Comment, ContractPart, FunctionDefinition, Loc, SourceUnitPart, Statement,
+};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.assembly_comments == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Assembly,
+    rationale: "Inline assembly skips the safety checks of Solidity, so reviewers and auditors \
+                need to know why it is used, and what it relies on. Any comment directly above \
+                the block counts. This is disabled by default, and enabled with \
+                `assembly_comments = true`.",
+    valid: &["// Reads the free memory pointer, which Solidity does not expose.\n\
+              assembly {\n    ptr := mload(0x40)\n}"],
+    invalid: &["assembly {\n    ptr := mload(0x40)\n}"],
+}];
+
+#[must_use]
+/// Validates that each inline assembly block in a function, modifier, or constructor body has a
+/// comment directly above it, explaining why it is needed.
+///
+/// Any kind of comment counts, as long as only whitespace separates it from the `assembly`
+/// keyword. Blocks nested in other statements, such as `if` or `unchecked` blocks, are checked too.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, comments, .. } = parsed;
+
+    let mut functions: Vec<&FunctionDefinition> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::ContractDefinition(c) => {
+                functions.extend(c.parts.iter().filter_map(|el| match el {
+                    ContractPart::FunctionDefinition(f) => Some(f.as_ref()),
+                    _ => None,
+                }));
+            }
+            SourceUnitPart::FunctionDefinition(f) => functions.push(f),
+            _ => {}
+        }
+    }
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for f in functions {
+        let Some(body) = &f.body else { continue };
+        let mut blocks: Vec<Loc> = Vec::new();
+        for_each_statement(body, &mut |statement| {
+            if let Statement::Assembly { loc, .. } = statement {
+                blocks.push(*loc);
+            }
+        });
+        for block in blocks {
+            if !has_comment_above(content, comments, block.start()) {
+                invalid_items.push(invalid_item(file, content, f, block));
+            }
+        }
+    }
+    invalid_items
+}
+
+fn invalid_item(file: &Path, content: &str, f: &FunctionDefinition, block: Loc) -> InvalidItem {
+    let (line, col) = offset_to_line_col(content, block.start());
+    let text = format!("assembly block in {} has no comment explaining it", f.name());
+    InvalidItem::new(ValidatorKind::Assembly, file.display().to_string(), text, line, col)
+}
+
+// Returns `true` if a comment ends before `start` with only whitespace in between.
+fn has_comment_above(content: &str, comments: &[Comment], start: usize) -> bool {
+    comments.iter().any(|comment| {
+        let loc = match comment {
+            Comment::Line(loc, _) |
+            Comment::Block(loc, _) |
+            Comment::DocLine(loc, _) |
+            Comment::DocBlock(loc, _) => loc,
+        };
+        loc.end() <= start && content[loc.end()..start].trim().is_empty()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, assembly_comments: Option<bool>) -> Vec<String> {
+        let config = Config { assembly_comments, ..Config::default() };
+        descriptions_with_config(&validate, "./src/Counter.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r#"
+            contract Counter {
+                function read() public view returns (uint256 ptr) {
+                    // Reads the free memory pointer.
+                    assembly {
+                        ptr := mload(0x40)
+                    }
+                    /* Memory safe, since it only reads. */ assembly ("memory-safe") {}
+                    uint256 x = 1;
+                    assembly {}
+                }
+
+                modifier check() {
+                    if (true) {
+                        unchecked { assembly {} }
+                    }
+                    _;
+                }
+            }
+
+            function helper() {
+                /// Doc comments count too.
+                assembly {}
+                for (uint256 i; i < 1; i++) { assembly {} }
+            }
+        "#;
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Assembly block without a comment in ./src/Counter.sol:10:21: assembly block in read has no comment explaining it",
+            "Assembly block without a comment in ./src/Counter.sol:15:37: assembly block in check has no comment explaining it",
+            "Assembly block without a comment in ./src/Counter.sol:24:47: assembly block in helper has no comment explaining it",
+        ];
+        assert_eq!(findings(content, Some(true)), expected);
+    }
+}
@@ -1,38 +1,121 @@
-use crate::check::utils::{offset_to_line, InvalidItem, ValidatorKind};
-use once_cell::sync::Lazy;
-use regex::Regex;
-use solang_parser::pt::{
-    ContractPart, SourceUnit, SourceUnitPart, VariableAttribute, VariableDefinition,
+use crate::{
+    check::{
+        utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+        validators::Explanation,
+    },
+    config::Config,
 };
-use std::path::Path;
+use regex::Regex;
+use solang_parser::pt::{ContractPart, SourceUnitPart, VariableAttribute, VariableDefinition};
+use std::{fmt, path::Path, sync::LazyLock};
+
+// The default regex matching valid constant and immutable names, see `VALID_NAMES` and
+// `INVALID_NAMES` for examples. This can be overridden with `constant_name_pattern` and
+// `immutable_name_pattern` in `.scopelint.toml`.
+static RE_VALID_CONSTANT_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:[$_]*[A-Z0-9][$_]*){1,}$").unwrap());
+
+/// Decides whether constant and immutable names are valid, in place of `constant_name_pattern` and
+/// `immutable_name_pattern`.
+///
+/// Set a rule with [`Config::constant_name_rule`] when using scopelint as a library, for
+/// conventions that a regex cannot express, such as a prefix that depends on the variable's type.
+/// Closures that take the name and the variable definition are rules.
+pub trait ConstantNameRule: Send + Sync {
+    /// Returns `true` if `name` is a valid name for the constant or immutable variable `v`.
+    fn is_valid(&self, name: &str, v: &VariableDefinition) -> bool;
+}
 
-// A regex matching valid constant names, see the `validate_constant_names_regex` test for examples.
-static RE_VALID_CONSTANT_NAME: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^(?:[$_]*[A-Z0-9][$_]*){1,}$").unwrap());
+impl<F> ConstantNameRule for F
+where
+    F: Fn(&str, &VariableDefinition) -> bool + Send + Sync,
+{
+    fn is_valid(&self, name: &str, v: &VariableDefinition) -> bool {
+        self(name, v)
+    }
+}
+
+impl fmt::Debug for dyn ConstantNameRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ConstantNameRule")
+    }
+}
 
-const fn is_matching_file(_file: &Path) -> bool {
+const fn is_matching_file(_parsed: &Parsed) -> bool {
     true
 }
 
+// Examples of constant and immutable names that the default pattern accepts.
+const VALID_NAMES: [&str; 20] = [
+    "MAX_UINT256",
+    "256_MAXUINT",
+    "256_MAX_11_UINT",
+    "VARIABLE",
+    "VARIABLE_NAME",
+    "VARIABLE_NAME_",
+    "VARIABLE___NAME",
+    "VARIABLE_NAME_WOW",
+    "VARIABLE_NAME_WOW_AS_MANY_UNDERSCORES_AS_YOU_WANT",
+    "__VARIABLE",
+    "_VARIABLE__NAME",
+    "_VARIABLE_NAME__",
+    "_VARIABLE_NAME_WOW",
+    "_VARIABLE_NAME_WOW_AS_MANY_UNDERSCORES_AS_YOU_WANT",
+    "$VARIABLE_NAME",
+    "_$VARIABLE_NAME_",
+    "$_VARIABLE_NAME$",
+    "_$VARIABLE_NAME$_",
+    "$_VARIABLE_NAME_$",
+    "$_VARIABLE__NAME_",
+];
+
+// Examples of constant and immutable names that the default pattern rejects.
+const INVALID_NAMES: [&str; 10] = [
+    "variable",
+    "variableName",
+    "_variable",
+    "_variable_Name",
+    "VARIABLe",
+    "VARIABLE_name",
+    "_VARIABLe",
+    "_VARIABLE_name",
+    "$VARIABLe",
+    "$VARIABLE_name",
+];
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Constant,
+    rationale:
+        "Writing constants and immutables in `ALL_CAPS` shows that they cannot change after \
+         deployment. Digits, underscores, and `$` are allowed anywhere. Override the convention \
+         with `constant_name_pattern`, and for immutables with `immutable_name_pattern`.",
+    valid: &VALID_NAMES,
+    invalid: &INVALID_NAMES,
+}];
+
 #[must_use]
-/// Validates that constant and immutable variable names are in `ALL_CAPS`.
-pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem> {
-    if !is_matching_file(file) {
+/// Validates that constant and immutable variable names are in `ALL_CAPS`, or match the patterns
+/// configured for each.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
         return Vec::new()
     }
 
+    let Parsed { file, content, pt, config, .. } = parsed;
+
     let mut invalid_items: Vec<InvalidItem> = Vec::new();
     for element in &pt.0 {
         match element {
             SourceUnitPart::VariableDefinition(v) => {
-                if let Some(invalid_item) = validate_name(file, content, v) {
+                if let Some(invalid_item) = validate_name(file, content, config, v) {
                     invalid_items.push(invalid_item);
                 }
             }
             SourceUnitPart::ContractDefinition(c) => {
                 for el in &c.parts {
                     if let ContractPart::VariableDefinition(v) = el {
-                        if let Some(invalid_item) = validate_name(file, content, v) {
+                        if let Some(invalid_item) = validate_name(file, content, config, v) {
                             invalid_items.push(invalid_item);
                         }
                     }
@@ -44,37 +127,68 @@ pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem>
     invalid_items
 }
 
-fn is_valid_constant_name(name: &str) -> bool {
-    RE_VALID_CONSTANT_NAME.is_match(name)
+pub(crate) fn constant_name_pattern(config: &Config) -> &Regex {
+    config.constant_name_pattern.as_ref().unwrap_or(&RE_VALID_CONSTANT_NAME)
+}
+
+// Immutables use the constant pattern unless they have their own, so configs that only set
+// `constant_name_pattern` keep applying it to both.
+pub(crate) fn immutable_name_pattern(config: &Config) -> &Regex {
+    config.immutable_name_pattern.as_ref().unwrap_or_else(|| constant_name_pattern(config))
+}
+
+// Returns the regex the name of `v` must match, or `None` if it is not a constant or immutable.
+pub(crate) fn name_pattern<'a>(v: &VariableDefinition, config: &'a Config) -> Option<&'a Regex> {
+    v.attrs.iter().find_map(|a| match a {
+        VariableAttribute::Constant(_) => Some(constant_name_pattern(config)),
+        VariableAttribute::Immutable(_) => Some(immutable_name_pattern(config)),
+        _ => None,
+    })
+}
+
+// Returns `true` if the name of `v` is valid, using the configured rule if there is one, or `None`
+// if it is not a constant or immutable.
+fn is_valid_name(v: &VariableDefinition, config: &Config) -> Option<bool> {
+    let pattern = name_pattern(v, config)?;
+    let name = &v.name.as_ref()?.name;
+    Some(
+        config
+            .constant_name_rule
+            .as_ref()
+            .map_or_else(|| pattern.is_match(name), |rule| rule.is_valid(name, v)),
+    )
 }
 
-fn validate_name(file: &Path, content: &str, v: &VariableDefinition) -> Option<InvalidItem> {
-    let is_constant = v
-        .attrs
-        .iter()
-        .any(|a| matches!(a, VariableAttribute::Constant(_) | VariableAttribute::Immutable(_)));
-    let name = &v.name.as_ref().unwrap().name;
-
-    if is_constant && !is_valid_constant_name(name) {
-        Some(InvalidItem::new(
-            ValidatorKind::Constant,
-            file.display().to_string(),
-            name.clone(),
-            offset_to_line(content, v.loc.start()),
-        ))
-    } else {
-        None
+fn validate_name(
+    file: &Path,
+    content: &str,
+    config: &Config,
+    v: &VariableDefinition,
+) -> Option<InvalidItem> {
+    if is_valid_name(v, config)? {
+        return None
     }
+    let name = v.name.as_ref()?;
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::Constant,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::check::utils::ExpectedFindings;
+    use crate::check::utils::{messages_with_config, ExpectedFindings};
+    use solang_parser::pt::{Expression, Type};
 
     #[test]
     fn test_validate() {
-        let content = r#"
+        let content = r"
             contract MyContract {
                 // These have the constant or immutable keyword and should be valid.
                 uint256 constant MAX_UINT256 = type(uint256).max;
@@ -88,7 +202,7 @@ mod tests {
                 address alice = address(123);
                 uint256 aliceBalance = 500;
             }
-        "#;
+        ";
 
         let expected_findings = ExpectedFindings::new(2);
         expected_findings.assert_eq(content, &validate);
@@ -96,48 +210,74 @@ mod tests {
 
     #[test]
     fn test_is_valid_constant_name() {
-        let allowed_names = vec![
-            "MAX_UINT256",
-            "256_MAXUINT",
-            "256_MAX_11_UINT",
-            "VARIABLE",
-            "VARIABLE_NAME",
-            "VARIABLE_NAME_",
-            "VARIABLE___NAME",
-            "VARIABLE_NAME_WOW",
-            "VARIABLE_NAME_WOW_AS_MANY_UNDERSCORES_AS_YOU_WANT",
-            "__VARIABLE",
-            "_VARIABLE__NAME",
-            "_VARIABLE_NAME__",
-            "_VARIABLE_NAME_WOW",
-            "_VARIABLE_NAME_WOW_AS_MANY_UNDERSCORES_AS_YOU_WANT",
-            "$VARIABLE_NAME",
-            "_$VARIABLE_NAME_",
-            "$_VARIABLE_NAME$",
-            "_$VARIABLE_NAME$_",
-            "$_VARIABLE_NAME_$",
-            "$_VARIABLE__NAME_",
-        ];
+        let config = Config::default();
+        for name in VALID_NAMES {
+            assert!(constant_name_pattern(&config).is_match(name), "{name}");
+        }
+
+        for name in INVALID_NAMES {
+            assert!(!constant_name_pattern(&config).is_match(name), "{name}");
+        }
+    }
 
-        let disallowed_names = [
-            "variable",
-            "variableName",
-            "_variable",
-            "_variable_Name",
-            "VARIABLe",
-            "VARIABLE_name",
-            "_VARIABLe",
-            "_VARIABLE_name",
-            "$VARIABLe",
-            "$VARIABLE_name",
+    #[test]
+    fn test_validate_with_immutable_pattern() {
+        let content = r"
+            contract MyContract {
+                uint256 constant MAX_SUPPLY = 100;
+                uint256 constant maxSupply = 100;
+                uint256 immutable iStartTime;
+                uint256 immutable START_TIME;
+            }
+        ";
+        let config = Config {
+            immutable_name_pattern: Some(Regex::new(r"^i[A-Z][a-zA-Z0-9]*$").unwrap()),
+            ..Config::default()
+        };
+        let names = messages_with_config(&validate, "./src/MyContract.sol", content, &config);
+        let expected = vec![
+            "Invalid constant or immutable name: maxSupply",
+            "Invalid constant or immutable name: START_TIME",
         ];
+        assert_eq!(names, expected);
+    }
 
-        for name in allowed_names {
-            assert_eq!(is_valid_constant_name(name), true, "{name}");
-        }
+    #[test]
+    fn test_is_valid_constant_name_with_config() {
+        let config = Config {
+            constant_name_pattern: Some(Regex::new(r"^k[A-Z][a-zA-Z0-9]*$").unwrap()),
+            ..Config::default()
+        };
+        assert!(constant_name_pattern(&config).is_match("kMaxUint"));
+        assert!(!constant_name_pattern(&config).is_match("MAX_UINT"));
+        // Immutables use the constant pattern when they have no pattern of their own.
+        assert!(immutable_name_pattern(&config).is_match("kMaxUint"));
+    }
 
-        for name in disallowed_names {
-            assert_eq!(is_valid_constant_name(name), false, "{name}");
-        }
+    #[test]
+    fn test_validate_with_rule() {
+        // Constants of type `address` must be prefixed with `A_`, and other constants must not be.
+        let rule = |name: &str, v: &VariableDefinition| {
+            let is_address = matches!(v.ty, Expression::Type(_, Type::Address));
+            is_address == name.starts_with("A_")
+        };
+        let content = r"
+            contract MyContract {
+                address constant A_OWNER = address(1);
+                uint256 constant MAX_SUPPLY = 100;
+                address constant ADMIN = address(2);
+                uint256 constant A_MAX = 1;
+
+                // The rule only applies to constants and immutables.
+                address admin;
+            }
+        ";
+        let config = Config { constant_name_rule: Some(Box::new(rule)), ..Config::default() };
+        let names = messages_with_config(&validate, "./src/MyContract.sol", content, &config);
+        let expected = vec![
+            "Invalid constant or immutable name: ADMIN",
+            "Invalid constant or immutable name: A_MAX",
+        ];
+        assert_eq!(names, expected);
     }
 }
@@ -0,0 +1,110 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use regex::Regex;
+use solang_parser::pt::{ContractDefinition, ContractPart, FunctionTy, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.constructor_parameter_pattern.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::ConstructorParameter,
+    rationale: "A naming pattern keeps constructor parameters from colliding with the state \
+                variables they initialize. Nothing is checked unless \
+                `constructor_parameter_pattern` is set, and these examples use \
+                `^[a-z][a-zA-Z0-9]*_$`. Unnamed parameters are not checked.",
+    valid: &["constructor(address owner_) {}"],
+    invalid: &["constructor(address _owner) {}", "constructor(address owner) {}"],
+}];
+
+#[must_use]
+/// Validates that constructor parameter names match `constructor_parameter_pattern`, e.g. a
+/// trailing underscore like `owner_`, so they do not collide with the variables they initialize.
+///
+/// Unnamed parameters are not checked.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(pattern) = &config.constructor_parameter_pattern else { return Vec::new() };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            invalid_items.extend(validate_contract(file, content, pattern, c));
+        }
+    }
+    invalid_items
+}
+
+fn validate_contract(
+    file: &Path,
+    content: &str,
+    pattern: &Regex,
+    c: &ContractDefinition,
+) -> Vec<InvalidItem> {
+    let contract = c.name.as_ref().map_or("", |name| name.name.as_str());
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for el in &c.parts {
+        let ContractPart::FunctionDefinition(f) = el else { continue };
+        if f.ty != FunctionTy::Constructor {
+            continue
+        }
+        for (_, param) in &f.params {
+            let Some(name) = param.as_ref().and_then(|param| param.name.as_ref()) else { continue };
+            if pattern.is_match(&name.name) {
+                continue
+            }
+
+            let (line, col) = offset_to_line_col(content, name.loc.start());
+            let text = format!("{} in the constructor of {contract}", name.name);
+            let file = file.display().to_string();
+            let kind = ValidatorKind::ConstructorParameter;
+            invalid_items.push(InvalidItem::new(kind, file, text, line, col));
+        }
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, pattern: Option<&str>) -> Vec<String> {
+        let config = Config {
+            constructor_parameter_pattern: pattern.map(|pattern| Regex::new(pattern).unwrap()),
+            ..Config::default()
+        };
+        descriptions_with_config(&validate, "./src/Counter.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract Counter {
+                address immutable owner;
+                uint256 immutable start;
+
+                // Only the parameters of constructors are checked, and unnamed ones are skipped.
+                constructor(address owner_, uint256 start, bytes memory) {}
+                function setOwner(address owner) external {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Invalid constructor parameter name in ./src/Counter.sol:7:53: start in the constructor of Counter",
+        ];
+        assert_eq!(findings(content, Some("^[a-z][a-zA-Z0-9]*_$")), expected);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::check::{
+    utils::{offset_to_line_col, FileKind, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{Identifier, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    let config = parsed.config;
+    parsed.is_file_kind(FileKind::Src) &&
+        (config.contracts_per_file == Some(true) || config.contract_file_names == Some(true))
+}
+
+/// Explains the conventions this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 2] = [
+    Explanation {
+        kind: ValidatorKind::ContractFileName,
+        rationale: "Naming each file after its contract makes contracts easy to find, and matches \
+                    how imports are usually written. Files that declare no contracts are not \
+                    checked. This is disabled by default, and enabled with `contract_file_names = \
+                    true`. These examples are in `src/Counter.sol`.",
+        valid: &["contract Counter {}"],
+        invalid: &["contract Token {}"],
+    },
+    Explanation {
+        kind: ValidatorKind::ContractPerFile,
+        rationale: "A single contract per file keeps files small, and their names meaningful. \
+                    Interfaces and libraries count as contracts too. This is disabled by default, \
+                    and enabled with `contracts_per_file = true`.",
+        valid: &["contract Counter {}"],
+        invalid: &["interface ICounter {}\ncontract Counter is ICounter {}"],
+    },
+];
+
+#[must_use]
+/// Validates that files in `src/` declare at most one contract, interface, or library, with
+/// `contracts_per_file`, and that one of them is named after the file, with
+/// `contract_file_names`.
+///
+/// Both are disabled by default, and enabled separately. The first extra contract is reported for
+/// files with several, and the first contract for files that are not named after any of them.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let names: Vec<&Identifier> =
+        pt.0.iter()
+            .filter_map(|element| match element {
+                SourceUnitPart::ContractDefinition(c) => c.name.as_ref(),
+                _ => None,
+            })
+            .collect();
+
+    let item = |kind: ValidatorKind, name: &Identifier, text: String| {
+        let (line, col) = offset_to_line_col(content, name.loc.start());
+        InvalidItem::new(kind, file.display().to_string(), text, line, col)
+    };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    if config.contract_file_names == Some(true) {
+        if let Some(first) = names.first() {
+            let stem = file_stem(file);
+            if !names.iter().any(|name| name.name == stem) {
+                let text = format!("{} does not match the file name `{stem}`", first.name);
+                invalid_items.push(item(ValidatorKind::ContractFileName, first, text));
+            }
+        }
+    }
+    if config.contracts_per_file == Some(true) {
+        if let Some(second) = names.get(1) {
+            let text = format!(
+                "{} is one of {} contracts in the file, which should only have one",
+                second.name,
+                names.len()
+            );
+            invalid_items.push(item(ValidatorKind::ContractPerFile, second, text));
+        }
+    }
+    invalid_items
+}
+
+// Returns the name of `file` without its extension, e.g. `Counter` for `./src/Counter.sol`.
+fn file_stem(file: &Path) -> String {
+    file.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, per_file: bool, file_names: bool) -> Vec<String> {
+        let config = Config {
+            contracts_per_file: Some(per_file),
+            contract_file_names: Some(file_names),
+            ..Config::default()
+        };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = "interface ICounter {}\ncontract Counter is ICounter {}\nlibrary Math {}";
+        let file = "./src/Counter.sol";
+
+        // The checks are disabled by default, and enabled separately.
+        assert!(findings(file, content, false, false).is_empty());
+        let expected = vec![
+            "Multiple contracts in one file: Counter is one of 3 contracts in the file, which should only have one",
+        ];
+        assert_eq!(findings(file, content, true, false), expected);
+
+        // A file is named after its contract if any of its contracts has the same name.
+        assert!(findings(file, content, false, true).is_empty());
+        let expected = vec![
+            "Contract not named after its file: ICounter does not match the file name `Token`",
+        ];
+        assert_eq!(findings("./src/Token.sol", content, false, true), expected);
+
+        // Files with a single contract named after them, files without contracts, and files
+        // outside `src/` are valid.
+        assert!(findings(file, "contract Counter {}", true, true).is_empty());
+        assert!(findings(file, "function helper() {}", true, true).is_empty());
+        assert!(findings("./test/Counter.t.sol", content, true, true).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::check::{
+    utils::{offset_to_line_col, signature, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, FunctionTy, SourceUnitPart};
+use std::{collections::HashSet, path::Path};
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::DuplicateFunction,
+    rationale: "Solidity rejects two functions with the same name and parameter types in one \
+                contract, and this reports them without compiling. Overloads with different \
+                parameter types are valid.",
+    valid: &["function mint(address to) public {}\n\
+              function mint(address to, uint256 amount) public {}"],
+    invalid: &["function mint(address to) public {}\n\
+                function mint(address recipient) external {}"],
+}];
+
+#[must_use]
+/// Validates that no two functions in the same contract have the same name and parameter types.
+/// Overloads with different parameter types are valid.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    // Free functions share a single scope at the file level, and each contract has its own scope.
+    let mut free_functions: Vec<&FunctionDefinition> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::FunctionDefinition(f) => free_functions.push(f),
+            SourceUnitPart::ContractDefinition(c) => {
+                let functions = c.parts.iter().filter_map(|el| match el {
+                    ContractPart::FunctionDefinition(f) => Some(f.as_ref()),
+                    _ => None,
+                });
+                invalid_items.extend(validate_scope(file, content, functions));
+            }
+            _ => (),
+        }
+    }
+    invalid_items.extend(validate_scope(file, content, free_functions));
+    invalid_items
+}
+
+// Returns an invalid item for every function whose signature matches an earlier function.
+fn validate_scope<'a>(
+    file: &Path,
+    content: &str,
+    functions: impl IntoIterator<Item = &'a FunctionDefinition>,
+) -> Vec<InvalidItem> {
+    let mut signatures: HashSet<String> = HashSet::new();
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for f in functions {
+        if f.ty != FunctionTy::Function {
+            continue
+        }
+
+        let signature = signature(content, f);
+        if !signatures.insert(signature.clone()) {
+            let (line, col) = offset_to_line_col(content, f.name_loc().start());
+            let file = file.display().to_string();
+            let kind = ValidatorKind::DuplicateFunction;
+            invalid_items.push(InvalidItem::new(kind, file, signature, line, col));
+        }
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{descriptions_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // Free functions are checked too.
+            function freeFunction(uint256 a) pure returns (uint256) { return a; }
+            function freeFunction(uint a) pure returns (uint256) { return a; }
+
+            contract MyContract {
+                // Overloads with different parameter types are valid.
+                function transfer(address to) public {}
+                function transfer(address to, uint256 amount) public {}
+                function transfer(address to, bytes memory data) public {}
+
+                // Duplicates are invalid, regardless of names, data locations, or type aliases.
+                function transfer(address recipient) external {}
+                function transfer(address, uint amount) public {}
+                function transfer(address to, bytes calldata data) external {}
+            }
+
+            // Functions in different contracts do not conflict.
+            contract OtherContract {
+                function transfer(address to) public {}
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(4);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_reports_second_occurrence() {
+        let content =
+            "contract C {\n  function f(uint8 a) public {}\n  function f(uint8 b) public {}\n}";
+        let config = Config::default();
+        let findings =
+            descriptions_with_config(&validate, "./src/MyContract.sol", content, &config);
+        assert_eq!(findings, vec!["Duplicate function in ./src/MyContract.sol:3:12: f(uint8)"]);
+    }
+}
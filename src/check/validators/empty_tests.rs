@@ -0,0 +1,105 @@
+use crate::check::{
+    utils::{offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::{test_names::is_test_function, Explanation},
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, SourceUnitPart, Statement};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.empty_tests == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::EmptyTest,
+    rationale: "An empty test always passes, so it is usually unfinished. This is a heuristic, so \
+                it is disabled by default, and enabled with `empty_tests = true`.",
+    valid: &["function test_Increment() public {\n    counter.increment();\n}"],
+    invalid: &["function test_Increment() public {}"],
+}];
+
+#[must_use]
+/// Validates that test functions have at least one statement, since an empty test always passes
+/// and is usually unfinished.
+///
+/// This is a heuristic, so it is disabled by default. Functions without a body, such as tests
+/// declared in abstract contracts, are not checked.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_body(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_body(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    let Some(Statement::Block { statements, .. }) = &f.body else { return None };
+    if !is_test_function(f) || !statements.is_empty() {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{} has an empty body", f.name());
+    Some(
+        InvalidItem::new(ValidatorKind::EmptyTest, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, empty_tests: Option<bool>) -> Vec<String> {
+        let config = Config { empty_tests, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            abstract contract CounterTest {
+                // Tests with statements, functions that are not tests, and tests without a body are
+                // valid.
+                function test_Increment() public { counter.increment(); }
+                function test_Helper() public { _helper(); }
+                function setUp() public {}
+                function _helper() internal {}
+                function test_Abstract() public virtual;
+
+                // These tests have an empty body, and should be invalid.
+                function test_Decrement() public {}
+                function testFuzz_Reset(uint256 x) external {
+                    // TODO
+                }
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./test/Counter.t.sol", content, None).is_empty());
+
+        let expected = vec![
+            "Empty test function: test_Decrement() has an empty body",
+            "Empty test function: testFuzz_Reset(uint256) has an empty body",
+        ];
+        assert_eq!(findings("./test/Counter.t.sol", content, Some(true)), expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content, Some(true)).is_empty());
+    }
+}
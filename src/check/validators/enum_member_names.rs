@@ -0,0 +1,120 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use regex::Regex;
+use solang_parser::pt::{ContractPart, EnumDefinition, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.enum_member_pattern.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::EnumMember,
+    rationale: "Projects write enum members in either `ALL_CAPS` or `PascalCase`, so the \
+                convention is configurable. Nothing is checked unless `enum_member_pattern` is \
+                set, and these examples use `^[A-Z][A-Z0-9_]*$`.",
+    valid: &["enum Status { ACTIVE, PAUSED }"],
+    invalid: &["enum Status { Active, paused }"],
+}];
+
+#[must_use]
+/// Validates that enum member names match `enum_member_pattern`, e.g. `ALL_CAPS` or `PascalCase`
+/// depending on the project's convention.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(pattern) = &config.enum_member_pattern else { return Vec::new() };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::EnumDefinition(e) => {
+                invalid_items.extend(validate_members(file, content, pattern, e));
+            }
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::EnumDefinition(e) = el {
+                        invalid_items.extend(validate_members(file, content, pattern, e));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_members(
+    file: &Path,
+    content: &str,
+    pattern: &Regex,
+    e: &EnumDefinition,
+) -> Vec<InvalidItem> {
+    let enum_name = e.name.as_ref().map_or("", |name| name.name.as_str());
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for member in e.values.iter().flatten() {
+        if pattern.is_match(&member.name) {
+            continue
+        }
+
+        let (line, col) = offset_to_line_col(content, member.loc.start());
+        let text = format!("{} in {enum_name}", member.name);
+        let file = file.display().to_string();
+        invalid_items.push(InvalidItem::new(ValidatorKind::EnumMember, file, text, line, col));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, pattern: Option<&str>) -> Vec<String> {
+        let config = Config {
+            enum_member_pattern: pattern.map(|pattern| Regex::new(pattern).unwrap()),
+            ..Config::default()
+        };
+        descriptions_with_config(&validate, "./src/MyContract.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // File level enums are checked too.
+            enum Mode { UP, Down }
+
+            contract MyContract {
+                enum Status {
+                    ACTIVE,
+                    PAUSED_FOR_UPGRADE,
+                    Stopped
+                }
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Invalid enum member name in ./src/MyContract.sol:3:29: Down in Mode",
+            "Invalid enum member name in ./src/MyContract.sol:9:21: Stopped in Status",
+        ];
+        assert_eq!(findings(content, Some("^[A-Z][A-Z0-9_]*$")), expected);
+
+        // Teams that use `PascalCase` members can configure that instead.
+        let expected = vec![
+            "Invalid enum member name in ./src/MyContract.sol:3:25: UP in Mode",
+            "Invalid enum member name in ./src/MyContract.sol:7:21: ACTIVE in Status",
+            "Invalid enum member name in ./src/MyContract.sol:8:21: PAUSED_FOR_UPGRADE in Status",
+        ];
+        assert_eq!(findings(content, Some("^[A-Z][a-z0-9]+(?:[A-Z][a-z0-9]+)*$")), expected);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::check::{
+    utils::{is_pascal_case, offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, ErrorDefinition, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Error,
+    rationale: "Custom errors are types, so they are named like contracts and structs. Only \
+                letters and digits are allowed.",
+    valid: &["error Unauthorized();", "error InsufficientBalance(uint256 available);"],
+    invalid: &["error unauthorized();", "error Insufficient_Balance();"],
+}];
+
+#[must_use]
+/// Validates that custom error names are in `PascalCase`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::ErrorDefinition(e) => {
+                if let Some(invalid_item) = validate_name(file, content, e) {
+                    invalid_items.push(invalid_item);
+                }
+            }
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::ErrorDefinition(e) = el {
+                        if let Some(invalid_item) = validate_name(file, content, e) {
+                            invalid_items.push(invalid_item);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(file: &Path, content: &str, e: &ErrorDefinition) -> Option<InvalidItem> {
+    let name = e.name.as_ref()?;
+    if is_pascal_case(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::Error,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // File level errors are checked too.
+            error file_level_error();
+            error FileLevelError(uint256 code);
+
+            contract MyContract {
+                // These are PascalCase and should be valid.
+                error Unauthorized();
+                error InsufficientBalance(uint256 available, uint256 required);
+                error ERC20InvalidSender(address sender);
+
+                // These are not PascalCase and should be invalid.
+                error unauthorized();
+                error Insufficient_Balance(uint256 available, uint256 required);
+                error INVALID_SENDER();
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(4);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let content = "error bad();\ncontract C {\n  error Good();\n  error Bad_Error();\n}";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./src/MyContract.sol", content, &config);
+
+        let locations: Vec<String> = invalid_items.iter().map(InvalidItem::location).collect();
+        assert_eq!(locations, vec!["./src/MyContract.sol:1:7", "./src/MyContract.sol:4:9"]);
+    }
+}
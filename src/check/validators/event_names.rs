@@ -0,0 +1,109 @@
+use crate::check::{
+    utils::{is_pascal_case, offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, EventDefinition, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Event,
+    rationale:
+        "Events are named like types, which also tells emitting an event apart from calling a \
+         function.",
+    valid: &["event Transfer(address indexed from, address indexed to, uint256 amount);"],
+    invalid: &["event transfer(address from, address to);", "event Token_Transfer();"],
+}];
+
+#[must_use]
+/// Validates that event names are in `PascalCase`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::EventDefinition(e) => {
+                if let Some(invalid_item) = validate_name(file, content, e) {
+                    invalid_items.push(invalid_item);
+                }
+            }
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::EventDefinition(e) = el {
+                        if let Some(invalid_item) = validate_name(file, content, e) {
+                            invalid_items.push(invalid_item);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(file: &Path, content: &str, e: &EventDefinition) -> Option<InvalidItem> {
+    let name = e.name.as_ref()?;
+    if is_pascal_case(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::Event,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // File level events are checked too.
+            event file_level_event();
+
+            contract MyContract {
+                // These are PascalCase and should be valid.
+                event Transfer(address indexed from, address indexed to, uint256 amount);
+                event ERC20Approval(address owner) anonymous;
+
+                // These are not PascalCase and should be invalid.
+                event transfer(address indexed from, address indexed to, uint256 amount);
+                event Transfer_Event();
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(3);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let content = "contract C {\n  event Good();\n  event bad();\n}";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./src/MyContract.sol", content, &config);
+
+        let locations: Vec<String> = invalid_items.iter().map(InvalidItem::location).collect();
+        assert_eq!(locations, vec!["./src/MyContract.sol:3:9"]);
+    }
+}
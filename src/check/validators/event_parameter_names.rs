@@ -0,0 +1,116 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use regex::Regex;
+use solang_parser::pt::{ContractPart, EventDefinition, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.indexed_event_parameter_pattern.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::EventParameter,
+    rationale: "Indexed parameters are the topics that logs are filtered by, and some projects \
+                mark them in their names. Nothing is checked unless \
+                `indexed_event_parameter_pattern` is set, and these examples use \
+                `^_[a-z][a-zA-Z0-9]*$`. Parameters that are not indexed are not checked.",
+    valid: &["event Transfer(address indexed _from, address indexed _to, uint256 amount);"],
+    invalid: &["event Transfer(address indexed from, address indexed to, uint256 amount);"],
+}];
+
+#[must_use]
+/// Validates that the names of indexed event parameters match `indexed_event_parameter_pattern`.
+///
+/// Parameters that are not indexed, and indexed parameters without a name, are not checked.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(pattern) = &config.indexed_event_parameter_pattern else { return Vec::new() };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::EventDefinition(e) => {
+                invalid_items.extend(validate_parameters(file, content, pattern, e));
+            }
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::EventDefinition(e) = el {
+                        invalid_items.extend(validate_parameters(file, content, pattern, e));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_parameters(
+    file: &Path,
+    content: &str,
+    pattern: &Regex,
+    e: &EventDefinition,
+) -> Vec<InvalidItem> {
+    let event = e.name.as_ref().map_or("", |name| name.name.as_str());
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for param in e.fields.iter().filter(|param| param.indexed) {
+        let Some(name) = &param.name else { continue };
+        if pattern.is_match(&name.name) {
+            continue
+        }
+
+        let (line, col) = offset_to_line_col(content, name.loc.start());
+        let text = format!("{} in {event}", name.name);
+        let file = file.display().to_string();
+        invalid_items.push(InvalidItem::new(ValidatorKind::EventParameter, file, text, line, col));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, pattern: Option<&str>) -> Vec<String> {
+        let config = Config {
+            indexed_event_parameter_pattern: pattern.map(|pattern| Regex::new(pattern).unwrap()),
+            ..Config::default()
+        };
+        descriptions_with_config(&validate, "./src/MyContract.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // File level events are checked too.
+            event Paused(address indexed account);
+
+            contract MyContract {
+                // Indexed parameters match the pattern, and others are not checked.
+                event Transfer(address indexed _from, address indexed _to, uint256 amount);
+                event Approval(address indexed, address indexed _spender, uint256 value);
+
+                // These indexed parameters do not match the pattern, and should be invalid.
+                event Deposit(address indexed owner, uint256 indexed _id, bytes data);
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Invalid indexed event parameter name in ./src/MyContract.sol:3:42: account in Paused",
+            "Invalid indexed event parameter name in ./src/MyContract.sol:11:47: owner in Deposit",
+        ];
+        assert_eq!(findings(content, Some("^_[a-z][a-zA-Z0-9]*$")), expected);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::check::{
+    utils::{
+        offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+        VisibilitySummary,
+    },
+    validators::{test_names::is_test_function, Explanation},
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.external_tests == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::TestPublic,
+    rationale: "Tests are only called by forge, never by other functions, so some style guides \
+                declare them `external`. This is a stylistic preference, so it is disabled by \
+                default, and enabled with `external_tests = true`.",
+    valid: &["function test_Increment() external {}"],
+    invalid: &["function test_Increment() public {}"],
+}];
+
+#[must_use]
+/// Validates that test functions are declared `external` rather than `public`, since forge calls
+/// them from outside the contract and they are never called internally.
+///
+/// This is a stylistic preference, so it is disabled by default. Tests that are internal or private
+/// are reported by [`super::test_names`] instead.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_visibility(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_visibility(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    if !is_test_function(f) || !f.is_public() {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{} is public, declare it `external` instead", f.name());
+    Some(
+        InvalidItem::new(ValidatorKind::TestPublic, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, external_tests: Option<bool>) -> Vec<String> {
+        let config = Config { external_tests, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract CounterTest {
+                // External tests and public functions that are not tests are valid.
+                function test_Increment() external {}
+                function setUp() public {}
+                function helper_Increment() public {}
+
+                // These tests are public, and should be invalid.
+                function test_Decrement() public {}
+                function testFuzz_Reset(uint256 x) public virtual {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./test/Counter.t.sol", content, None).is_empty());
+
+        let expected = vec![
+            "Public test function: test_Decrement() is public, declare it `external` instead",
+            "Public test function: testFuzz_Reset(uint256) is public, declare it `external` instead",
+        ];
+        assert_eq!(findings("./test/Counter.t.sol", content, Some(true)), expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content, Some(true)).is_empty());
+    }
+}
@@ -1,29 +1,112 @@
+use crate::{
+    check::{error::CheckError, utils::escape_github_data},
+    config::{CheckOpts, OutputFormat, Verbosity},
+};
 use colored::Colorize;
-use std::{error::Error, fs, process};
+use similar::TextDiff;
+use std::{fs, io, process};
 
 /// Validates that Solidity and TOML files are formatted correctly.
+///
+/// Solidity files are checked with `forge fmt --check`, run in the project root, so it reads the
+/// `[fmt]` settings, e.g. `line_length` and `tab_width`, from the `foundry.toml` there itself and
+/// agrees with `forge fmt`. They are not applied to `foundry.toml`, which has its own TOML style.
+///
+/// Nothing is printed when the report is output as JSON, SARIF, Checkstyle, or JUnit, and failures
+/// are printed as an annotation for the GitHub format. With `--diff`, the text format also prints
+/// the changes needed to fix the formatting.
 /// # Errors
-/// Returns an error if formatting is invalid or parsing fails.
-pub fn validate(taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>> {
+/// Returns [`CheckError::FormattingFailed`] if formatting is invalid, or [`CheckError::Io`] if
+/// `forge` cannot be run or `foundry.toml` cannot be read.
+pub fn validate(opts: &CheckOpts, taplo_opts: taplo::formatter::Options) -> Result<(), CheckError> {
     // Check Solidity with `forge fmt`.
     let forge_status = process::Command::new("forge").arg("fmt").arg("--check").output()?;
 
     // Print any warnings/errors from `forge fmt`.
-    let stderr = String::from_utf8(forge_status.stderr)?;
+    let stderr = String::from_utf8(forge_status.stderr)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
     let forge_ok = forge_status.status.success() && stderr.is_empty();
-    print!("{stderr}"); // Prints nothing if stderr is empty.
+    let print_details =
+        opts.output_format() == OutputFormat::Text && opts.verbosity() != Verbosity::Quiet;
+    if print_details {
+        print!("{stderr}"); // Prints nothing if stderr is empty.
+    }
+
+    // `forge fmt --check` prints the diff of each unformatted file to stdout.
+    if print_details && opts.diff {
+        print!("{}", String::from_utf8_lossy(&forge_status.stdout));
+    }
 
     // Check TOML with `taplo fmt`
     let config_orig = fs::read_to_string("./foundry.toml")?;
     let config_fmt = taplo::formatter::format(&config_orig, taplo_opts);
     let taplo_ok = config_orig == config_fmt;
+    if print_details && opts.diff && !taplo_ok {
+        print!("{}", colorize_diff(&unified_diff("foundry.toml", &config_orig, &config_fmt)));
+    }
 
     if !forge_ok || !taplo_ok {
-        eprintln!(
-            "{}: Formatting validation failed, run `scopelint fmt` to fix",
-            "error".bold().red()
-        );
-        return Err("Invalid fmt found".into())
+        let message = "Formatting validation failed, run `scopelint fmt` to fix";
+        match opts.output_format() {
+            OutputFormat::Text => eprintln!("{}: {message}", "error".bold().red()),
+            OutputFormat::Json |
+            OutputFormat::Sarif |
+            OutputFormat::Checkstyle |
+            OutputFormat::Junit => (),
+            OutputFormat::Github => println!("::error::{}", escape_github_data(message)),
+        }
+        return Err(CheckError::FormattingFailed)
     }
     Ok(())
 }
+
+// Returns a unified diff of the changes from `original` to `formatted`.
+fn unified_diff(file: &str, original: &str, formatted: &str) -> String {
+    TextDiff::from_lines(original, formatted)
+        .unified_diff()
+        .header(&format!("a/{file}"), &format!("b/{file}"))
+        .to_string()
+}
+
+// Colors the removed lines of a unified diff red and the added lines green.
+fn colorize_diff(diff: &str) -> String {
+    let mut colored = String::with_capacity(diff.len());
+    for line in diff.split_inclusive('\n') {
+        let line = if line.starts_with("---") || line.starts_with("+++") {
+            line.bold()
+        } else if line.starts_with('-') {
+            line.red()
+        } else if line.starts_with('+') {
+            line.green()
+        } else if line.starts_with("@@") {
+            line.cyan()
+        } else {
+            line.normal()
+        };
+        colored.push_str(&line.to_string());
+    }
+    colored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let original = "[profile.default]\nsrc = 'src'\n  out = 'out'\n";
+        let formatted = "[profile.default]\n  out = 'out'\n  src = 'src'\n";
+        let expected = [
+            "--- a/foundry.toml",
+            "+++ b/foundry.toml",
+            "@@ -1,3 +1,3 @@",
+            " [profile.default]",
+            "-src = 'src'",
+            "   out = 'out'",
+            "+  src = 'src'",
+            "",
+        ];
+        assert_eq!(unified_diff("foundry.toml", original, formatted), expected.join("\n"));
+        assert_eq!(unified_diff("foundry.toml", original, original), "");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractDefinition, ContractTy, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.interface_prefix != Some(false)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Interface,
+    rationale:
+        "The `I` prefix tells interfaces apart from the contracts that implement them, e.g. \
+         `IERC20` and `ERC20`. Disable it with `interface_prefix = false`.",
+    valid: &["interface IERC20 {}", "interface ICounter {}"],
+    invalid: &["interface ERC20 {}", "interface Icounter {}"],
+}];
+
+#[must_use]
+/// Validates that interface names are prefixed with `I`, e.g. `IERC20`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            if let Some(invalid_item) = validate_name(file, content, c) {
+                invalid_items.push(invalid_item);
+            }
+        }
+    }
+    invalid_items
+}
+
+// The name must be an `I` followed by another uppercase letter, so `IERC20` is valid but `Index`
+// is not.
+fn is_valid_interface_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn validate_name(file: &Path, content: &str, c: &ContractDefinition) -> Option<InvalidItem> {
+    let name = c.name.as_ref()?;
+    if !matches!(c.ty, ContractTy::Interface(_)) || is_valid_interface_name(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::Interface,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // These are prefixed with `I` and should be valid.
+            interface IERC20 {}
+            interface ICounter { function increment() external; }
+
+            // These are not prefixed with `I` and should be invalid.
+            interface Counter {}
+            interface Index {}
+            interface iCounter {}
+
+            // Contracts and libraries are not interfaces, so are not checked here.
+            contract Token {}
+            library Math {}
+        ";
+
+        let expected_findings = ExpectedFindings::new(3);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_disabled() {
+        let content = "interface Counter {}";
+        let findings = |config: &Config| {
+            findings_with_config(&validate, "./src/Counter.sol", content, config).len()
+        };
+
+        assert_eq!(findings(&Config::default()), 1);
+        let config = Config { interface_prefix: Some(false), ..Config::default() };
+        assert_eq!(findings(&config), 0);
+    }
+
+    #[test]
+    fn test_is_valid_interface_name() {
+        assert!(is_valid_interface_name("IERC20"));
+        assert!(is_valid_interface_name("ICounter"));
+        assert!(!is_valid_interface_name("I"));
+        assert!(!is_valid_interface_name("Index"));
+        assert!(!is_valid_interface_name("Counter"));
+        assert!(!is_valid_interface_name("iCounter"));
+    }
+}
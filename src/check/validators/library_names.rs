@@ -0,0 +1,81 @@
+use crate::check::{
+    utils::{is_pascal_case, offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractDefinition, ContractTy, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Library,
+    rationale: "Libraries are named like contracts, since they are called the same way, e.g. \
+                `SafeMath.add`. Only letters and digits are allowed.",
+    valid: &["library SafeMath {}", "library Strings {}"],
+    invalid: &["library safeMath {}", "library safe_math {}"],
+}];
+
+#[must_use]
+/// Validates that library names are in `PascalCase`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            if let Some(invalid_item) = validate_name(file, content, c) {
+                invalid_items.push(invalid_item);
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(file: &Path, content: &str, c: &ContractDefinition) -> Option<InvalidItem> {
+    let name = c.name.as_ref()?;
+    if !matches!(c.ty, ContractTy::Library(_)) || is_pascal_case(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(
+        ValidatorKind::Library,
+        file.display().to_string(),
+        name.name.clone(),
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::utils::ExpectedFindings;
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // These are PascalCase and should be valid.
+            library SafeMath {}
+            library Strings { function toString(uint256 value) internal pure {} }
+
+            // These are not PascalCase and should be invalid.
+            library safeMath {}
+            library safe_math {}
+
+            // Contracts and interfaces are not libraries, so are not checked here.
+            contract token {}
+            interface counter {}
+        ";
+
+        let expected_findings = ExpectedFindings::new(2);
+        expected_findings.assert_eq(content, &validate);
+    }
+}
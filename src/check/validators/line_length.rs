@@ -0,0 +1,85 @@
+use crate::check::{
+    utils::{InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use std::path::Path;
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::LongLine,
+    rationale: "Long lines are hard to read and review, especially side by side in diffs. The \
+                formatter wraps most code, but not long strings or comments, so this reports any \
+                line over the limit. This is disabled by default, and these examples use \
+                `max_line_length = 40`.",
+    valid: &["// Increments the counter by one."],
+    invalid: &["// Increments the counter by one, and emits an event."],
+}];
+
+#[must_use]
+/// Validates that no line is longer than the configured `max_line_length`, counted in
+/// characters.
+///
+/// Lines are checked without the parse tree, so they are checked in files that cannot be parsed
+/// too. Each line over the limit is reported at the first character past it.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    let Some(limit) = parsed.config.max_line_length else { return Vec::new() };
+    validate_lines(parsed.file, parsed.content, limit)
+}
+
+/// Returns an invalid item for each line of `content` that is longer than `limit` characters.
+pub(crate) fn validate_lines(file: &Path, content: &str, limit: usize) -> Vec<InvalidItem> {
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        // The byte order mark at the start of the file is not part of the first line.
+        let line = if index == 0 { line.strip_prefix('\u{feff}').unwrap_or(line) } else { line };
+        let length = line.chars().count();
+        if length <= limit {
+            continue
+        }
+        let text = format!("{length} characters long, over the limit of {limit}");
+        let file = file.display().to_string();
+        invalid_items.push(InvalidItem::new(
+            ValidatorKind::LongLine,
+            file,
+            text,
+            index + 1,
+            limit + 1,
+        ));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, max_line_length: Option<usize>) -> Vec<String> {
+        let config = Config { max_line_length, ..Config::default() };
+        descriptions_with_config(&validate, "./src/Counter.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = "contract Counter {\n    // Lines at the limit are valid.\n    \
+                       // This comment is over the limit.\n    string s = \"é is one character\";\n}\n";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Line too long in ./src/Counter.sol:3:37: 38 characters long, over the limit of 36",
+        ];
+        assert_eq!(findings(content, Some(36)), expected);
+        assert!(findings(content, Some(38)).is_empty());
+    }
+
+    #[test]
+    fn test_validate_lines_byte_order_mark() {
+        // Only a byte order mark at the start of the file is skipped, others are characters.
+        let items =
+            validate_lines(Path::new("./src/Counter.sol"), "\u{feff}abcd\nab\u{feff}cd\n", 4);
+        let lines: Vec<usize> = items.iter().map(InvalidItem::line).collect();
+        assert_eq!(lines, [2]);
+    }
+}
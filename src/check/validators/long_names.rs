@@ -0,0 +1,192 @@
+use crate::{
+    check::{
+        utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+        validators::Explanation,
+    },
+    config::MaxIdentifierLength,
+};
+use solang_parser::pt::{
+    ContractPart, FunctionTy, Identifier, SourceUnitPart, VariableAttribute, VariableDefinition,
+};
+use std::path::Path;
+
+const fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.max_identifier_length.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::LongName,
+    rationale:
+        "Very long names are hard to read, and push code past the line length. The limit is \
+                set per kind of identifier, so constants can be longer than functions. This is \
+                disabled by default, and these examples use `max_identifier_length = { function = \
+                24 }`. Findings are warnings unless `[severity]` says otherwise.",
+    valid: &["function transferOwnership(address to) public {}"],
+    invalid: &["function transferOwnershipAfterDelay(address to) public {}"],
+}];
+
+// The kinds of identifiers that have a length limit.
+#[derive(Debug, Clone, Copy)]
+enum IdentifierKind {
+    Constant,
+    Function,
+    Variable,
+}
+
+impl IdentifierKind {
+    // Returns the limit for this kind of identifier, or `None` if its length is not checked.
+    fn limit(self, max: &MaxIdentifierLength) -> Option<usize> {
+        let limit = match self {
+            Self::Constant => max.constant,
+            Self::Function => max.function,
+            Self::Variable => max.variable,
+        };
+        limit.or(max.default)
+    }
+
+    // Returns the plural noun used in messages, e.g. "functions".
+    const fn noun(self) -> &'static str {
+        match self {
+            Self::Constant => "constants",
+            Self::Function => "functions",
+            Self::Variable => "variables",
+        }
+    }
+
+    fn of(v: &VariableDefinition) -> Self {
+        let is_constant = v
+            .attrs
+            .iter()
+            .any(|a| matches!(a, VariableAttribute::Constant(_) | VariableAttribute::Immutable(_)));
+        if is_constant {
+            Self::Constant
+        } else {
+            Self::Variable
+        }
+    }
+}
+
+#[must_use]
+/// Validates that the names of constants, functions, and state variables are not longer than the
+/// limit configured for their kind with `[max_identifier_length]`.
+///
+/// Constants include immutables, functions include modifiers, and variables are the mutable state
+/// variables. Kinds without a limit of their own use the `default` limit, and are not checked if
+/// there is none. Findings are warnings by default, since long names are a matter of readability.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(max) = &config.max_identifier_length else { return Vec::new() };
+
+    let mut identifiers: Vec<(IdentifierKind, &Identifier)> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    match el {
+                        ContractPart::FunctionDefinition(f)
+                            if matches!(f.ty, FunctionTy::Function | FunctionTy::Modifier) =>
+                        {
+                            identifiers
+                                .extend(f.name.iter().map(|n| (IdentifierKind::Function, n)));
+                        }
+                        ContractPart::VariableDefinition(v) => {
+                            identifiers.extend(v.name.iter().map(|n| (IdentifierKind::of(v), n)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            SourceUnitPart::FunctionDefinition(f) if f.ty == FunctionTy::Function => {
+                identifiers.extend(f.name.iter().map(|n| (IdentifierKind::Function, n)));
+            }
+            SourceUnitPart::VariableDefinition(v) => {
+                identifiers.extend(v.name.iter().map(|n| (IdentifierKind::Constant, n)));
+            }
+            _ => {}
+        }
+    }
+
+    identifiers
+        .into_iter()
+        .filter_map(|(kind, name)| validate_length(file, content, max, kind, name))
+        .collect()
+}
+
+fn validate_length(
+    file: &Path,
+    content: &str,
+    max: &MaxIdentifierLength,
+    kind: IdentifierKind,
+    name: &Identifier,
+) -> Option<InvalidItem> {
+    let limit = kind.limit(max)?;
+    let length = name.name.len();
+    if length <= limit {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let text = format!(
+        "{} is {length} characters long, over the limit of {limit} for {}",
+        name.name,
+        kind.noun()
+    );
+    Some(InvalidItem::new(ValidatorKind::LongName, file.display().to_string(), text, line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(content: &str, max_identifier_length: Option<MaxIdentifierLength>) -> Vec<String> {
+        let config = Config { max_identifier_length, ..Config::default() };
+        messages_with_config(&validate, "./src/Counter.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            uint256 constant FILE_LEVEL_LIMIT = 1;
+
+            contract Counter {
+                uint256 public constant MAX_INCREMENT = 10;
+                uint256 immutable DEPLOYED_AT_TIMESTAMP;
+                uint256 public count;
+                uint256 internal countAtLastReset;
+
+                function increment() public {}
+                function incrementUntilLimit() public {}
+                modifier onlyOwnerOrAdmin() { _; }
+                constructor() {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        // Kinds without their own limit use the default one, and names at the limit are valid.
+        let max =
+            MaxIdentifierLength { default: Some(12), constant: Some(16), ..Default::default() };
+        let expected = vec![
+            "Identifier too long: DEPLOYED_AT_TIMESTAMP is 21 characters long, over the limit of 16 for constants",
+            "Identifier too long: countAtLastReset is 16 characters long, over the limit of 12 for variables",
+            "Identifier too long: incrementUntilLimit is 19 characters long, over the limit of 12 for functions",
+            "Identifier too long: onlyOwnerOrAdmin is 16 characters long, over the limit of 12 for functions",
+        ];
+        assert_eq!(findings(content, Some(max)), expected);
+
+        // Kinds without a limit are not checked.
+        let max = MaxIdentifierLength { function: Some(12), ..Default::default() };
+        let expected = vec![
+            "Identifier too long: incrementUntilLimit is 19 characters long, over the limit of 12 for functions",
+            "Identifier too long: onlyOwnerOrAdmin is 16 characters long, over the limit of 12 for functions",
+        ];
+        assert_eq!(findings(content, Some(max)), expected);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::check::{
+    utils::{
+        offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+        VisibilitySummary,
+    },
+    validators::Explanation,
+};
+use solang_parser::pt::{
+    CodeLocation, Comment, ContractDefinition, ContractPart, FunctionDefinition, FunctionTy,
+    SourceUnitPart,
+};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Src) && parsed.config.missing_docs == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MissingDoc,
+    rationale: "NatSpec comments document the public interface of a contract for users, auditors, \
+                and tools. Any `///` or `/** */` comment right before the function counts, \
+                including `/// @inheritdoc IERC20`. This is disabled by default, and enabled with \
+                `missing_docs = true`.",
+    valid: &["/// @notice Increments the counter.\nfunction increment() public {}"],
+    invalid: &["// Increments the counter.\nfunction increment() public {}"],
+}];
+
+#[must_use]
+/// Validates that public and external functions in src contracts have a NatSpec doc comment,
+/// either `///` or `/** */`, right before them.
+///
+/// Any doc comment counts, including `/// @inheritdoc IERC20`. Doc comments are found among the
+/// comments returned by the parser, between the function and the end of the previous item in the
+/// contract.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, comments, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            invalid_items.extend(validate_contract(file, content, comments, c));
+        }
+    }
+    invalid_items
+}
+
+// Returns the offset where `part` ends, including the body of functions, which `ContractPart::loc`
+// excludes.
+fn part_end(part: &ContractPart) -> usize {
+    match part {
+        ContractPart::FunctionDefinition(f) => {
+            f.body.as_ref().map_or_else(|| f.loc.end(), |body| body.loc().end())
+        }
+        _ => part.loc().end(),
+    }
+}
+
+// Returns `true` if there is a doc comment in `comments` between the offsets `start` and `end`.
+fn has_doc_comment(comments: &[Comment], start: usize, end: usize) -> bool {
+    comments.iter().any(|comment| match comment {
+        Comment::DocLine(loc, _) | Comment::DocBlock(loc, _) => {
+            loc.start() >= start && loc.end() <= end
+        }
+        Comment::Line(..) | Comment::Block(..) => false,
+    })
+}
+
+/// Returns the functions of `c`, each with the offset where the comments that document it start,
+/// which is the end of the previous item in the contract. Its doc comments lie between that offset
+/// and the start of the function.
+pub(crate) fn functions_with_doc_start(
+    c: &ContractDefinition,
+) -> Vec<(&FunctionDefinition, usize)> {
+    // The first item can only be documented after the contract name.
+    let mut previous_end = c.name.as_ref().map_or_else(|| c.loc.start(), |name| name.loc.end());
+
+    let mut functions = Vec::new();
+    for el in &c.parts {
+        if let ContractPart::FunctionDefinition(f) = el {
+            functions.push((f.as_ref(), previous_end));
+        }
+        previous_end = part_end(el);
+    }
+    functions
+}
+
+fn validate_contract(
+    file: &Path,
+    content: &str,
+    comments: &[Comment],
+    c: &ContractDefinition,
+) -> Vec<InvalidItem> {
+    functions_with_doc_start(c)
+        .into_iter()
+        .filter_map(|(f, doc_start)| validate_function(file, content, comments, doc_start, f))
+        .collect()
+}
+
+fn validate_function(
+    file: &Path,
+    content: &str,
+    comments: &[Comment],
+    doc_start: usize,
+    f: &FunctionDefinition,
+) -> Option<InvalidItem> {
+    if f.ty != FunctionTy::Function || !f.is_public_or_external() {
+        return None
+    }
+    if has_doc_comment(comments, doc_start, f.loc.start()) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{} has no NatSpec comment", f.name());
+    Some(
+        InvalidItem::new(ValidatorKind::MissingDoc, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, missing_docs: Option<bool>) -> Vec<String> {
+        let config = Config { missing_docs, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            /// @notice A counter.
+            contract Counter {
+                /// @notice Increments the counter.
+                function increment() public {
+                    /// Doc comments in the body do not document the next function.
+                }
+                function decrement() external {}
+
+                /**
+                 * @notice Resets the counter.
+                 */
+                function reset() external {}
+                /// @inheritdoc ICounter
+                function count() public view returns (uint256) {}
+
+                // Regular comments are not NatSpec comments.
+                function total() public view returns (uint256) {}
+
+                // Internal and private functions, and constructors, do not need a comment.
+                function _update() internal {}
+                constructor() {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./src/Counter.sol", content, None).is_empty());
+
+        let expected = vec![
+            "Missing NatSpec comment: decrement() has no NatSpec comment",
+            "Missing NatSpec comment: total() has no NatSpec comment",
+        ];
+        assert_eq!(findings("./src/Counter.sol", content, Some(true)), expected);
+
+        // Only src files are checked.
+        assert!(findings("./test/Counter.t.sol", content, Some(true)).is_empty());
+    }
+}
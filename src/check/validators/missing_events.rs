@@ -0,0 +1,157 @@
+use crate::check::{
+    utils::{
+        for_each_statement, offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed,
+        ValidatorKind, VisibilitySummary,
+    },
+    validators::Explanation,
+};
+use solang_parser::pt::{
+    ContractPart, ContractTy, FunctionAttribute, FunctionDefinition, FunctionTy, Mutability,
+    SourceUnitPart, Statement,
+};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Src) && parsed.config.missing_events == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MissingEvent,
+    rationale: "Events let off-chain tools and auditors follow every change to the state of a \
+                contract. Whether a function changes state cannot be known without analyzing its \
+                data flow, so every public or external function that is not `view` or `pure` \
+                counts, unless its body is empty. Any `emit` in the body counts, including in \
+                branches. This is disabled by default, and enabled with `missing_events = true`.",
+    valid: &["function increment() external {\n    count++;\n    emit Incremented(count);\n}"],
+    invalid: &["function increment() external {\n    count++;\n}"],
+}];
+
+#[must_use]
+/// Validates that public and external functions in src contracts that are not `view` or `pure`
+/// emit at least one event.
+///
+/// This is a heuristic, since any function that is not `view` or `pure` is assumed to change
+/// state. Functions with an empty body, or without one, and the functions of interfaces and
+/// libraries are not checked. Events emitted by functions or modifiers it calls do not count.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        let SourceUnitPart::ContractDefinition(c) = element else { continue };
+        if !matches!(c.ty, ContractTy::Contract(_) | ContractTy::Abstract(_)) {
+            continue
+        }
+        for el in &c.parts {
+            if let ContractPart::FunctionDefinition(f) = el {
+                invalid_items.extend(validate_function(file, content, f));
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_function(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    if f.ty != FunctionTy::Function || !f.is_public_or_external() || is_read_only(f) {
+        return None
+    }
+    let body = f.body.as_ref()?;
+    if matches!(body, Statement::Block { statements, .. } if statements.is_empty()) {
+        return None
+    }
+
+    let mut emits = false;
+    for_each_statement(body, &mut |statement| {
+        emits |= matches!(statement, Statement::Emit(..));
+    });
+    if emits {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{} is not `view` or `pure`, but emits no event", f.name());
+    Some(
+        InvalidItem::new(ValidatorKind::MissingEvent, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+// Returns `true` if `f` is `view` or `pure`, or `constant`, which older versions use for `view`.
+fn is_read_only(f: &FunctionDefinition) -> bool {
+    f.attributes.iter().any(|a| {
+        matches!(
+            a,
+            FunctionAttribute::Mutability(
+                Mutability::View(_) | Mutability::Pure(_) | Mutability::Constant(_)
+            )
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, missing_events: Option<bool>) -> Vec<String> {
+        let config = Config { missing_events, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract Counter {
+                uint256 count;
+
+                // Functions that emit an event, anywhere in their body, are valid.
+                function increment() external {
+                    count++;
+                    emit Incremented(count);
+                }
+                function reset(bool force) public {
+                    if (force) { count = 0; emit Reset(); }
+                }
+
+                // These change state without emitting an event.
+                function decrement() external { count--; }
+                function set(uint256 value) public payable { count = value; }
+                function add(uint256 value) external returns (uint256) { return count += value; }
+
+                // Read-only, empty, internal, and special functions are not checked.
+                function get() external view returns (uint256) { return count; }
+                function double(uint256 x) public pure returns (uint256) { return 2 * x; }
+                function hook() external virtual {}
+                function _update() internal { count++; }
+                constructor() { count = 1; }
+                receive() external payable { count++; }
+            }
+
+            interface ICounter {
+                function decrement() external;
+            }
+
+            library Math {
+                function increment(uint256 x) public returns (uint256) { return x + 1; }
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./src/Counter.sol", content, None).is_empty());
+
+        let expected = vec![
+            "State-changing function without an event: decrement() is not `view` or `pure`, but emits no event",
+            "State-changing function without an event: set(uint256) is not `view` or `pure`, but emits no event",
+            "State-changing function without an event: add(uint256) is not `view` or `pure`, but emits no event",
+        ];
+        assert_eq!(findings("./src/Counter.sol", content, Some(true)), expected);
+
+        // Only src files are checked.
+        assert!(findings("./test/Counter.t.sol", content, Some(true)).is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::check::{
+    utils::{offset_to_line_col, signature, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{
+    ContractPart, FunctionAttribute, FunctionDefinition, FunctionTy, SourceUnitPart,
+};
+use std::path::Path;
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MissingVisibility,
+    rationale: "Functions without a visibility were public before Solidity 0.5, which is easy to \
+                miss in older code. Constructors, fallback and receive functions, modifiers, and \
+                free functions are not checked.",
+    valid: &["function increment() public {}"],
+    invalid: &["function increment() {}"],
+}];
+
+#[must_use]
+/// Validates that functions in contracts, interfaces, and libraries declare their visibility.
+///
+/// Solidity before 0.5 made functions without a visibility public, so they are only found in older
+/// code. Constructors, fallback and receive functions, modifiers, and free functions are not
+/// checked, since they have no visibility or a fixed one.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_visibility(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_visibility(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    let has_visibility = f.attributes.iter().any(|a| matches!(a, FunctionAttribute::Visibility(_)));
+    // Unnamed functions are fallback functions in Solidity before 0.6.
+    if f.ty != FunctionTy::Function || f.name.is_none() || has_visibility {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{} has no visibility specifier", f.name());
+    let file = file.display().to_string();
+    Some(
+        InvalidItem::new(ValidatorKind::MissingVisibility, file, text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::findings_with_config, config::Config};
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            pragma solidity ^0.4.24;
+
+            contract Counter {
+                function increment() {}
+                function decrement() public {}
+                function _reset() internal {}
+                function() payable {}
+                constructor() {}
+                modifier onlyOwner() { _; }
+            }
+
+            // Free functions cannot have a visibility.
+            function helper() pure returns (uint256) {}
+        ";
+        let invalid_items =
+            findings_with_config(&validate, "./src/Counter.sol", content, &Config::default());
+
+        let expected = vec![
+            "Missing function visibility in ./src/Counter.sol:5:26: increment() has no visibility specifier",
+        ];
+        let findings: Vec<String> = invalid_items.iter().map(InvalidItem::description).collect();
+        assert_eq!(findings, expected);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    check::{
+        utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+        validators::{test_contract_names::is_test_contract, Explanation},
+    },
+    config::MockContractNames,
+};
+use solang_parser::pt::{ContractDefinition, ContractTy, SourceUnitPart};
+use std::path::{Component, Path};
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.mock_contract_names.is_some() &&
+        parsed.file.starts_with(&parsed.config.paths.test)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MockContractName,
+    rationale: "A `Mock` affix keeps mocks from being mistaken for the contracts they replace. \
+                Mocks are the contracts in a `mock` or `mocks` directory of the test directory, \
+                and other contracts there with `Mock` in their name. This is disabled by default, \
+                and these examples use `mock_contract_names = \"prefix\"`.",
+    valid: &["contract MockERC20 {}"],
+    invalid: &["contract ERC20Mock {}", "contract FakeERC20 {}"],
+}];
+
+#[must_use]
+/// Validates that mock contracts in the test directory start or end with `Mock`, as configured
+/// with `mock_contract_names`, e.g. `MockERC20` or `ERC20Mock`.
+///
+/// Mock contracts are the contracts in a `mock` or `mocks` directory, and the contracts elsewhere
+/// whose name contains `Mock`. Abstract contracts and contracts with tests are not mocks.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(position) = config.mock_contract_names else { return Vec::new() };
+    let in_mocks_dir = is_in_mocks_dir(file);
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            if let Some(invalid_item) = validate_name(file, content, position, in_mocks_dir, c) {
+                invalid_items.push(invalid_item);
+            }
+        }
+    }
+    invalid_items
+}
+
+// Returns `true` if `file` is in a directory named `mock` or `mocks`, at any depth.
+fn is_in_mocks_dir(file: &Path) -> bool {
+    let Some(dir) = file.parent() else { return false };
+    dir.components().any(|component| match component {
+        Component::Normal(name) => name == "mock" || name == "mocks",
+        _ => false,
+    })
+}
+
+fn is_valid_mock_name(name: &str, position: MockContractNames) -> bool {
+    match position {
+        MockContractNames::Prefix => {
+            name.strip_prefix("Mock").is_some_and(|rest| rest.starts_with(char::is_uppercase))
+        }
+        MockContractNames::Suffix => name.len() > "Mock".len() && name.ends_with("Mock"),
+    }
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    position: MockContractNames,
+    in_mocks_dir: bool,
+    c: &ContractDefinition,
+) -> Option<InvalidItem> {
+    let name = c.name.as_ref()?;
+    let is_mock = in_mocks_dir || name.name.contains("Mock");
+    if !matches!(c.ty, ContractTy::Contract(_)) || is_test_contract(c) || !is_mock {
+        return None
+    }
+    if is_valid_mock_name(&name.name, position) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let text = match position {
+        MockContractNames::Prefix => format!("{} does not start with `Mock`", name.name),
+        MockContractNames::Suffix => format!("{} does not end with `Mock`", name.name),
+    };
+    let file = file.display().to_string();
+    Some(InvalidItem::new(ValidatorKind::MockContractName, file, text, line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, position: Option<MockContractNames>) -> Vec<String> {
+        let config = Config { mock_contract_names: position, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // Contracts in a mocks directory are mocks, except abstract and test contracts.
+            contract MockERC20 {}
+            contract ERC20Mock {}
+            contract FakeOracle {}
+            abstract contract BaseOracle {}
+            contract OracleTest { function test_Price() public {} }
+        ";
+        let file = "./test/mocks/Tokens.sol";
+
+        // The check is disabled by default.
+        assert!(findings(file, content, None).is_empty());
+
+        let expected = vec![
+            "Invalid mock contract name: ERC20Mock does not start with `Mock`",
+            "Invalid mock contract name: FakeOracle does not start with `Mock`",
+        ];
+        assert_eq!(findings(file, content, Some(MockContractNames::Prefix)), expected);
+        let expected = vec![
+            "Invalid mock contract name: MockERC20 does not end with `Mock`",
+            "Invalid mock contract name: FakeOracle does not end with `Mock`",
+        ];
+        assert_eq!(findings(file, content, Some(MockContractNames::Suffix)), expected);
+
+        // Outside a mocks directory, only contracts with `Mock` in their name are mocks.
+        let file = "./test/utils/Tokens.sol";
+        let expected = vec!["Invalid mock contract name: ERC20Mock does not start with `Mock`"];
+        assert_eq!(findings(file, content, Some(MockContractNames::Prefix)), expected);
+
+        // Only the test directory is checked, and a bare `Mock` has no name.
+        assert!(
+            findings("./src/mocks/Tokens.sol", content, Some(MockContractNames::Prefix)).is_empty()
+        );
+        let content = "contract Mock {} contract Mockery {}";
+        let expected = vec![
+            "Invalid mock contract name: Mock does not start with `Mock`",
+            "Invalid mock contract name: Mockery does not start with `Mock`",
+        ];
+        assert_eq!(findings(file, content, Some(MockContractNames::Prefix)), expected);
+    }
+}
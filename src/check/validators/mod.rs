@@ -1,14 +1,326 @@
+use crate::check::utils::{InvalidItem, Parsed, ValidatorKind};
+
 /// Validates that Solidity and TOML files are formatted correctly.
 pub mod formatting;
 
+/// Validates that abstract contract names match the configured pattern.
+pub mod abstract_contract_names;
+
+/// Validates that inline assembly blocks have a comment explaining them.
+pub mod assembly_comments;
+
 /// Validates that constant and immutable variable names are in `ALL_CAPS`.
 pub mod constant_names;
 
-/// Validates that a script has a single public method named `run`.
+/// Validates that constructor parameter names match the configured pattern.
+pub mod constructor_parameter_names;
+
+/// Validates that src files declare one contract, named after the file.
+pub mod contract_files;
+
+/// Validates that functions in the same contract do not have the same signature.
+pub mod duplicate_functions;
+
+/// Validates that test functions are not empty.
+pub mod empty_tests;
+
+/// Validates that test functions are declared `external` rather than `public`.
+pub mod external_tests;
+
+/// Validates that enum member names match the configured pattern.
+pub mod enum_member_names;
+
+/// Validates that custom error names are in `PascalCase`.
+pub mod error_names;
+
+/// Validates that event names are in `PascalCase`.
+pub mod event_names;
+
+/// Validates that indexed event parameter names match the configured pattern.
+pub mod event_parameter_names;
+
+/// Validates that interface names are prefixed with `I`.
+pub mod interface_names;
+
+/// Validates that library names are in `PascalCase`.
+pub mod library_names;
+
+/// Validates that lines are not longer than the configured limit.
+pub mod line_length;
+
+/// Validates that names are not longer than the configured limits.
+pub mod long_names;
+
+/// Validates that public and external functions have a NatSpec comment.
+pub mod missing_docs;
+
+/// Validates that public and external functions that change state emit an event.
+pub mod missing_events;
+
+/// Validates that functions declare their visibility.
+pub mod missing_visibility;
+
+/// Validates that mock contract names start or end with `Mock`.
+pub mod mock_contract_names;
+
+/// Validates that modifier names are in `mixedCase`.
+pub mod modifier_names;
+
+/// Validates that state variables named like constants are declared `constant` or `immutable`.
+pub mod mutable_constant_names;
+
+/// Validates that NatSpec tags match the signature of the function they document.
+pub mod natspec_tags;
+
+/// Validates that a script has a single public method named `run`, or only the configured methods.
 pub mod script_one_pubic_run_method;
 
+/// Validates that test files do not have misspelled `setUp` functions.
+pub mod set_up_names;
+
+/// Validates that state variables do not shadow inherited state variables.
+pub mod shadowing;
+
 /// Validates that internal and private function names are prefixed with an underscore.
 pub mod src_names_internal;
 
+/// Validates that public and external function names are not prefixed with an underscore.
+pub mod src_names_public;
+
+/// Validates that mutable state variable names are in `mixedCase`.
+pub mod state_variable_names;
+
+/// Validates that upgradeable contracts declare a storage gap.
+pub mod storage_gaps;
+
+/// Validates that struct and enum names are in `PascalCase`.
+pub mod type_names;
+
+/// Validates that internal and private functions in test files are prefixed with an underscore.
+pub mod test_helper_names;
+
+/// Validates that test contracts inherit from the configured base contract.
+pub mod test_base_contracts;
+
+/// Validates that test contracts are named after the contract under test.
+pub mod test_contract_names;
+
+/// Validates that unit test names reference a public function of the contract under test.
+pub mod test_function_names;
+
 /// Validates that test names are in the correct format.
 pub mod test_names;
+
+/// Validates that immutable variables are assigned where declared or in the constructor.
+pub mod unassigned_immutables;
+
+/// Explains the convention behind a kind of finding, as printed by `scopelint explain`.
+pub struct Explanation {
+    /// The kind that is explained.
+    pub kind: ValidatorKind,
+    /// Why the convention is enforced and what it covers, in addition to the kind's description.
+    pub rationale: &'static str,
+    /// Names or code that follow the convention.
+    pub valid: &'static [&'static str],
+    /// Names or code that are reported.
+    pub invalid: &'static [&'static str],
+}
+
+/// A validator, along with the kinds of findings it reports.
+pub struct Validator {
+    /// The kinds of findings the validator reports. It is only run if any of them are enabled.
+    pub kinds: &'static [ValidatorKind],
+    /// Validates a parsed file, returning the findings.
+    pub validate: fn(&Parsed) -> Vec<InvalidItem>,
+    /// Explains each of the kinds, in the same order.
+    pub explanations: &'static [Explanation],
+}
+
+/// All validators that are run on each parsed file, in the order they are run.
+pub const REGISTRY: [Validator; 37] = [
+    Validator {
+        kinds: &[ValidatorKind::Test, ValidatorKind::TestFuzz, ValidatorKind::TestVisibility],
+        validate: test_names::validate,
+        explanations: &test_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Src],
+        validate: src_names_internal::validate,
+        explanations: &src_names_internal::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::SrcPublic],
+        validate: src_names_public::validate,
+        explanations: &src_names_public::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Script],
+        validate: script_one_pubic_run_method::validate,
+        explanations: &script_one_pubic_run_method::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Constant],
+        validate: constant_names::validate,
+        explanations: &constant_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::MutableConstant],
+        validate: mutable_constant_names::validate,
+        explanations: &mutable_constant_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::StateVariable],
+        validate: state_variable_names::validate,
+        explanations: &state_variable_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Error],
+        validate: error_names::validate,
+        explanations: &error_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Event],
+        validate: event_names::validate,
+        explanations: &event_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::EventParameter],
+        validate: event_parameter_names::validate,
+        explanations: &event_parameter_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Struct, ValidatorKind::Enum],
+        validate: type_names::validate,
+        explanations: &type_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::EnumMember],
+        validate: enum_member_names::validate,
+        explanations: &enum_member_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Interface],
+        validate: interface_names::validate,
+        explanations: &interface_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Library],
+        validate: library_names::validate,
+        explanations: &library_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Shadowing],
+        validate: shadowing::validate,
+        explanations: &shadowing::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Modifier],
+        validate: modifier_names::validate,
+        explanations: &modifier_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::DuplicateFunction],
+        validate: duplicate_functions::validate,
+        explanations: &duplicate_functions::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::TestContractName],
+        validate: test_contract_names::validate,
+        explanations: &test_contract_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::TestSubject],
+        validate: test_function_names::validate,
+        explanations: &test_function_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::TestBase],
+        validate: test_base_contracts::validate,
+        explanations: &test_base_contracts::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::SetUp],
+        validate: set_up_names::validate,
+        explanations: &set_up_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::EmptyTest],
+        validate: empty_tests::validate,
+        explanations: &empty_tests::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::TestPublic],
+        validate: external_tests::validate,
+        explanations: &external_tests::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::TestHelper],
+        validate: test_helper_names::validate,
+        explanations: &test_helper_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::ConstructorParameter],
+        validate: constructor_parameter_names::validate,
+        explanations: &constructor_parameter_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::AbstractContract],
+        validate: abstract_contract_names::validate,
+        explanations: &abstract_contract_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::MockContractName],
+        validate: mock_contract_names::validate,
+        explanations: &mock_contract_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::MissingDoc],
+        validate: missing_docs::validate,
+        explanations: &missing_docs::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::NatspecTag],
+        validate: natspec_tags::validate,
+        explanations: &natspec_tags::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::MissingVisibility],
+        validate: missing_visibility::validate,
+        explanations: &missing_visibility::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::LongName],
+        validate: long_names::validate,
+        explanations: &long_names::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::ContractFileName, ValidatorKind::ContractPerFile],
+        validate: contract_files::validate,
+        explanations: &contract_files::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::Assembly],
+        validate: assembly_comments::validate,
+        explanations: &assembly_comments::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::UnassignedImmutable],
+        validate: unassigned_immutables::validate,
+        explanations: &unassigned_immutables::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::MissingEvent],
+        validate: missing_events::validate,
+        explanations: &missing_events::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::StorageGap],
+        validate: storage_gaps::validate,
+        explanations: &storage_gaps::EXPLANATIONS,
+    },
+    Validator {
+        kinds: &[ValidatorKind::LongLine],
+        validate: line_length::validate,
+        explanations: &line_length::EXPLANATIONS,
+    },
+];
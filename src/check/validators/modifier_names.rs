@@ -0,0 +1,95 @@
+use crate::check::{
+    utils::{is_mixed_case, offset_to_line_col, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, FunctionTy, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Modifier,
+    rationale: "Modifiers are named like functions, since they are used in function signatures.",
+    valid: &["modifier onlyOwner() { _; }"],
+    invalid: &["modifier OnlyOwner() { _; }", "modifier only_owner() { _; }"],
+}];
+
+#[must_use]
+/// Validates that modifier names are in `mixedCase`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_name(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    let name = f.name();
+    if f.ty != FunctionTy::Modifier || is_mixed_case(&name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    Some(InvalidItem::new(ValidatorKind::Modifier, file.display().to_string(), name, line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract MyContract {
+                // These are mixedCase and should be valid.
+                modifier onlyOwner() { _; }
+                modifier whenNotPaused() { _; }
+                modifier nonReentrant() { _; }
+
+                // These are not mixedCase and should be invalid.
+                modifier OnlyOwner() { _; }
+                modifier only_owner() { _; }
+                modifier _onlyOwner() { _; }
+
+                // Functions are not modifiers, so are not checked here.
+                function Function_Name() public {}
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(3);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let content = "contract C {\n  modifier good() { _; }\n  modifier Bad() { _; }\n}";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./src/MyContract.sol", content, &config);
+
+        let locations: Vec<String> = invalid_items.iter().map(InvalidItem::location).collect();
+        assert_eq!(locations, vec!["./src/MyContract.sol:3:12"]);
+    }
+}
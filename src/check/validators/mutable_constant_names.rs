@@ -0,0 +1,119 @@
+use crate::check::{
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::{constant_names::constant_name_pattern, Explanation},
+};
+use regex::Regex;
+use solang_parser::pt::{ContractPart, SourceUnitPart, VariableAttribute, VariableDefinition};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::MutableConstant,
+    rationale: "`ALL_CAPS` names are for values that cannot change, so a mutable variable named \
+                like a constant is misleading. Either declare it `constant` or `immutable`, or \
+                rename it in `mixedCase`.",
+    valid: &["uint256 internal constant MAX_SUPPLY = 100;", "uint256 internal maxSupply = 100;"],
+    invalid: &["uint256 internal MAX_SUPPLY = 100;"],
+}];
+
+#[must_use]
+/// Validates that state variables named like constants, e.g. `MAX_SUPPLY`, are declared
+/// `constant` or `immutable`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let pattern = constant_name_pattern(config);
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::VariableDefinition(v) = el {
+                    if let Some(invalid_item) = validate_name(file, content, pattern, v) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    pattern: &Regex,
+    v: &VariableDefinition,
+) -> Option<InvalidItem> {
+    let is_constant = v
+        .attrs
+        .iter()
+        .any(|a| matches!(a, VariableAttribute::Constant(_) | VariableAttribute::Immutable(_)));
+    let name = v.name.as_ref()?;
+    if is_constant || !pattern.is_match(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let text = format!(
+        "{} is named like a constant, so declare it `constant` or `immutable`, or rename it",
+        name.name
+    );
+    Some(InvalidItem::new(
+        ValidatorKind::MutableConstant,
+        file.display().to_string(),
+        text,
+        line,
+        col,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{descriptions_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract MyContract {
+                // These are named like constants and are constant or immutable, so are valid.
+                uint256 constant MAX_SUPPLY = 100;
+                address immutable OWNER;
+
+                // These are named like constants but are mutable, so are invalid.
+                uint256 MAX_COUNT = 10;
+                address internal ADMIN;
+
+                // These are not named like constants, so are valid.
+                uint256 count;
+                address public owner;
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(2);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_message() {
+        let content = "contract C {\n  uint256 internal MAX_COUNT = 10;\n}";
+        let config = Config::default();
+        let findings =
+            descriptions_with_config(&validate, "./src/MyContract.sol", content, &config);
+        assert_eq!(
+            findings,
+            vec!["Invalid mutable variable name in ./src/MyContract.sol:2:20: MAX_COUNT is named like a constant, so declare it `constant` or `immutable`, or rename it"]
+        );
+    }
+}
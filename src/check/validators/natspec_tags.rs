@@ -0,0 +1,153 @@
+use crate::check::{
+    utils::{offset_to_line_col, signature, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::{missing_docs::functions_with_doc_start, Explanation},
+};
+use solang_parser::{
+    doccomment::{parse_doccomments, DocCommentTag},
+    pt::{Comment, FunctionDefinition, SourceUnitPart},
+};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.natspec_tags == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::NatspecTag,
+    rationale: "Tags that do not match the signature are usually left over from a refactor, and \
+                mislead readers. Undocumented functions and functions documented with \
+                `@inheritdoc` are not checked. This is disabled by default, and enabled with \
+                `natspec_tags = true`.",
+    valid: &["/// @param amount The amount to add.\n\
+              /// @return The new count.\n\
+              function add(uint256 amount) public returns (uint256) {}"],
+    invalid: &["/// @param value The amount to add.\n\
+                function add(uint256 amount) public returns (uint256) {}"],
+}];
+
+#[must_use]
+/// Validates that the NatSpec comment of each documented function has a `@param` tag for each
+/// named parameter, no `@param` tags for other names, and a `@return` tag for each return value.
+///
+/// Functions without a doc comment are not checked, see [`super::missing_docs`] for those, and
+/// neither are functions documented with `@inheritdoc`, since their tags are inherited.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, comments, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for (f, doc_start) in functions_with_doc_start(c) {
+                invalid_items.extend(validate_tags(file, content, comments, doc_start, f));
+            }
+        }
+    }
+    invalid_items
+}
+
+fn validate_tags(
+    file: &Path,
+    content: &str,
+    comments: &[Comment],
+    doc_start: usize,
+    f: &FunctionDefinition,
+) -> Vec<InvalidItem> {
+    let docs = parse_doccomments(comments, doc_start, f.loc.start());
+    let tags: Vec<&DocCommentTag> = docs.iter().flat_map(|doc| doc.comments()).collect();
+    if tags.is_empty() || tags.iter().any(|tag| tag.tag == "inheritdoc") {
+        return Vec::new()
+    }
+
+    let name = f.name();
+    let params: Vec<&str> = f
+        .params
+        .iter()
+        .filter_map(|(_, param)| param.as_ref()?.name.as_ref())
+        .map(|name| name.name.as_str())
+        .collect();
+    // The name is the first word of the tag, e.g. `amount` for `@param amount The amount`.
+    let param_tags: Vec<(&str, usize)> = tags
+        .iter()
+        .filter(|tag| tag.tag == "param")
+        .map(|tag| (tag.value.split_whitespace().next().unwrap_or_default(), tag.value_offset))
+        .collect();
+    let return_tags = tags.iter().filter(|tag| tag.tag == "return").count();
+
+    let file = file.display().to_string();
+    let item = |text: String, offset: usize| {
+        let (line, col) = offset_to_line_col(content, offset);
+        InvalidItem::new(ValidatorKind::NatspecTag, file.clone(), text, line, col)
+            .with_signature(signature(content, f))
+    };
+    let name_offset = f.name_loc().start();
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for param in &params {
+        if !param_tags.iter().any(|(tag, _)| tag == param) {
+            invalid_items.push(item(format!("{name} has no `@param {param}`"), name_offset));
+        }
+    }
+    for (tag, offset) in &param_tags {
+        if !params.contains(tag) {
+            let text = format!("`@param {tag}` is not a parameter of {name}");
+            invalid_items.push(item(text, *offset));
+        }
+    }
+    let returns = f.returns.len();
+    if return_tags != returns {
+        let text = format!("{name} has {return_tags} `@return` tags for {returns} return values");
+        invalid_items.push(item(text, name_offset));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::descriptions_with_config, config::Config};
+
+    fn findings(content: &str, natspec_tags: Option<bool>) -> Vec<String> {
+        let config = Config { natspec_tags, ..Config::default() };
+        descriptions_with_config(&validate, "./src/Counter.sol", content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract Counter {
+                /// @notice Adds to the counter.
+                /// @param amount The amount to add.
+                /// @return The new count.
+                function add(uint256 amount) public returns (uint256) {}
+
+                /**
+                 * @notice Sets the counter.
+                 * @param value The new value.
+                 * @param old No longer a parameter.
+                 */
+                function set(uint256 value, address owner) public returns (uint256) {}
+
+                /// @inheritdoc ICounter
+                function reset(uint256 value) public {}
+
+                // Undocumented functions and regular comments are not checked.
+                function clear(uint256 value) public {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings(content, None).is_empty());
+
+        let expected = vec![
+            "Mismatched NatSpec tag in ./src/Counter.sol:13:26: set(uint256,address) has no `@param owner`",
+            "Mismatched NatSpec tag in ./src/Counter.sol:11:27: `@param old` is not a parameter of set",
+            "Mismatched NatSpec tag in ./src/Counter.sol:13:26: set(uint256,address) has 0 `@return` tags for 1 return values",
+        ];
+        assert_eq!(findings(content, Some(true)), expected);
+    }
+}
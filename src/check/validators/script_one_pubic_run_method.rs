@@ -1,28 +1,69 @@
-use crate::check::utils::{
-    FileKind, InvalidItem, IsFileKind, Name, ValidatorKind, VisibilitySummary,
+use crate::{
+    check::{
+        utils::{
+            offset_to_line_col, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+            VisibilitySummary,
+        },
+        validators::Explanation,
+    },
+    config::ScriptEntrypointVisibility,
+};
+use solang_parser::pt::{
+    ContractPart, FunctionAttribute, FunctionDefinition, Identifier, SourceUnitPart, Visibility,
 };
-use solang_parser::pt::{ContractPart, SourceUnit, SourceUnitPart};
-use std::path::Path;
 
-fn is_matching_file(file: &Path) -> bool {
-    file.is_file_kind(FileKind::Script)
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Script)
 }
 
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Script,
+    rationale: "Forge runs the `run` method of a script by default, so a single entrypoint makes \
+                it clear what a script does. Allow other methods with `script_public_methods`, and \
+                only count external functions with `script_entrypoint_visibility = \"external\"`.",
+    valid: &["contract Deploy is Script {\n    \
+              function setUp() public {}\n    \
+              function run() public {}\n\
+              }"],
+    invalid: &["contract Deploy is Script {\n    \
+                function run() public {}\n    \
+                function deploy() public {}\n\
+                }"],
+}];
+
 #[must_use]
-/// Validates that a script has a single public method named `run`.
-pub fn validate(file: &Path, _content: &str, pt: &SourceUnit) -> Vec<InvalidItem> {
-    if !is_matching_file(file) {
+/// Validates that a script has a single public method named `run`. When `script_public_methods` is
+/// configured, scripts may instead have any of the allowed methods, each defined once.
+///
+/// Public and external functions are the public methods by default. With
+/// `script_entrypoint_visibility = "external"`, only external functions are, so scripts can have
+/// public helpers. The finding is reported on the name of the script contract, i.e. the first
+/// contract with a public method, or the first contract if none has one.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
         return Vec::new()
     }
 
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let allowed_methods: Vec<&str> = config
+        .script_public_methods
+        .as_ref()
+        .map_or_else(|| vec!["run"], |names| names.iter().map(String::as_str).collect());
+    let entrypoints = config.script_entrypoint_visibility.unwrap_or_default();
+
     let mut public_methods: Vec<String> = Vec::new();
+    let mut first_contract: Option<&Identifier> = None;
+    let mut script_contract: Option<&Identifier> = None;
     for element in &pt.0 {
         if let SourceUnitPart::ContractDefinition(c) = element {
+            first_contract = first_contract.or(c.name.as_ref());
             for el in &c.parts {
                 if let ContractPart::FunctionDefinition(f) = el {
                     let name = f.name();
-                    if f.is_public_or_external() && name != "setUp" && name != "constructor" {
+                    if is_entrypoint(f, entrypoints) && name != "setUp" && name != "constructor" {
                         public_methods.push(name);
+                        script_contract = script_contract.or(c.name.as_ref());
                     }
                 }
             }
@@ -31,83 +72,116 @@ pub fn validate(file: &Path, _content: &str, pt: &SourceUnit) -> Vec<InvalidItem
 
     // Parse the public methods found to return a vec that's either empty if valid, or has a single
     // invalid item otherwise.
-    match public_methods.len() {
-        0 => {
-            vec![InvalidItem::new(
-                ValidatorKind::Script,
-                file.display().to_string(),
-                "No `run` method found".to_string(),
-                0, // This spans multiple lines, so we don't have a line number.
-            )]
-        }
+    let expected = describe(&allowed_methods);
+    let visibility = match entrypoints {
+        ScriptEntrypointVisibility::Public => "public",
+        ScriptEntrypointVisibility::External => "external",
+    };
+    let text = match public_methods.len() {
+        0 => format!("No {expected} method found"),
         1 => {
-            if public_methods[0] == "run" {
-                Vec::new()
-            } else {
-                vec![InvalidItem::new(
-                    ValidatorKind::Script,
-                    file.display().to_string(),
-                    "The only public method must be named `run`".to_string(),
-                    0,
-                )]
+            if allowed_methods.contains(&public_methods[0].as_str()) {
+                return Vec::new()
             }
+            format!("The only {visibility} method must be named {expected}")
         }
         _ => {
-            vec![InvalidItem::new(
-              ValidatorKind::Script,
-              file.display().to_string(),
-              format!("Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: {public_methods:?}"),
-              0,
-          )]
+            let is_allowed = |name: &String| allowed_methods.contains(&name.as_str());
+            let is_unique = |(i, name): (usize, &String)| !public_methods[..i].contains(name);
+            if allowed_methods.len() > 1 &&
+                public_methods.iter().all(is_allowed) &&
+                public_methods.iter().enumerate().all(is_unique)
+            {
+                return Vec::new()
+            }
+
+            if allowed_methods.len() == 1 {
+                format!("Scripts must have a single {visibility} method named {expected} (excluding `setUp`), but the following methods were found: {public_methods:?}")
+            } else {
+                format!("Scripts must only have {visibility} methods named {expected}, each defined once (excluding `setUp`), but the following methods were found: {public_methods:?}")
+            }
         }
+    };
+
+    // The methods span multiple lines, so the finding is reported on the contract name instead, or
+    // without a line or column number if the file has no contracts.
+    let (line, col) = script_contract
+        .or(first_contract)
+        .map_or((0, 0), |name| offset_to_line_col(content, name.loc.start()));
+    vec![InvalidItem::new(ValidatorKind::Script, file.display().to_string(), text, line, col)]
+}
+
+// Returns `true` if `f` is one of the public methods of a script, given which visibilities count.
+fn is_entrypoint(f: &FunctionDefinition, entrypoints: ScriptEntrypointVisibility) -> bool {
+    match entrypoints {
+        ScriptEntrypointVisibility::Public => f.is_public_or_external(),
+        ScriptEntrypointVisibility::External => f
+            .attributes
+            .iter()
+            .any(|a| matches!(a, FunctionAttribute::Visibility(Visibility::External(_)))),
+    }
+}
+
+// Formats the allowed method names for a message, e.g. "`run`" or "`deploy` or `upgrade`".
+fn describe(names: &[&str]) -> String {
+    let names: Vec<String> = names.iter().map(|name| format!("`{name}`")).collect();
+    match names.as_slice() {
+        [] => String::new(),
+        [name] => name.clone(),
+        [rest @ .., last] => format!("{} or {last}", rest.join(", ")),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::check::utils::ExpectedFindings;
+    use crate::{
+        check::utils::{
+            descriptions_with_config, findings_with_config, messages_with_config, ExpectedFindings,
+        },
+        config::Config,
+    };
 
     #[test]
     fn test_validate() {
         // TODO add another test for the third match arm
-        let content_good = r#"
+        let content_good = r"
             contract MyContract {
                 function run() public {}
             }
-        "#;
+        ";
 
         // The number after `bad` on the variable name indicates the match arm covered.
-        let content_bad0 = r#"
+        let content_bad0 = r"
             contract MyContract {}
-        "#;
+        ";
 
-        let content_bad1 = r#"
+        let content_bad1 = r"
             contract MyContract {
                 function notRun() public {}
             }
-        "#;
+        ";
 
-        let content_bad2_variant0 = r#"
+        let content_bad2_variant0 = r"
             contract MyContract {
                 function run() public {}
                 function run(string memory config) public {}
             }
-        "#;
+        ";
 
-        let content_bad2_variant1 = r#"
+        let content_bad2_variant1 = r"
             contract MyContract {
                 function run() public {}
                 function foo() public {}
             }
-        "#;
+        ";
 
-        let content_bad2_variant2 = r#"
+        let content_bad2_variant2 = r"
             contract MyContract {
                 function foo() public {}
                 function bar() public {}
             }
-        "#;
+        ";
 
         let expected_findings_good = ExpectedFindings::new(0);
         expected_findings_good.assert_eq(content_good, &validate);
@@ -119,4 +193,82 @@ mod tests {
         expected_findings_bad.assert_eq(content_bad2_variant1, &validate);
         expected_findings_bad.assert_eq(content_bad2_variant2, &validate);
     }
+
+    #[test]
+    fn test_validate_with_config() {
+        let config = Config {
+            script_public_methods: Some(vec!["deploy".to_string(), "upgrade".to_string()]),
+            ..Config::default()
+        };
+        let findings = |content: &str| -> Vec<String> {
+            messages_with_config(&validate, "./script/Deploy.s.sol", content, &config)
+        };
+
+        // Any subset of the allowed methods is valid.
+        let content =
+            "contract Deploy { function deploy() public {} function upgrade() public {} }";
+        assert!(findings(content).is_empty());
+        assert!(findings("contract Deploy { function upgrade() external {} }").is_empty());
+
+        // Methods that are not allowed, overloads, and no allowed methods are invalid.
+        let content = "contract Deploy { function deploy() public {} function run() public {} }";
+        let expected = r#"Invalid script interface: Scripts must only have public methods named `deploy` or `upgrade`, each defined once (excluding `setUp`), but the following methods were found: ["deploy", "run"]"#;
+        assert_eq!(findings(content), vec![expected]);
+        let content =
+            "contract Deploy { function deploy() public {} function deploy(uint256) public {} }";
+        assert_eq!(findings(content).len(), 1);
+        let expected = "Invalid script interface: No `deploy` or `upgrade` method found";
+        assert_eq!(findings("contract Deploy {}"), vec![expected]);
+    }
+
+    #[test]
+    fn test_validate_external_entrypoints() {
+        let findings = |content: &str, entrypoints: Option<ScriptEntrypointVisibility>| {
+            let config = Config { script_entrypoint_visibility: entrypoints, ..Config::default() };
+            findings_with_config(&validate, "./script/Deploy.s.sol", content, &config)
+                .iter()
+                .map(InvalidItem::message)
+                .collect::<Vec<String>>()
+        };
+
+        // Public helpers are only allowed when just external functions are entrypoints.
+        let content = "contract Deploy { function run() external {} function helper() public {} }";
+        assert_eq!(findings(content, None).len(), 1);
+        assert_eq!(findings(content, Some(ScriptEntrypointVisibility::Public)).len(), 1);
+        assert!(findings(content, Some(ScriptEntrypointVisibility::External)).is_empty());
+
+        // A public `run` is then not an entrypoint.
+        let content = "contract Deploy { function run() public {} }";
+        let expected = "Invalid script interface: No `run` method found";
+        assert_eq!(findings(content, Some(ScriptEntrypointVisibility::External)), vec![expected]);
+        let content = "contract Deploy { function deploy() external {} }";
+        let expected = "Invalid script interface: The only external method must be named `run`";
+        assert_eq!(findings(content, Some(ScriptEntrypointVisibility::External)), vec![expected]);
+    }
+
+    #[test]
+    fn test_validate_location() {
+        let findings = |content: &str| -> Vec<String> {
+            let config = Config::default();
+            descriptions_with_config(&validate, "./script/Deploy.s.sol", content, &config)
+        };
+
+        // Findings are reported on the first contract with a public method, or the first contract.
+        let content = "contract Helper {}\ncontract Deploy {\n    function deploy() public {}\n}";
+        let expected = "Invalid script interface in ./script/Deploy.s.sol:2:10: The only public method must be named `run`";
+        assert_eq!(findings(content), vec![expected]);
+        let content = "contract Helper {}\ncontract Deploy {}";
+        let expected =
+            "Invalid script interface in ./script/Deploy.s.sol:1:10: No `run` method found";
+        assert_eq!(findings(content), vec![expected]);
+        let expected = "Invalid script interface in ./script/Deploy.s.sol: No `run` method found";
+        assert_eq!(findings("pragma solidity ^0.8.17;"), vec![expected]);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&["run"]), "`run`");
+        assert_eq!(describe(&["deploy", "upgrade"]), "`deploy` or `upgrade`");
+        assert_eq!(describe(&["a", "b", "c"]), "`a`, `b` or `c`");
+    }
 }
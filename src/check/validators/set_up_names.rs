@@ -0,0 +1,99 @@
+use crate::check::{
+    utils::{offset_to_line_col, FileKind, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, FunctionTy, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::SetUp,
+    rationale: "Forge only calls `setUp` before each test, so functions with any other casing \
+                silently never run.",
+    valid: &["function setUp() public {}"],
+    invalid: &["function setup() public {}", "function SetUp() public {}"],
+}];
+
+#[must_use]
+/// Validates that test files do not have functions named like `setUp` with different casing, e.g.
+/// `setup` or `SetUp`. Forge only calls `setUp` before each test, so these never run.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_name(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn is_misspelled_set_up(name: &str) -> bool {
+    name != "setUp" && name.eq_ignore_ascii_case("setUp")
+}
+
+fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    let name = f.name();
+    if f.ty != FunctionTy::Function || !is_misspelled_set_up(&name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{name} is never called by forge, rename it to `setUp`");
+    Some(InvalidItem::new(ValidatorKind::SetUp, file.display().to_string(), text, line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str) -> Vec<String> {
+        let config = Config::default();
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract CounterTest {
+                // These are valid.
+                function setUp() public {}
+                function setUpCounter() public {}
+                function test_Setup() public {}
+
+                // These differ from `setUp` only in casing, and should be invalid.
+                function setup() public {}
+                function Setup() public {}
+                function SetUp() public {}
+                function SETUP() public {}
+            }
+        ";
+
+        let expected = vec![
+            "Misspelled setUp function: setup is never called by forge, rename it to `setUp`",
+            "Misspelled setUp function: Setup is never called by forge, rename it to `setUp`",
+            "Misspelled setUp function: SetUp is never called by forge, rename it to `setUp`",
+            "Misspelled setUp function: SETUP is never called by forge, rename it to `setUp`",
+        ];
+        assert_eq!(findings("./test/Counter.t.sol", content), expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::check::{
+    symbols::Scope,
+    utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractDefinition, ContractPart, SourceUnitPart, VariableDefinition};
+use std::{collections::HashMap, path::Path};
+
+fn state_variables(c: &ContractDefinition) -> impl Iterator<Item = &VariableDefinition> {
+    c.parts.iter().filter_map(|el| match el {
+        ContractPart::VariableDefinition(v) => Some(v.as_ref()),
+        _ => None,
+    })
+}
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.config.shadowing == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Shadowing,
+    rationale: "A state variable with the same name as an inherited one hides it, so the contract \
+                reads a different variable than expected. Solidity 0.6 and later reject this, so \
+                the check is disabled by default, and enabled with `shadowing = true`. Private \
+                variables are not inherited, so they can be reused.",
+    valid: &["contract Base { uint256 internal count; }\n\
+              contract Counter is Base { uint256 internal total; }"],
+    invalid: &["contract Base { uint256 internal count; }\n\
+                contract Counter is Base { uint256 internal count; }"],
+}];
+
+#[must_use]
+/// Validates that state variables do not have the same name as a state variable they inherit.
+///
+/// Base contracts are resolved by name among the contracts in the file and in
+/// [`Config::symbols`], which holds the contracts of all project files. Private variables
+/// are not inherited, so they can be reused.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, kind, content, pt, config, .. } = parsed;
+    // The contracts in this file take precedence, since the index can be out of date, e.g. for
+    // sources read from stdin.
+    let scope = config.symbols.scope(pt, *kind);
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            invalid_items.extend(validate_contract(file, content, &scope, c));
+        }
+    }
+    invalid_items
+}
+
+// Returns the variables the contract `name` inherits, mapped to the nearest base contract that
+// declares them.
+fn inherited_variables<'a>(name: &'a str, scope: &'a Scope) -> HashMap<&'a str, &'a str> {
+    let mut variables = HashMap::new();
+    for (base, symbols) in scope.ancestors(name) {
+        for variable in &symbols.variables {
+            variables.entry(variable.as_str()).or_insert(base);
+        }
+    }
+    variables
+}
+
+fn validate_contract(
+    file: &Path,
+    content: &str,
+    scope: &Scope,
+    c: &ContractDefinition,
+) -> Vec<InvalidItem> {
+    let Some(name) = &c.name else { return Vec::new() };
+    let inherited = inherited_variables(&name.name, scope);
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for v in state_variables(c) {
+        let Some(name) = &v.name else { continue };
+        let Some(base) = inherited.get(name.name.as_str()) else { continue };
+
+        let (line, col) = offset_to_line_col(content, name.loc.start());
+        let text = format!("{} shadows the state variable inherited from `{base}`", name.name);
+        let file = file.display().to_string();
+        invalid_items.push(InvalidItem::new(ValidatorKind::Shadowing, file, text, line, col));
+    }
+    invalid_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::{
+            symbols::{ContractSymbols, SymbolIndex},
+            utils::messages_with_config,
+        },
+        config::Config,
+    };
+    use std::{collections::HashSet, sync::Arc};
+
+    fn findings(content: &str, config: &Config) -> Vec<String> {
+        messages_with_config(&validate, "./src/Counter.sol", content, config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract Base {
+                uint256 public count;
+                address internal owner;
+                uint256 private secret;
+            }
+            contract Middle is Base {
+                uint256 internal limit;
+            }
+
+            // These shadow variables of direct and indirect bases, and should be invalid.
+            contract Counter is Middle {
+                uint256 internal count;
+                address public owner;
+                uint256 limit;
+
+                // Private variables are not inherited, and new names are valid.
+                uint256 internal secret;
+                uint256 internal total;
+            }
+        ";
+
+        let config = Config { shadowing: Some(true), ..Config::default() };
+        let expected = vec![
+            "Shadowed state variable: count shadows the state variable inherited from `Base`",
+            "Shadowed state variable: owner shadows the state variable inherited from `Base`",
+            "Shadowed state variable: limit shadows the state variable inherited from `Middle`",
+        ];
+        assert_eq!(findings(content, &config), expected);
+
+        // The check is disabled by default.
+        assert!(findings(content, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_other_files() {
+        // Bases in other files are resolved through the symbol index.
+        let mut config = Config { shadowing: Some(true), ..Config::default() };
+        let base = ContractSymbols {
+            bases: vec!["Counter".to_string()],
+            variables: HashSet::from(["number".to_string()]),
+            ..ContractSymbols::default()
+        };
+        let mut symbols = SymbolIndex::default();
+        symbols.insert("Base", base);
+        config.symbols = Arc::new(symbols);
+
+        // The inheritance cycle between `Base` and `Counter` is not followed forever.
+        let content = "contract Counter is Base { uint256 number; uint256 other; }";
+        let expected = vec![
+            "Shadowed state variable: number shadows the state variable inherited from `Base`",
+        ];
+        assert_eq!(findings(content, &config), expected);
+
+        // Unknown bases are skipped.
+        let content = "contract Counter is Unknown { uint256 number; }";
+        assert!(
+            findings(content, &Config { shadowing: Some(true), ..Config::default() }).is_empty()
+        );
+    }
+}
@@ -1,20 +1,35 @@
-use crate::check::utils::{
-    offset_to_line, FileKind, InvalidItem, IsFileKind, Name, ValidatorKind, VisibilitySummary,
+use crate::check::{
+    utils::{
+        offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+        VisibilitySummary,
+    },
+    validators::Explanation,
 };
-use solang_parser::pt::{ContractPart, ContractTy, FunctionDefinition, SourceUnit, SourceUnitPart};
+use solang_parser::pt::{ContractPart, ContractTy, FunctionDefinition, FunctionTy, SourceUnitPart};
 use std::path::Path;
 
-fn is_matching_file(file: &Path) -> bool {
-    file.is_file_kind(FileKind::Src)
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Src)
 }
 
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::Src,
+    rationale: "A leading underscore shows at the call site that a function is not part of the \
+                contract's external interface.",
+    valid: &["function _update() internal {}", "function _check() private view {}"],
+    invalid: &["function update() internal {}"],
+}];
+
 #[must_use]
 /// Validates that internal and private function names are prefixed with an underscore.
-pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem> {
-    if !is_matching_file(file) {
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
         return Vec::new()
     }
 
+    let Parsed { file, content, pt, .. } = parsed;
+
     let mut invalid_items: Vec<InvalidItem> = Vec::new();
     for element in &pt.0 {
         match element {
@@ -24,7 +39,7 @@ pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem>
                 }
             }
             SourceUnitPart::ContractDefinition(c) => match c.ty {
-                ContractTy::Library(_) => continue,
+                ContractTy::Library(_) => (),
                 _ => {
                     for el in &c.parts {
                         if let ContractPart::FunctionDefinition(f) = el {
@@ -46,14 +61,18 @@ fn is_valid_internal_or_private_name(name: &str) -> bool {
 }
 
 fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    // Constructors, fallbacks, and receives have fixed names, and modifiers have no visibility.
+    if f.ty != FunctionTy::Function {
+        return None
+    }
+
     let name = f.name();
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
     if f.is_internal_or_private() && !is_valid_internal_or_private_name(&name) {
-        Some(InvalidItem::new(
-            ValidatorKind::Src,
-            file.display().to_string(),
-            name,
-            offset_to_line(content, f.loc.start()),
-        ))
+        Some(
+            InvalidItem::new(ValidatorKind::Src, file.display().to_string(), name, line, col)
+                .with_signature(signature(content, f)),
+        )
     } else {
         None
     }
@@ -62,11 +81,14 @@ fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<I
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::check::utils::ExpectedFindings;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
 
     #[test]
     fn test_validate() {
-        let content = r#"
+        let content = r"
             contract MyContract {
                 // Valid names for internal or private src methods.
                 function _myInternalMethod() internal {}
@@ -80,9 +102,34 @@ mod tests {
                 function myPublicMethod() public {}
                 function myExternalMethod() external {}
             }
-        "#;
+        ";
 
         let expected_findings = ExpectedFindings { src: 2, ..ExpectedFindings::default() };
         expected_findings.assert_eq(content, &validate);
     }
+
+    #[test]
+    fn test_validate_lines() {
+        let content = r"
+            pragma solidity 0.6.12;
+
+            // Free functions are checked too.
+            function freeFunction() internal {}
+
+            abstract contract MyContract {
+                // Special function types are ignored.
+                constructor() internal {}
+                fallback() external {}
+                receive() external payable {}
+
+                function myPrivateMethod() private {}
+            }
+        ";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./src/MyContract.sol", content, &config);
+
+        let lines: Vec<usize> = invalid_items.iter().map(InvalidItem::line).collect();
+        assert_eq!(lines, vec![5, 13]);
+    }
 }
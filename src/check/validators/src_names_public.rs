@@ -0,0 +1,114 @@
+use crate::check::{
+    utils::{
+        offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+        VisibilitySummary,
+    },
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, FunctionTy, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Src)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::SrcPublic,
+    rationale: "A leading underscore marks internal and private functions, so public and external \
+                functions must not have one.",
+    valid: &["function increment() external {}"],
+    invalid: &["function _increment() public {}"],
+}];
+
+#[must_use]
+/// Validates that public and external function names are not prefixed with an underscore.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::FunctionDefinition(f) = el {
+                    if let Some(invalid_item) = validate_name(file, content, f) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+fn is_valid_public_or_external_name(name: &str) -> bool {
+    !name.starts_with('_')
+}
+
+fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    if f.ty != FunctionTy::Function {
+        return None
+    }
+
+    let name = f.name();
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    if f.is_public_or_external() && !is_valid_public_or_external_name(&name) {
+        Some(
+            InvalidItem::new(ValidatorKind::SrcPublic, file.display().to_string(), name, line, col)
+                .with_signature(signature(content, f)),
+        )
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::utils::ExpectedFindings;
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract MyContract {
+                // Valid names for public or external src methods.
+                function myPublicMethod() public {}
+                function myExternalMethod() external {}
+
+                // Invalid names for public or external src methods.
+                function _myPublicMethod() public {}
+                function _myExternalMethod() external {}
+                function __myExternalMethod() external view returns (uint256) {}
+
+                // These should be ignored since they are internal and private.
+                function _myInternalMethod() internal {}
+                function _myPrivateMethod() private {}
+                function myInternalMethod() internal {}
+            }
+
+            interface IMyContract {
+                function _myInterfaceMethod() external;
+            }
+
+            library MyLibrary {
+                function _myLibraryMethod() public {}
+                function _myInternalLibraryMethod() internal {}
+            }
+        ";
+
+        let expected_findings = ExpectedFindings { src: 5, ..ExpectedFindings::default() };
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_is_valid_public_or_external_name() {
+        assert!(is_valid_public_or_external_name("transfer"));
+        assert!(is_valid_public_or_external_name("transfer_"));
+        assert!(!is_valid_public_or_external_name("_transfer"));
+        assert!(!is_valid_public_or_external_name("__transfer"));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    check::{
+        utils::{offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+        validators::{constant_names::constant_name_pattern, Explanation},
+    },
+    config::Config,
+};
+use regex::Regex;
+use solang_parser::pt::{ContractPart, SourceUnitPart, VariableAttribute, VariableDefinition};
+use std::{path::Path, sync::LazyLock};
+
+// The default regex matching valid state variable names, which are in `mixedCase` with an optional
+// leading underscore, e.g. `count` or `_balances`. This can be overridden with
+// `state_variable_pattern` in `.scopelint.toml`.
+static RE_VALID_STATE_VARIABLE_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^_?[a-z][a-zA-Z0-9]*$").unwrap());
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::StateVariable,
+    rationale: "Mutable state variables are named like local variables, with an optional leading \
+                underscore, so they are not mistaken for constants. Override the convention with \
+                `state_variable_pattern`.",
+    valid: &["count", "_balances", "owner2"],
+    invalid: &["My_Var", "SOME_value", "Owner", "is_paused"],
+}];
+
+#[must_use]
+/// Validates that mutable state variable names are in `mixedCase`, or match the configured
+/// pattern.
+///
+/// Constants and immutables are checked by the constant name validator instead. Mutable variables
+/// named like constants are reported as mutable constants, so they are not reported again here.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            for el in &c.parts {
+                if let ContractPart::VariableDefinition(v) = el {
+                    if let Some(invalid_item) = validate_name(file, content, config, v) {
+                        invalid_items.push(invalid_item);
+                    }
+                }
+            }
+        }
+    }
+    invalid_items
+}
+
+pub(crate) fn state_variable_pattern(config: &Config) -> &Regex {
+    config.state_variable_pattern.as_ref().unwrap_or(&RE_VALID_STATE_VARIABLE_NAME)
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    config: &Config,
+    v: &VariableDefinition,
+) -> Option<InvalidItem> {
+    let is_constant = v
+        .attrs
+        .iter()
+        .any(|a| matches!(a, VariableAttribute::Constant(_) | VariableAttribute::Immutable(_)));
+    let name = v.name.as_ref()?;
+    if is_constant ||
+        constant_name_pattern(config).is_match(&name.name) ||
+        state_variable_pattern(config).is_match(&name.name)
+    {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let file = file.display().to_string();
+    Some(InvalidItem::new(ValidatorKind::StateVariable, file, name.name.clone(), line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::utils::{descriptions_with_config, ExpectedFindings};
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract MyContract {
+                // These are mixedCase and should be valid.
+                uint256 public count;
+                mapping(address => uint256) internal _balances;
+                address private owner2;
+
+                // Constants and immutables are checked by the constant name validator.
+                uint256 constant Max_Supply = 100;
+                address immutable Owner_Address;
+
+                // Mutable variables named like constants are reported as mutable constants.
+                uint256 internal MAX_COUNT = 10;
+
+                // These are not mixedCase and should be invalid.
+                uint256 public My_Var;
+                uint256 internal SOME_value;
+                address private Owner;
+                bool internal is_paused;
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(4);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_pattern() {
+        let content = "contract C {\n  uint256 internal s_count;\n  uint256 internal count;\n}";
+        let config = Config {
+            state_variable_pattern: Some(Regex::new(r"^s_[a-z]\w*$").unwrap()),
+            ..Config::default()
+        };
+        let findings =
+            descriptions_with_config(&validate, "./src/MyContract.sol", content, &config);
+        assert_eq!(
+            findings,
+            vec!["Invalid state variable name in ./src/MyContract.sol:3:20: count"]
+        );
+    }
+}
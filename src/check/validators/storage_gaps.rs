@@ -0,0 +1,136 @@
+use crate::check::{
+    utils::{offset_to_line_col, FileKind, InvalidItem, Parsed, ValidatorKind},
+    validators::{
+        test_base_contracts::{base_names, inherits},
+        Explanation,
+    },
+};
+use solang_parser::pt::{
+    ContractDefinition, ContractPart, ContractTy, Expression, Identifier, SourceUnitPart,
+    VariableDefinition,
+};
+use std::{collections::HashMap, path::Path};
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Src) && parsed.config.upgradeable_bases.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::StorageGap,
+    rationale: "Upgradeable contracts reserve storage slots in a `__gap` array, so a new version \
+                can add state variables without shifting the storage of the contracts that \
+                inherit from it. A contract is upgradeable if it inherits from one of the \
+                `upgradeable_bases`, directly or through other contracts in the same file. This \
+                is disabled by default, and these examples use `upgradeable_bases = \
+                [\"Initializable\"]`.",
+    valid: &["contract Vault is Initializable {\n    uint256 public total;\n    \
+              uint256[49] private __gap;\n}"],
+    invalid: &["contract Vault is Initializable {\n    uint256 public total;\n}"],
+}];
+
+#[must_use]
+/// Validates that upgradeable contracts in src files declare a `__gap` array, which reserves
+/// storage slots for later versions.
+///
+/// Contracts are upgradeable if they inherit from one of the `upgradeable_bases`, e.g.
+/// `Initializable`, directly or through other contracts in the same file. Bases defined in other
+/// files are not followed, and the size of the gap is not checked.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(upgradeable_bases) = &config.upgradeable_bases else { return Vec::new() };
+
+    let contracts: Vec<&ContractDefinition> =
+        pt.0.iter()
+            .filter_map(|element| match element {
+                SourceUnitPart::ContractDefinition(c) => Some(c.as_ref()),
+                _ => None,
+            })
+            .collect();
+    let bases: HashMap<&str, Vec<&str>> = contracts
+        .iter()
+        .filter_map(|c| Some((c.name.as_ref()?.name.as_str(), base_names(c))))
+        .collect();
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for c in contracts {
+        let Some(name) = &c.name else { continue };
+        if !matches!(c.ty, ContractTy::Contract(_) | ContractTy::Abstract(_)) || has_gap(c) {
+            continue
+        }
+        let Some(base) = upgradeable_bases.iter().find(|base| inherits(&name.name, base, &bases))
+        else {
+            continue
+        };
+        invalid_items.push(invalid_item(file, content, name, base));
+    }
+    invalid_items
+}
+
+// Returns `true` if `c` declares a state variable named `__gap` with an array type.
+fn has_gap(c: &ContractDefinition) -> bool {
+    c.parts.iter().any(|el| match el {
+        ContractPart::VariableDefinition(v) => is_gap(v),
+        _ => false,
+    })
+}
+
+fn is_gap(v: &VariableDefinition) -> bool {
+    v.name.as_ref().is_some_and(|name| name.name == "__gap") &&
+        matches!(v.ty, Expression::ArraySubscript(..))
+}
+
+fn invalid_item(file: &Path, content: &str, name: &Identifier, base: &str) -> InvalidItem {
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let text = format!("{} inherits from `{base}`, but has no `__gap` array", name.name);
+    InvalidItem::new(ValidatorKind::StorageGap, file.display().to_string(), text, line, col)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, upgradeable_bases: Option<&[&str]>) -> Vec<String> {
+        let upgradeable_bases =
+            upgradeable_bases.map(|bases| bases.iter().map(ToString::to_string).collect());
+        let config = Config { upgradeable_bases, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // Upgradeable contracts with a gap are valid, whether they inherit directly or not.
+            abstract contract VaultBase is Initializable { uint256[50] private __gap; }
+            contract Vault is VaultBase { uint256 total; uint256[49] private __gap; }
+
+            // These are upgradeable without a gap, or with a gap that is not an array.
+            contract Token is oz.Initializable { uint256 supply; }
+            contract Staking is VaultBase { uint256 private __gap; }
+            contract Proxy is UUPSUpgradeable {}
+
+            // Contracts that are not upgradeable, and interfaces, do not need a gap.
+            contract Counter is Ownable {}
+            interface IVault is Initializable {}
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./src/Vault.sol", content, None).is_empty());
+
+        let bases: &[&str] = &["Initializable", "UUPSUpgradeable"];
+        let expected = vec![
+            "Missing storage gap: Token inherits from `Initializable`, but has no `__gap` array",
+            "Missing storage gap: Staking inherits from `Initializable`, but has no `__gap` array",
+            "Missing storage gap: Proxy inherits from `UUPSUpgradeable`, but has no `__gap` array",
+        ];
+        assert_eq!(findings("./src/Vault.sol", content, Some(bases)), expected);
+
+        // Only src files are checked.
+        assert!(findings("./test/Vault.t.sol", content, Some(bases)).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::check::{
+    utils::{offset_to_line_col, FileKind, InvalidItem, Parsed, ValidatorKind},
+    validators::{test_contract_names::is_test_contract, Explanation},
+};
+use solang_parser::pt::{ContractDefinition, SourceUnitPart};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.test_base_contract.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::TestBase,
+    rationale: "A shared base contract gives every test the same setup and helpers. The base can \
+                be inherited directly or through other contracts in the same file. This is \
+                disabled by default, and these examples use `test_base_contract = \"BaseTest\"`.",
+    valid: &["contract CounterTest is BaseTest {\n    function test_Increment() public {}\n}"],
+    invalid: &["contract CounterTest is Test {\n    function test_Increment() public {}\n}"],
+}];
+
+#[must_use]
+/// Validates that test contracts inherit from the contract configured with `test_base_contract`,
+/// e.g. `Test` or `BaseTest`.
+///
+/// The base can be inherited directly or through other contracts in the same file, such as a
+/// shared `CounterSetup` contract. Bases defined in other files are not followed.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let Some(required) = config.test_base_contract.as_deref() else { return Vec::new() };
+
+    let contracts: Vec<&ContractDefinition> =
+        pt.0.iter()
+            .filter_map(|element| match element {
+                SourceUnitPart::ContractDefinition(c) => Some(c.as_ref()),
+                _ => None,
+            })
+            .collect();
+    let bases: HashMap<&str, Vec<&str>> = contracts
+        .iter()
+        .filter_map(|c| Some((c.name.as_ref()?.name.as_str(), base_names(c))))
+        .collect();
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for c in contracts {
+        if let Some(invalid_item) = validate_bases(file, content, required, &bases, c) {
+            invalid_items.push(invalid_item);
+        }
+    }
+    invalid_items
+}
+
+/// Returns the names of the contracts that `c` inherits from directly, e.g. `Test` for both
+/// `is Test` and `is forge.Test`.
+pub(crate) fn base_names(c: &ContractDefinition) -> Vec<&str> {
+    c.base
+        .iter()
+        .filter_map(|base| base.name.identifiers.last())
+        .map(|identifier| identifier.name.as_str())
+        .collect()
+}
+
+/// Returns `true` if the contract named `name` inherits from `required`, following the bases that
+/// are defined in `bases`.
+pub(crate) fn inherits(name: &str, required: &str, bases: &HashMap<&str, Vec<&str>>) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending = bases.get(name).cloned().unwrap_or_default();
+    while let Some(base) = pending.pop() {
+        if base == required {
+            return true
+        }
+        // Skips contracts that were already visited, which guards against inheritance cycles.
+        if visited.insert(base) {
+            pending.extend(bases.get(base).into_iter().flatten());
+        }
+    }
+    false
+}
+
+fn validate_bases(
+    file: &Path,
+    content: &str,
+    required: &str,
+    bases: &HashMap<&str, Vec<&str>>,
+    c: &ContractDefinition,
+) -> Option<InvalidItem> {
+    let name = c.name.as_ref()?;
+    if !is_test_contract(c) || inherits(&name.name, required, bases) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let text = format!("{} does not inherit from `{required}`", name.name);
+    Some(InvalidItem::new(ValidatorKind::TestBase, file.display().to_string(), text, line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, required: Option<&str>) -> Vec<String> {
+        let config =
+            Config { test_base_contract: required.map(str::to_string), ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // Contracts without tests do not need to inherit from the base.
+            contract CounterSetup is BaseTest { function setUp() public {} }
+            contract Helpers { function helper() public {} }
+
+            // These inherit from the base directly, qualified, or through the setup contract.
+            contract CounterTest is BaseTest { function test_Increment() public {} }
+            contract CounterQualifiedTest is forge.BaseTest { function test_Increment() public {} }
+            contract CounterDecrementTest is CounterSetup { function test_Decrement() public {} }
+
+            // These do not inherit from the base, and should be invalid.
+            contract CounterResetTest is Helpers { function test_Reset() public {} }
+            contract CounterOtherTest { function test_Other() public {} }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./test/Counter.t.sol", content, None).is_empty());
+
+        let expected = vec![
+            "Missing test base contract: CounterResetTest does not inherit from `BaseTest`",
+            "Missing test base contract: CounterOtherTest does not inherit from `BaseTest`",
+        ];
+        assert_eq!(findings("./test/Counter.t.sol", content, Some("BaseTest")), expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content, Some("BaseTest")).is_empty());
+    }
+
+    #[test]
+    fn test_inherits() {
+        let bases = HashMap::from([("A", vec!["B"]), ("B", vec!["A", "C"]), ("C", vec![])]);
+        assert!(inherits("A", "C", &bases));
+        assert!(!inherits("A", "D", &bases));
+        assert!(!inherits("D", "A", &bases));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    check::{
+        utils::{offset_to_line_col, FileKind, InvalidItem, Parsed, ValidatorKind},
+        validators::{test_names::is_test_function, Explanation},
+    },
+    config::TestContractNames,
+};
+use solang_parser::pt::{ContractDefinition, ContractPart, ContractTy, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.test_contract_names.is_some()
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::TestContractName,
+    rationale: "A `Test` suffix tells test contracts apart from helpers and the contracts under \
+                test. With `test_contract_names = \"file\"`, names must also start with the file \
+                name. This is disabled by default, and these examples use `test_contract_names = \
+                \"suffix\"`.",
+    valid: &["contract CounterTest {\n    function test_Increment() public {}\n}"],
+    invalid: &["contract TestCounter {\n    function test_Increment() public {}\n}"],
+}];
+
+#[must_use]
+/// Validates that contracts with test methods are named `<Name>Test` or `<Name>_Test`.
+///
+/// With `test_contract_names = "file"`, the name must also start with the file name, e.g. contracts
+/// in `Counter.t.sol` are named like `CounterTest` or `CounterIncrement_Test`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+    let stem = match config.test_contract_names {
+        Some(TestContractNames::File) => file_stem(file),
+        _ => None,
+    };
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            if let Some(invalid_item) = validate_name(file, content, stem, c) {
+                invalid_items.push(invalid_item);
+            }
+        }
+    }
+    invalid_items
+}
+
+// Returns the file name without the `.t.sol` extension, e.g. `Counter` for `./test/Counter.t.sol`.
+fn file_stem(file: &Path) -> Option<&str> {
+    file.file_name()?.to_str()?.strip_suffix(".t.sol")
+}
+
+fn is_valid_test_contract_name(name: &str, stem: Option<&str>) -> bool {
+    let has_suffix = name.len() > "Test".len() && name.ends_with("Test");
+    has_suffix && stem.is_none_or(|stem| name.starts_with(stem))
+}
+
+// Only concrete contracts with test methods are test contracts, which excludes base contracts that
+// only contain shared setup.
+pub(crate) fn is_test_contract(c: &ContractDefinition) -> bool {
+    let has_tests = c.parts.iter().any(|el| match el {
+        ContractPart::FunctionDefinition(f) => is_test_function(f),
+        _ => false,
+    });
+    matches!(c.ty, ContractTy::Contract(_)) && has_tests
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    stem: Option<&str>,
+    c: &ContractDefinition,
+) -> Option<InvalidItem> {
+    if !is_test_contract(c) {
+        return None
+    }
+
+    let name = c.name.as_ref()?;
+    if is_valid_test_contract_name(&name.name, stem) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    let file = file.display().to_string();
+    Some(InvalidItem::new(ValidatorKind::TestContractName, file, name.name.clone(), line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, mode: Option<TestContractNames>) -> Vec<String> {
+        let config = Config { test_contract_names: mode, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // Base contracts without tests and abstract contracts are not test contracts.
+            contract CounterSetup { function setUp() public {} }
+            abstract contract CounterBase { function testAbstract() public {} }
+
+            // A single file can have multiple test contracts.
+            contract CounterTest is CounterSetup { function test_Increment() public {} }
+            contract CounterDecrement_Test { function test_Decrement() public {} }
+            contract Increment is CounterSetup { function test_Increment() public {} }
+            contract OtherTest { function test_Other() public {} }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./test/Counter.t.sol", content, None).is_empty());
+
+        let suffix = findings("./test/Counter.t.sol", content, Some(TestContractNames::Suffix));
+        assert_eq!(suffix, vec!["Invalid test contract name: Increment"]);
+
+        let file = findings("./test/Counter.t.sol", content, Some(TestContractNames::File));
+        let expected =
+            vec!["Invalid test contract name: Increment", "Invalid test contract name: OtherTest"];
+        assert_eq!(file, expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content, Some(TestContractNames::Suffix)).is_empty());
+    }
+
+    #[test]
+    fn test_is_valid_test_contract_name() {
+        assert!(is_valid_test_contract_name("CounterTest", None));
+        assert!(is_valid_test_contract_name("Counter_Test", None));
+        assert!(is_valid_test_contract_name("CounterIncrementTest", Some("Counter")));
+        assert!(!is_valid_test_contract_name("Test", None));
+        assert!(!is_valid_test_contract_name("CounterTests", None));
+        assert!(!is_valid_test_contract_name("TestCounter", None));
+        assert!(!is_valid_test_contract_name("TokenTest", Some("Counter")));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::check::{
+    symbols::SymbolIndex,
+    utils::{offset_to_line_col, FileKind, InvalidItem, Name, Parsed, ValidatorKind},
+    validators::{test_names::is_test_function, Explanation},
+};
+use solang_parser::pt::{ContractDefinition, ContractPart, ContractTy, SourceUnitPart};
+use std::{collections::HashSet, path::Path};
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.test_function_names == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::TestSubject,
+    rationale:
+        "Unit tests named after a function that does not exist are usually left over from a \
+         rename. The contract under test is found from the test contract name, e.g. `Counter` for \
+         `CounterTest`. Revert tests and tests of contracts that are not found are not checked. \
+         This is disabled by default, and enabled with `test_function_names = true`.",
+    valid: &["contract Counter { function increment() public {} }\n\
+              contract CounterTest {\n    \
+              function test_Increment_AddsOne() public {}\n\
+              }"],
+    invalid: &["contract Counter { function increment() public {} }\n\
+                contract CounterTest {\n    \
+                function test_Decrement_SubtractsOne() public {}\n\
+                }"],
+}];
+
+#[must_use]
+/// Validates that unit tests named like `test_Increment_Condition` reference a public function of
+/// the contract under test, e.g. `increment` for tests in `CounterTest`.
+///
+/// The contract under test is the longest src contract name that the test contract name starts
+/// with, after removing the `Test` suffix. Tests of contracts that are not found, and revert tests
+/// like `test_RevertIf_Condition`, are not checked.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, config, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            invalid_items.extend(validate_contract(file, content, &config.symbols, c));
+        }
+    }
+    invalid_items
+}
+
+fn validate_contract(
+    file: &Path,
+    content: &str,
+    symbols: &SymbolIndex,
+    c: &ContractDefinition,
+) -> Vec<InvalidItem> {
+    let Some(name) = &c.name else { return Vec::new() };
+    let Some(subject) = subject(&name.name, symbols) else { return Vec::new() };
+    if !matches!(c.ty, ContractTy::Contract(_)) {
+        return Vec::new()
+    }
+    let functions = functions(subject, symbols);
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for el in &c.parts {
+        let ContractPart::FunctionDefinition(f) = el else { continue };
+        let test_name = f.name();
+        let Some(segment) = tested_function(&test_name).filter(|_| is_test_function(f)) else {
+            continue
+        };
+        if functions.contains(segment) || functions.contains(lowercase_first(segment).as_str()) {
+            continue
+        }
+
+        let (line, col) = offset_to_line_col(content, f.name_loc().start());
+        let text = format!(
+            "{test_name} tests `{segment}`, which is not a public or external function of `{subject}`"
+        );
+        let file = file.display().to_string();
+        invalid_items.push(InvalidItem::new(ValidatorKind::TestSubject, file, text, line, col));
+    }
+    invalid_items
+}
+
+// Returns the name of the src contract under test, e.g. `Counter` for `CounterIncrement_Test`.
+fn subject<'a>(test_contract: &str, symbols: &'a SymbolIndex) -> Option<&'a str> {
+    let stem = test_contract.strip_suffix("Test")?;
+    let stem = stem.strip_suffix('_').unwrap_or(stem);
+    symbols
+        .contracts()
+        .filter(|(name, contract)| contract.kind == Some(FileKind::Src) && stem.starts_with(name))
+        .map(|(name, _)| name)
+        .max_by_key(|name| name.len())
+}
+
+// Returns the public functions of a contract, including the ones it inherits.
+fn functions<'a>(name: &'a str, symbols: &'a SymbolIndex) -> HashSet<&'a str> {
+    let contracts =
+        symbols.get(name).into_iter().chain(symbols.ancestors(name).into_iter().map(|(_, c)| c));
+    contracts.flat_map(|contract| contract.functions.iter().map(String::as_str)).collect()
+}
+
+// Returns the function segment of a test name, e.g. `Increment` for `testFuzz_Increment_Overflow`.
+fn tested_function(test_name: &str) -> Option<&str> {
+    let rest = test_name.strip_prefix("test")?;
+    let rest = rest.strip_prefix("Fork").unwrap_or(rest);
+    let rest = rest.strip_prefix("Fuzz").unwrap_or(rest);
+    let segment = rest.strip_prefix('_')?.split('_').next()?;
+    (!segment.is_empty() && !segment.starts_with("Revert")).then_some(segment)
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |c| c.to_ascii_lowercase().to_string() + chars.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, messages_with_config},
+        config::Config,
+    };
+    use std::sync::Arc;
+
+    fn contracts(content: &str) -> SymbolIndex {
+        let (pt, _comments) = solang_parser::parse(content, 0).expect("Parsing failed");
+        let mut symbols = SymbolIndex::default();
+        symbols.insert_source_unit(&pt, Some(FileKind::Src));
+        symbols
+    }
+
+    #[test]
+    fn test_validate() {
+        let src = r"
+            contract Ownable { function transferOwnership(address to) public {} }
+            contract Counter is Ownable {
+                uint256 public number;
+                function increment() external {}
+                function _decrement() internal {}
+            }
+        ";
+        let content = r"
+            contract CounterTest {
+                // These reference public functions of `Counter` or its bases, or public getters.
+                function test_Increment() public {}
+                function testFuzz_Increment_Overflow(uint256 x) public {}
+                function test_increment() public {}
+                function test_Number() public {}
+                function test_TransferOwnership() public {}
+
+                // Revert tests and functions that are not tests are not checked.
+                function test_RevertIf_Overflow() public {}
+                function helper_Decrement() public {}
+
+                // These do not reference a public function, and should be invalid.
+                function test_Decrement() public {}
+                function test_Reset_WhenZero() public {}
+            }
+
+            // Contracts with an unknown subject are not checked.
+            contract TokenTest { function test_Mint() public {} }
+        ";
+        let mut config = Config { test_function_names: Some(true), ..Config::default() };
+        config.symbols = Arc::new(contracts(src));
+        let findings = messages_with_config(&validate, "./test/Counter.t.sol", content, &config);
+        let expected = vec![
+            "Test of unknown function: test_Decrement tests `Decrement`, which is not a public or external function of `Counter`",
+            "Test of unknown function: test_Reset_WhenZero tests `Reset`, which is not a public or external function of `Counter`",
+        ];
+        assert_eq!(findings, expected);
+
+        // The check is disabled by default.
+        config.test_function_names = None;
+        assert!(
+            findings_with_config(&validate, "./test/Counter.t.sol", content, &config).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_subject() {
+        let contracts = contracts("contract Counter {} contract CounterV2 {} contract Token {}");
+        assert_eq!(subject("CounterTest", &contracts), Some("Counter"));
+        assert_eq!(subject("Counter_Test", &contracts), Some("Counter"));
+        assert_eq!(subject("CounterIncrement_Test", &contracts), Some("Counter"));
+        assert_eq!(subject("CounterV2Test", &contracts), Some("CounterV2"));
+        assert_eq!(subject("Counter", &contracts), None);
+        assert_eq!(subject("VaultTest", &contracts), None);
+    }
+
+    #[test]
+    fn test_tested_function() {
+        assert_eq!(tested_function("test_Increment"), Some("Increment"));
+        assert_eq!(tested_function("test_Increment_WhenZero"), Some("Increment"));
+        assert_eq!(tested_function("testFuzz_Increment"), Some("Increment"));
+        assert_eq!(tested_function("testForkFuzz_Increment"), Some("Increment"));
+        assert_eq!(tested_function("test_RevertIf_Overflow"), None);
+        assert_eq!(tested_function("testIncrement"), None);
+        assert_eq!(tested_function("test_"), None);
+    }
+}
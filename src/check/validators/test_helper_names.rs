@@ -0,0 +1,114 @@
+use crate::check::{
+    utils::{
+        offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+        VisibilitySummary,
+    },
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, FunctionDefinition, FunctionTy, SourceUnitPart};
+use std::path::Path;
+
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test) && parsed.config.test_helper_prefix == Some(true)
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::TestHelper,
+    rationale:
+        "A leading underscore tells test helpers apart from the tests that forge runs. This \
+                is separate from the check of src functions, so it is disabled by default, and \
+                enabled with `test_helper_prefix = true`.",
+    valid: &["function _deposit(uint256 amount) internal {}"],
+    invalid: &["function deposit(uint256 amount) internal {}"],
+}];
+
+#[must_use]
+/// Validates that internal and private functions in test files are prefixed with an underscore,
+/// so helpers are easy to tell apart from tests.
+///
+/// This is disabled by default, and is separate from [`super::src_names_internal`] so it can be
+/// enabled for tests alone. Internal and private functions named `test*` are reported by
+/// [`super::test_names`] instead, since they are likely tests that forge does not run.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::FunctionDefinition(f) => {
+                invalid_items.extend(validate_name(file, content, f));
+            }
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    if let ContractPart::FunctionDefinition(f) = el {
+                        invalid_items.extend(validate_name(file, content, f));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+    if f.ty != FunctionTy::Function || !f.is_internal_or_private() {
+        return None
+    }
+    let name = f.name();
+    if name.starts_with('_') || name.starts_with("test") {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    let text = format!("{name} is a test helper, so it should start with `_`");
+    Some(
+        InvalidItem::new(ValidatorKind::TestHelper, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::utils::messages_with_config, config::Config};
+
+    fn findings(file: &str, content: &str, test_helper_prefix: Option<bool>) -> Vec<String> {
+        let config = Config { test_helper_prefix, ..Config::default() };
+        messages_with_config(&validate, file, content, &config)
+    }
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract CounterTest {
+                // Prefixed helpers, public functions, and internal tests are valid here.
+                function _deposit() internal {}
+                function setUp() public {}
+                function test_Increment() public {}
+                function test_Decrement() internal {}
+
+                // These helpers are not prefixed, and should be invalid.
+                function deposit() internal {}
+                function withdraw() private {}
+            }
+        ";
+
+        // The check is disabled by default.
+        assert!(findings("./test/Counter.t.sol", content, None).is_empty());
+
+        let expected = vec![
+            "Invalid test helper name: deposit() is a test helper, so it should start with `_`",
+            "Invalid test helper name: withdraw() is a test helper, so it should start with `_`",
+        ];
+        assert_eq!(findings("./test/Counter.t.sol", content, Some(true)), expected);
+
+        // Only test files are checked.
+        assert!(findings("./src/Counter.sol", content, Some(true)).is_empty());
+    }
+}
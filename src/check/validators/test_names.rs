@@ -1,38 +1,127 @@
-use crate::check::utils::{
-    offset_to_line, FileKind, InvalidItem, IsFileKind, Name, ValidatorKind, VisibilitySummary,
+use crate::{
+    check::{
+        utils::{
+            offset_to_line_col, signature, FileKind, InvalidItem, Name, Parsed, ValidatorKind,
+            VisibilitySummary,
+        },
+        validators::Explanation,
+    },
+    config::Config,
 };
-use once_cell::sync::Lazy;
 use regex::Regex;
-use solang_parser::pt::{ContractPart, FunctionDefinition, SourceUnit, SourceUnitPart};
-use std::path::Path;
+use solang_parser::pt::{ContractPart, FunctionDefinition, SourceUnitPart};
+use std::{path::Path, sync::LazyLock};
 
-// A regex matching valid test names, see the `validate_test_names_regex` test for examples.
-static RE_VALID_TEST_NAME: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^test(Fork)?(Fuzz)?(_Revert(If|When|On))?_(\w+)*$").unwrap());
+// The default regex matching valid test names, see `VALID_NAMES` and `INVALID_NAMES` for
+// examples. This can be overridden with `test_name_pattern` in `.scopelint.toml`.
+static RE_VALID_TEST_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^test(Fork)?(Fuzz)?(_Revert(If|When|On))?_(\w+)*$").unwrap());
 
-fn is_matching_file(file: &Path) -> bool {
-    file.is_file_kind(FileKind::Test)
+fn is_matching_file(parsed: &Parsed) -> bool {
+    parsed.is_file_kind(FileKind::Test)
 }
 
+// Examples of test names that the default pattern accepts.
+const VALID_NAMES: [&str; 21] = [
+    "test_Description",
+    "test_Increment",
+    "testFuzz_Description",
+    "testFork_Description",
+    "testForkFuzz_Description",
+    "testForkFuzz_Description_MoreInfo",
+    "test_RevertIf_Condition",
+    "test_RevertIf_Paused",
+    "test_RevertWhen_Condition",
+    "test_RevertOn_Condition",
+    "test_RevertOn_Condition_MoreInfo",
+    "testFuzz_RevertIf_Condition",
+    "testFuzz_RevertWhen_Condition",
+    "testFuzz_RevertOn_Condition",
+    "testFuzz_RevertOn_Condition_MoreInfo",
+    "testForkFuzz_RevertIf_Condition",
+    "testForkFuzz_RevertWhen_Condition",
+    "testForkFuzz_RevertOn_Condition",
+    "testForkFuzz_RevertOn_Condition_MoreInfo",
+    "testForkFuzz_RevertOn_Condition_MoreInfo_Wow",
+    "testForkFuzz_RevertOn_Condition_MoreInfo_Wow_As_Many_Underscores_As_You_Want",
+];
+
+// Examples of test names that the default pattern rejects.
+const INVALID_NAMES: [&str; 12] = [
+    "test",
+    "testDescription",
+    "testDescriptionMoreInfo",
+    // The default pattern matches these, and `has_revert_condition` rejects them.
+    "test_RevertIfCondition",
+    "test_RevertWhenCondition",
+    "test_RevertOnCondition",
+    "testFuzz_RevertIfDescription",
+    "testFuzz_RevertWhenDescription",
+    "testFuzz_RevertOnDescription",
+    "testForkFuzz_RevertIfCondition",
+    "testForkFuzz_RevertWhenCondition",
+    "testForkFuzz_RevertOnCondition",
+];
+
+/// Explains the convention of each kind this validator reports, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 3] = [
+    Explanation {
+        kind: ValidatorKind::Test,
+        rationale: "Test names start with `test`, then optional `Fork` and `Fuzz` segments, an \
+                    optional `_RevertIf`, `_RevertWhen`, or `_RevertOn` segment followed by the \
+                    condition, and an underscore before the description. This keeps test output \
+                    readable, and lets `scopelint spec` build a specification from the names. \
+                    Override the convention with `test_name_pattern`.",
+        valid: &VALID_NAMES,
+        invalid: &INVALID_NAMES,
+    },
+    Explanation {
+        kind: ValidatorKind::TestFuzz,
+        rationale: "Forge fuzzes tests with parameters, and the `Fuzz` segment makes that visible \
+                    in the name and the test output.",
+        valid: &[
+            "function testFuzz_Increment(uint256 amount) public {}",
+            "function test_Increment() public {}",
+        ],
+        invalid: &[
+            "function test_Increment(uint256 amount) public {}",
+            "function testFuzz_Increment() public {}",
+        ],
+    },
+    Explanation {
+        kind: ValidatorKind::TestVisibility,
+        rationale: "Forge only runs public and external tests, so internal and private functions \
+                    named like tests silently never run.",
+        valid: &["function test_Increment() public {}"],
+        invalid: &["function test_Increment() internal {}"],
+    },
+];
+
 #[must_use]
-/// Validates that test names are in the correct format.
-pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem> {
-    if !is_matching_file(file) {
+/// Validates that test names are in the correct format, and that functions named like tests are
+/// public or external, since forge silently skips internal and private ones.
+///
+/// Tests with parameters are fuzz tests, so their names must have the `Fuzz` segment, e.g.
+/// `testFuzz_Increment`, and tests without parameters must not.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
         return Vec::new()
     }
 
+    let Parsed { file, content, pt, config, .. } = parsed;
+
     let mut invalid_items: Vec<InvalidItem> = Vec::new();
     for element in &pt.0 {
         match element {
             SourceUnitPart::FunctionDefinition(f) => {
-                if let Some(invalid_item) = validate_name(file, content, f) {
+                if let Some(invalid_item) = validate_name(file, content, config, f) {
                     invalid_items.push(invalid_item);
                 }
             }
             SourceUnitPart::ContractDefinition(c) => {
                 for el in &c.parts {
                     if let ContractPart::FunctionDefinition(f) = el {
-                        if let Some(invalid_item) = validate_name(file, content, f) {
+                        if let Some(invalid_item) = validate_name(file, content, config, f) {
                             invalid_items.push(invalid_item);
                         }
                     }
@@ -44,110 +133,178 @@ pub fn validate(file: &Path, content: &str, pt: &SourceUnit) -> Vec<InvalidItem>
     invalid_items
 }
 
-fn is_valid_test_name(name: &str) -> bool {
-    name.starts_with("test") && RE_VALID_TEST_NAME.is_match(name)
+pub(crate) fn test_name_pattern(config: &Config) -> &Regex {
+    config.test_name_pattern.as_ref().unwrap_or(&RE_VALID_TEST_NAME)
 }
 
-fn is_test_function(f: &FunctionDefinition) -> bool {
+pub(crate) fn is_valid_test_name(name: &str, config: &Config) -> bool {
+    name.starts_with("test") &&
+        test_name_pattern(config).is_match(name) &&
+        (config.test_name_pattern.is_some() || has_revert_condition(name))
+}
+
+// Returns `false` if the name has a `_RevertIf`, `_RevertWhen`, or `_RevertOn` segment that is not
+// followed by an underscore and a condition, e.g. `test_RevertIf_` or `test_RevertIfCondition`.
+// The default pattern cannot require this, since the `regex` crate has no look-ahead, and custom
+// patterns are not checked. Descriptions such as `test_RevertOnlyOwner` are not revert segments.
+fn has_revert_condition(name: &str) -> bool {
+    let mut segments = name.split('_').skip(1);
+    let Some(segment) = segments.next() else { return true };
+    match ["RevertIf", "RevertWhen", "RevertOn"].iter().find_map(|r| segment.strip_prefix(r)) {
+        Some("") => segments.next().is_some_and(|condition| !condition.is_empty()),
+        Some(rest) => !rest.starts_with(char::is_uppercase),
+        None => true,
+    }
+}
+
+// Returns `true` if the test name has the `Fuzz` segment, e.g. `testFuzz_` or `testForkFuzz_`.
+fn is_fuzz_test_name(name: &str) -> bool {
+    name.strip_prefix("test")
+        .map(|rest| rest.strip_prefix("Fork").unwrap_or(rest))
+        .is_some_and(|rest| rest.starts_with("Fuzz"))
+}
+
+pub(crate) fn is_test_function(f: &FunctionDefinition) -> bool {
     f.is_public_or_external() && f.name().starts_with("test")
 }
 
-fn validate_name(file: &Path, content: &str, f: &FunctionDefinition) -> Option<InvalidItem> {
+fn validate_name(
+    file: &Path,
+    content: &str,
+    config: &Config,
+    f: &FunctionDefinition,
+) -> Option<InvalidItem> {
     let name = f.name();
-    if is_test_function(f) && !is_valid_test_name(&name) {
-        Some(InvalidItem::new(
-            ValidatorKind::Test,
-            file.display().to_string(),
-            name,
-            offset_to_line(content, f.loc.start()),
-        ))
+    let (kind, text) = if f.is_internal_or_private() && name.starts_with("test") {
+        (ValidatorKind::TestVisibility, name)
+    } else if !is_test_function(f) {
+        return None
+    } else if !is_valid_test_name(&name, config) {
+        (ValidatorKind::Test, name)
+    } else if !f.params.is_empty() && !is_fuzz_test_name(&name) {
+        let text =
+            format!("{name} has parameters, so it is a fuzz test and must be named `testFuzz_*`");
+        (ValidatorKind::TestFuzz, text)
+    } else if f.params.is_empty() && is_fuzz_test_name(&name) {
+        let text = format!(
+            "{name} has no parameters, so it is not a fuzz test and must not be named `testFuzz_*`"
+        );
+        (ValidatorKind::TestFuzz, text)
     } else {
-        None
-    }
+        return None
+    };
+
+    let (line, col) = offset_to_line_col(content, f.name_loc().start());
+    Some(
+        InvalidItem::new(kind, file.display().to_string(), text, line, col)
+            .with_signature(signature(content, f)),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::check::utils::ExpectedFindings;
+    use crate::check::utils::{descriptions_with_config, findings_with_config, ExpectedFindings};
 
     #[test]
     fn test_validate() {
-        let content = r#"
+        let content = r"
             contract MyContract {
                 // Good test names.
                 function test_Description() public {}
                 function test_Increment() public {}
-                function testFuzz_Description() external {}
+                function testFuzz_Description(uint256 x) external {}
                 function testFork_Description() external {}
+                function testForkFuzz_Description(uint256 x) external {}
 
                 // Bad test names.
                 function test() public {}
                 function testDescription() public {}
                 function testDescriptionMoreInfo() external {}
 
-                // Things that are not tests and should be ignored.
+                // Functions named like tests that are never run by forge.
                 function test() internal {}
                 function testDescription() internal {}
-                function testDescriptionMoreInfo() private {}
+                function test_Description() private {}
 
+                // Things that are not tests and should be ignored.
                 function _test() public {}
                 function _testDescription() public {}
                 function _testDescriptionMoreInfo() public {}
             }
-        "#;
+        ";
 
-        let expected_findings = ExpectedFindings { test: 3, ..ExpectedFindings::default() };
+        let expected_findings = ExpectedFindings { test: 6, ..ExpectedFindings::default() };
         expected_findings.assert_eq(content, &validate);
     }
 
     #[test]
-    fn test_is_valid_test_name() {
-        let allowed_names = vec![
-            "test_Description",
-            "test_Increment",
-            "testFuzz_Description",
-            "testFork_Description",
-            "testForkFuzz_Description",
-            "testForkFuzz_Description_MoreInfo",
-            "test_RevertIf_Condition",
-            "test_RevertWhen_Condition",
-            "test_RevertOn_Condition",
-            "test_RevertOn_Condition_MoreInfo",
-            "testFuzz_RevertIf_Condition",
-            "testFuzz_RevertWhen_Condition",
-            "testFuzz_RevertOn_Condition",
-            "testFuzz_RevertOn_Condition_MoreInfo",
-            "testForkFuzz_RevertIf_Condition",
-            "testForkFuzz_RevertWhen_Condition",
-            "testForkFuzz_RevertOn_Condition",
-            "testForkFuzz_RevertOn_Condition_MoreInfo",
-            "testForkFuzz_RevertOn_Condition_MoreInfo_Wow",
-            "testForkFuzz_RevertOn_Condition_MoreInfo_Wow_As_Many_Underscores_As_You_Want",
+    fn test_validate_fuzz() {
+        let content = "contract C {\n  function test_Foo(uint256 x) public {}\n  function testFuzz_Foo() public {}\n}";
+        let config = Config::default();
+        let findings =
+            descriptions_with_config(&validate, "./test/MyContract.t.sol", content, &config);
+        let expected = vec![
+            "Invalid fuzz test name in ./test/MyContract.t.sol:2:12: test_Foo(uint256) has parameters, so it is a fuzz test and must be named `testFuzz_*`",
+            "Invalid fuzz test name in ./test/MyContract.t.sol:3:12: testFuzz_Foo() has no parameters, so it is not a fuzz test and must not be named `testFuzz_*`",
         ];
+        assert_eq!(findings, expected);
+    }
+
+    #[test]
+    fn test_is_fuzz_test_name() {
+        assert!(is_fuzz_test_name("testFuzz_Increment"));
+        assert!(is_fuzz_test_name("testForkFuzz_Increment"));
+        assert!(is_fuzz_test_name("testFuzz_RevertIf_Overflow"));
+        assert!(!is_fuzz_test_name("test_Increment"));
+        assert!(!is_fuzz_test_name("testFork_Increment"));
+        assert!(!is_fuzz_test_name("test_Fuzz"));
+    }
+
+    #[test]
+    fn test_validate_visibility() {
+        let content =
+            "contract C {\n  function test_A() internal {}\n  function testB() private {}\n}";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./test/MyContract.t.sol", content, &config);
 
-        let disallowed_names = [
-            "test",
-            "testDescription",
-            "testDescriptionMoreInfo",
-            // TODO The below are tough to prevent without regex look-ahead support.
-            // "test_RevertIfCondition",
-            // "test_RevertWhenCondition",
-            // "test_RevertOnCondition",
-            // "testFuzz_RevertIfDescription",
-            // "testFuzz_RevertWhenDescription",
-            // "testFuzz_RevertOnDescription",
-            // "testForkFuzz_RevertIfCondition",
-            // "testForkFuzz_RevertWhenCondition",
-            // "testForkFuzz_RevertOnCondition",
+        // Non-public tests are reported as such, regardless of whether the name is valid.
+        let findings: Vec<String> = invalid_items.iter().map(InvalidItem::description).collect();
+        let expected = vec![
+            "Non-public test function in ./test/MyContract.t.sol:2:12: test_A()",
+            "Non-public test function in ./test/MyContract.t.sol:3:12: testB()",
         ];
+        assert_eq!(findings, expected);
+    }
 
-        for name in allowed_names {
-            assert_eq!(is_valid_test_name(name), true, "{name}");
+    #[test]
+    fn test_is_valid_test_name() {
+        let config = Config::default();
+        for name in VALID_NAMES {
+            assert!(is_valid_test_name(name, &config), "{name}");
+        }
+
+        for name in INVALID_NAMES {
+            assert!(!is_valid_test_name(name, &config), "{name}");
         }
 
-        for name in disallowed_names {
-            assert_eq!(is_valid_test_name(name), false, "{name}");
+        // Revert segments need a condition, which the default pattern alone does not require.
+        for name in ["test_RevertIf", "test_RevertIf_", "testFuzz_RevertWhen_", "test_RevertOn__X"]
+        {
+            assert!(test_name_pattern(&config).is_match(name), "{name}");
+            assert!(!is_valid_test_name(name, &config), "{name}");
         }
+        assert!(is_valid_test_name("test_RevertOnlyOwner", &config));
+    }
+
+    #[test]
+    fn test_is_valid_test_name_with_config() {
+        let config = Config {
+            test_name_pattern: Some(Regex::new(r"^test_[a-z_]+$").unwrap()),
+            ..Config::default()
+        };
+        assert!(is_valid_test_name("test_increments_number", &config));
+        assert!(!is_valid_test_name("test_Increment", &config));
     }
 }
@@ -0,0 +1,132 @@
+use crate::check::{
+    utils::{is_pascal_case, offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{ContractPart, Identifier, SourceUnitPart};
+use std::path::Path;
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention of each kind this validator reports, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 2] = [
+    Explanation {
+        kind: ValidatorKind::Struct,
+        rationale: "Structs are types, so they are named like contracts. Only letters and digits \
+                    are allowed.",
+        valid: &["struct UserInfo { uint256 balance; }"],
+        invalid: &["struct userInfo { uint256 balance; }", "struct User_Info { uint256 balance; }"],
+    },
+    Explanation {
+        kind: ValidatorKind::Enum,
+        rationale:
+            "Enums are types, so they are named like contracts. Only letters and digits are \
+             allowed.",
+        valid: &["enum Status { Active, Paused }"],
+        invalid: &["enum status { Active, Paused }", "enum Token_Status { Active, Paused }"],
+    },
+];
+
+#[must_use]
+/// Validates that struct and enum names are in `PascalCase`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    let mut validate_name = |kind: ValidatorKind, name: &Option<Identifier>| {
+        if let Some(invalid_item) = validate_name(file, content, kind, name.as_ref()) {
+            invalid_items.push(invalid_item);
+        }
+    };
+
+    for element in &pt.0 {
+        match element {
+            SourceUnitPart::StructDefinition(s) => validate_name(ValidatorKind::Struct, &s.name),
+            SourceUnitPart::EnumDefinition(e) => validate_name(ValidatorKind::Enum, &e.name),
+            SourceUnitPart::ContractDefinition(c) => {
+                for el in &c.parts {
+                    match el {
+                        ContractPart::StructDefinition(s) => {
+                            validate_name(ValidatorKind::Struct, &s.name);
+                        }
+                        ContractPart::EnumDefinition(e) => {
+                            validate_name(ValidatorKind::Enum, &e.name);
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    invalid_items
+}
+
+fn validate_name(
+    file: &Path,
+    content: &str,
+    kind: ValidatorKind,
+    name: Option<&Identifier>,
+) -> Option<InvalidItem> {
+    let name = name?;
+    if is_pascal_case(&name.name) {
+        return None
+    }
+
+    let (line, col) = offset_to_line_col(content, name.loc.start());
+    Some(InvalidItem::new(kind, file.display().to_string(), name.name.clone(), line, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::utils::{findings_with_config, ExpectedFindings},
+        config::Config,
+    };
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            // File level structs and enums are checked too.
+            struct file_level_struct { uint256 a; }
+            enum FileLevelEnum { A, B }
+
+            contract MyContract {
+                // These are PascalCase and should be valid.
+                struct Position { uint256 x; uint256 y; }
+                enum Status { Active, Paused }
+
+                // These are not PascalCase and should be invalid.
+                struct position { uint256 x; }
+                struct Token_Info { address token; }
+                enum status { Active }
+                enum STATUS_CODE { Ok }
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(5);
+        expected_findings.assert_eq(content, &validate);
+    }
+
+    #[test]
+    fn test_validate_kinds_and_lines() {
+        let content = "struct bad { uint256 a; }\ncontract C {\n  struct Good { uint256 a; }\n  enum bad_enum { A }\n}";
+        let config = Config::default();
+        let invalid_items =
+            findings_with_config(&validate, "./src/MyContract.sol", content, &config);
+
+        let findings: Vec<(ValidatorKind, String)> =
+            invalid_items.iter().map(|item| (item.kind(), item.location())).collect();
+        let expected = vec![
+            (ValidatorKind::Struct, "./src/MyContract.sol:1:8".to_string()),
+            (ValidatorKind::Enum, "./src/MyContract.sol:4:8".to_string()),
+        ];
+        assert_eq!(findings, expected);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::check::{
+    utils::{for_each_statement, offset_to_line_col, InvalidItem, Parsed, ValidatorKind},
+    validators::Explanation,
+};
+use solang_parser::pt::{
+    ContractDefinition, ContractPart, Expression, FunctionTy, Identifier, SourceUnitPart,
+    Statement, VariableAttribute,
+};
+use std::{collections::HashSet, path::Path};
+
+const fn is_matching_file(_parsed: &Parsed) -> bool {
+    true
+}
+
+/// Explains the convention this validator enforces, for `scopelint explain`.
+pub const EXPLANATIONS: [Explanation; 1] = [Explanation {
+    kind: ValidatorKind::UnassignedImmutable,
+    rationale: "An immutable that is never assigned is always zero, which is almost always a bug. \
+                Immutables can only be assigned where they are declared or in the constructor of \
+                their contract, so both are checked.",
+    valid: &["address immutable owner;\nconstructor(address owner_) {\n    owner = owner_;\n}"],
+    invalid: &["address immutable owner;\nconstructor(address owner_) {}"],
+}];
+
+#[must_use]
+/// Validates that each immutable state variable is assigned, either where it is declared or in
+/// the constructor of its contract.
+///
+/// Assignments anywhere in the constructor body count, including in branches and loops, and
+/// assignments to several immutables at once, e.g. `(a, b) = f()`.
+pub fn validate(parsed: &Parsed) -> Vec<InvalidItem> {
+    if !is_matching_file(parsed) {
+        return Vec::new()
+    }
+
+    let Parsed { file, content, pt, .. } = parsed;
+
+    let mut invalid_items: Vec<InvalidItem> = Vec::new();
+    for element in &pt.0 {
+        if let SourceUnitPart::ContractDefinition(c) = element {
+            invalid_items.extend(validate_contract(file, content, c));
+        }
+    }
+    invalid_items
+}
+
+fn validate_contract(file: &Path, content: &str, c: &ContractDefinition) -> Vec<InvalidItem> {
+    let mut immutables: Vec<&Identifier> = Vec::new();
+    let mut assigned: HashSet<&str> = HashSet::new();
+    for el in &c.parts {
+        match el {
+            ContractPart::VariableDefinition(v)
+                if v.initializer.is_none() &&
+                    v.attrs.iter().any(|a| matches!(a, VariableAttribute::Immutable(_))) =>
+            {
+                immutables.extend(&v.name);
+            }
+            ContractPart::FunctionDefinition(f) if f.ty == FunctionTy::Constructor => {
+                let Some(body) = &f.body else { continue };
+                for_each_statement(body, &mut |statement| {
+                    if let Statement::Expression(_, expr) = statement {
+                        assigned_names(expr, &mut assigned);
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    immutables
+        .into_iter()
+        .filter(|name| !assigned.contains(name.name.as_str()))
+        .map(|name| {
+            let (line, col) = offset_to_line_col(content, name.loc.start());
+            let text = format!("{} is never assigned in the constructor", name.name);
+            let file = file.display().to_string();
+            InvalidItem::new(ValidatorKind::UnassignedImmutable, file, text, line, col)
+        })
+        .collect()
+}
+
+// Adds the names of the variables that `expr` assigns to `assigned`, including each variable of a
+// tuple assignment and of chained assignments such as `a = b = 1`.
+fn assigned_names<'a>(expr: &'a Expression, assigned: &mut HashSet<&'a str>) {
+    let Expression::Assign(_, left, right) = expr else { return };
+    match left.as_ref() {
+        Expression::Variable(name) => {
+            assigned.insert(&name.name);
+        }
+        Expression::List(_, params) => {
+            for (_, param) in params {
+                if let Some(Expression::Variable(name)) = param.as_ref().map(|param| &param.ty) {
+                    assigned.insert(&name.name);
+                }
+            }
+        }
+        _ => {}
+    }
+    assigned_names(right, assigned);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::utils::ExpectedFindings;
+
+    #[test]
+    fn test_validate() {
+        let content = r"
+            contract Counter {
+                // Immutables assigned where they are declared, or anywhere in the constructor, are
+                // valid.
+                uint256 immutable LIMIT = 10;
+                address immutable OWNER;
+                uint256 immutable START;
+                uint256 immutable END;
+                uint256 immutable STEP;
+                uint256 immutable MAX;
+                uint256 immutable MIN;
+                uint256 constant NOT_IMMUTABLE;
+
+                // This is never assigned, and should be invalid.
+                uint256 immutable UNASSIGNED;
+
+                constructor(address owner, bool flag) {
+                    OWNER = owner;
+                    (START, END) = (1, 2);
+                    if (flag) { STEP = 1; } else { STEP = 2; }
+                    MAX = MIN = 3;
+                }
+
+                // Assignments outside the constructor do not count.
+                function set() public { UNASSIGNED = 1; }
+            }
+
+            contract Token {
+                // Contracts without a constructor do not assign their immutables.
+                uint256 immutable SUPPLY;
+            }
+        ";
+
+        let expected_findings = ExpectedFindings::new(2);
+        expected_findings.assert_eq(content, &validate);
+    }
+}
@@ -1,4 +1,22 @@
-use clap::{Parser, Subcommand};
+use crate::check::{
+    symbols::SymbolIndex,
+    utils::{render_template, ValidatorKind, TEMPLATE_PLACEHOLDERS},
+    validators::constant_names::ConstantNameRule,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    ffi::OsStr,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use walkdir::WalkDir;
 
 #[derive(Debug, Parser)]
 #[clap(version, about, after_help = "Learn more: https://github.com/ScopeLift/scopelint")]
@@ -7,6 +25,18 @@ pub struct Opts {
     #[clap(subcommand)]
     /// The mode to run scopelint in.
     pub subcommand: Subcommands,
+    #[clap(long, global = true, help = "Disable colors in the output, like setting `NO_COLOR`.")]
+    /// Disables colors in the output.
+    pub no_color: bool,
+}
+
+impl Opts {
+    #[must_use]
+    /// Returns `true` if the output is colored, which `--no-color` and a non-empty `NO_COLOR`
+    /// environment variable disable, see <https://no-color.org>.
+    pub fn use_color(&self) -> bool {
+        !self.no_color && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -14,11 +44,1255 @@ pub struct Opts {
 pub enum Subcommands {
     #[clap(about = "Checks code to verify all conventions are being followed.")]
     /// Checks code to verify all conventions are being followed.
-    Check,
+    Check(CheckOpts),
     #[clap(about = "Formats Solidity and TOML files in the codebase.")]
     /// Formats Solidity and TOML files in the codebase.
-    Fmt,
+    Fmt(FmtOpts),
     #[clap(about = "Generates a specification for the current project from test names.")]
     /// Generates a specification for the current project from test names.
     Spec,
+    #[clap(about = "Lists the rules `check` enforces, and how to configure them.")]
+    /// Lists the rules `check` enforces, and how to configure them.
+    Rules(RulesOpts),
+    #[clap(about = "Explains a rule `check` enforces, with examples.")]
+    /// Explains a rule `check` enforces, with examples of valid and invalid code.
+    Explain(ExplainOpts),
+}
+
+#[derive(Debug, Default, Args)]
+/// Options for the `fmt` subcommand.
+pub struct FmtOpts {
+    #[clap(long, help = "Check the formatting without changing any files.")]
+    /// Check that the files are formatted without changing them, and fail with exit code `2` if
+    /// they are not, like the formatting check of `scopelint check`.
+    pub check: bool,
+}
+
+#[derive(Debug, Default, Args)]
+/// Options for the `rules` subcommand.
+pub struct RulesOpts {
+    #[clap(long, help = "Print the rules as JSON, e.g. for editor integrations.")]
+    /// Print the rules to stdout as a JSON array, with the kind, label, description, default
+    /// severity, and configuration of each.
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+/// Options for the `explain` subcommand.
+pub struct ExplainOpts {
+    #[clap(value_name = "KIND", help = "The kind of finding to explain, e.g. `constant`.")]
+    /// The kind of finding to explain, named as in the JSON output, ignoring case, dashes, and
+    /// underscores.
+    pub kind: ValidatorKind,
+}
+
+#[derive(Debug, Default, Args)]
+#[allow(clippy::struct_excessive_bools)] // Each bool is an independent CLI flag.
+/// Options for the `check` subcommand.
+pub struct CheckOpts {
+    #[clap(
+        value_name = "PATH",
+        conflicts_with = "stdin",
+        help = "Files or directories to check, instead of the project directories."
+    )]
+    /// Files or directories to check the conventions of, instead of the `src`, `script`, and
+    /// `test` directories. The kind of each file is inferred from its path.
+    pub paths: Vec<PathBuf>,
+    #[clap(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["paths", "stdin"],
+        help = "Check the Solidity files listed in FILE, one per line, or `-` for stdin."
+    )]
+    /// Check the conventions of the files listed in this file, one path per line, instead of the
+    /// project directories. A path of `-` reads the list from stdin. Paths that do not exist or
+    /// are not Solidity files are skipped with a warning.
+    pub files_from: Option<PathBuf>,
+    #[clap(
+        long,
+        value_name = "REF",
+        conflicts_with_all = ["paths", "files_from", "stdin"],
+        help = "Only check the Solidity files changed since the git REF, e.g. `origin/main`."
+    )]
+    /// Check the conventions of the Solidity files in the project directories that changed since
+    /// this git ref, including uncommitted and untracked files, instead of all of them. Deleted
+    /// files are skipped, and renamed files are checked under their new name. Outside of a git
+    /// repository, all files are checked.
+    pub since: Option<String>,
+    #[clap(long, value_enum, default_value_t, help = "The format to print the report in.")]
+    /// The format to print the report in.
+    pub format: OutputFormat,
+    #[clap(long, conflicts_with = "format", help = "Shorthand for `--format json`.")]
+    /// Shorthand for `--format json`.
+    pub json: bool,
+    #[clap(long, help = "Rename invalid constant and immutable names where possible.")]
+    /// Rename invalid constant and immutable names to `SCREAMING_SNAKE_CASE` before validating,
+    /// when the conversion is unambiguous.
+    pub fix: bool,
+    #[clap(
+        long,
+        requires = "fix",
+        help = "Print the renames `--fix` would make, without making them."
+    )]
+    /// Print the renames `--fix` would make instead of writing them, and fail if there are any.
+    pub dry_run: bool,
+    #[clap(
+        long,
+        conflicts_with_all = ["fix", "diff"],
+        help = "Check the Solidity source read from stdin, instead of the project files."
+    )]
+    /// Check the conventions of the Solidity source read from stdin, instead of the project files.
+    /// The formatting check is skipped.
+    pub stdin: bool,
+    #[clap(long, requires = "stdin", help = "The path of the file read from stdin.")]
+    /// The path of the file read from stdin, which determines the checks that apply to it, e.g.
+    /// `src/Counter.sol`. Findings are labeled with this path.
+    pub stdin_filename: Option<PathBuf>,
+    #[clap(
+        long,
+        value_name = "KIND",
+        value_delimiter = ',',
+        help = "Kinds of findings to not report, e.g. `constant,script`."
+    )]
+    /// Kinds of findings to not report, in addition to the ones disabled in `.scopelint.toml`.
+    /// Kinds are named as in the JSON output, ignoring case, dashes, and underscores.
+    pub disable: Vec<ValidatorKind>,
+    #[clap(
+        long,
+        help = "Validate all files, instead of skipping unchanged files that were clean."
+    )]
+    /// Validate all files, instead of skipping files that passed all convention checks in a
+    /// previous run and have not changed since. The cache is not read or written.
+    pub no_cache: bool,
+    #[clap(long, help = "Pass the convention checks when no Solidity files are found.")]
+    /// Pass the convention checks when no Solidity files are found to check, instead of failing.
+    /// Useful with `--files-from` when the list may not contain any Solidity files.
+    pub allow_empty: bool,
+    #[clap(long, help = "Follow symlinks to directories and files when finding files to check.")]
+    /// Follow symlinks when walking directories to find files to check, e.g. to check contract
+    /// directories that a monorepo shares through symlinks. Symlinks to an ancestor directory are
+    /// skipped with a warning, and files reached through several paths are only checked once.
+    pub follow_symlinks: bool,
+    #[clap(long, help = "Check files that `.gitignore` excludes, and walk `.git` directories.")]
+    /// Check the files that `.gitignore` files, `.git/info/exclude`, and the global git excludes
+    /// file exclude, which are skipped by default along with `.git` directories. Files excluded
+    /// by `.scopelintignore` are still skipped.
+    pub no_ignore: bool,
+    #[clap(long, help = "Stop at the first file with a finding, skipping the remaining checks.")]
+    /// Stop validating at the first file with any finding, and skip the formatting check when the
+    /// convention checks fail. The report only contains the findings of that file.
+    pub fail_fast: bool,
+    #[clap(long, value_name = "N", help = "Fail the check if there are more than N warnings.")]
+    /// Fail the convention checks if more than this many findings have the warning severity.
+    /// Findings with the error severity always fail the check, and warnings never do by default.
+    pub max_warnings: Option<usize>,
+    #[clap(
+        long,
+        value_name = "FILE",
+        conflicts_with = "fail_fast",
+        help = "Do not report the findings recorded in FILE, e.g. `.scopelint-baseline.json`."
+    )]
+    /// Do not report the findings recorded in this baseline file, so only new findings fail the
+    /// check. Findings are matched by kind, file, and name, so moved code stays matched.
+    pub baseline: Option<PathBuf>,
+    #[clap(
+        long,
+        requires = "baseline",
+        conflicts_with = "stdin",
+        help = "Record the current findings in the `--baseline` file."
+    )]
+    /// Record the current findings in the `--baseline` file, replacing its contents. The recorded
+    /// findings are not reported.
+    pub write_baseline: bool,
+    #[clap(long, help = "Print a diff of the changes needed to fix the formatting.")]
+    /// Print a diff of the changes `scopelint fmt` would make to each file that is not formatted.
+    pub diff: bool,
+    #[clap(
+        long,
+        short,
+        conflicts_with = "verbose",
+        help = "Only print the summary of the checks."
+    )]
+    /// Only print the summary of the checks, without the individual findings.
+    pub quiet: bool,
+    #[clap(
+        long,
+        conflicts_with_all = ["format", "json", "quiet", "verbose", "diff", "dry_run"],
+        help = "Only print the number of findings, skipping the formatting check."
+    )]
+    /// Only print the number of convention findings to stdout, and fail if it is not zero. The
+    /// formatting check is skipped, so the exit code only depends on the count.
+    pub count: bool,
+    #[clap(long, help = "Print how long each phase of the check took, slowest first.")]
+    /// Print a table of the time spent walking directories, parsing, in each validator, and in the
+    /// formatting check, slowest first. The time of each validator is summed across files, so it
+    /// can exceed the total run time when files are checked in parallel.
+    pub timings: bool,
+    #[clap(long, short, help = "Print each file as it is checked, with timings.")]
+    /// Print each file as it is checked, along with how long it took to parse and validate.
+    pub verbose: bool,
+}
+
+impl CheckOpts {
+    #[must_use]
+    /// Returns the format to print the report in, accounting for the `--json` shorthand.
+    pub const fn output_format(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else {
+            self.format
+        }
+    }
+
+    #[must_use]
+    /// Returns the verbosity of the text output, based on the `--quiet` and `--verbose` flags.
+    /// `--count` implies `--quiet`.
+    pub const fn verbosity(&self) -> Verbosity {
+        if self.quiet || self.count {
+            Verbosity::Quiet
+        } else if self.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// The format to print the `check` report in.
+pub enum OutputFormat {
+    #[default]
+    /// Human readable findings and summary, printed to stderr.
+    Text,
+    /// A JSON report printed to stdout, with all other output suppressed.
+    Json,
+    /// GitHub Actions workflow commands printed to stdout, which annotate the findings in PRs.
+    Github,
+    /// A SARIF log printed to stdout, for code scanning tools and IDEs.
+    Sarif,
+    /// A Checkstyle XML report printed to stdout, for CI systems such as Jenkins and GitLab.
+    Checkstyle,
+    /// A JUnit XML report printed to stdout, for CI systems that aggregate test results.
+    Junit,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// How much detail to print in the text output of `check`. The JSON and GitHub formats always
+/// print every finding.
+pub enum Verbosity {
+    /// Only the summary of each check.
+    Quiet,
+    #[default]
+    /// The summary and the individual findings.
+    Normal,
+    /// Everything from `Normal`, plus each file that was checked and how long it took.
+    Verbose,
+}
+
+// ================================
+// ======== Foundry config ========
+// ================================
+
+/// The source, test, and script directories of a foundry project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundryPaths {
+    /// Directory containing the core contracts, `./src` by default.
+    pub src: String,
+    /// Directory containing the test contracts, `./test` by default.
+    pub test: String,
+    /// Directory containing the scripts, `./script` by default.
+    pub script: String,
+}
+
+impl Default for FoundryPaths {
+    fn default() -> Self {
+        Self {
+            src: "./src".to_string(),
+            test: "./test".to_string(),
+            script: "./script".to_string(),
+        }
+    }
+}
+
+impl FoundryPaths {
+    /// Reads the paths from the `[profile.default]` section of `./foundry.toml`, falling back to
+    /// the defaults if the file is missing.
+    /// # Errors
+    /// Errors if `foundry.toml` exists but cannot be read or is not valid TOML.
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let config_path = Path::new("./foundry.toml");
+        if !config_path.exists() {
+            return Ok(Self::default())
+        }
+        Self::from_toml(&fs::read_to_string(config_path)?)
+    }
+
+    /// Parses the paths from the contents of a `foundry.toml` file. Any of the `src`, `test`, or
+    /// `script` keys that are not present keep their default value.
+    /// # Errors
+    /// Errors if the content is not valid TOML.
+    pub fn from_toml(content: &str) -> Result<Self, Box<dyn Error>> {
+        let config: toml::Table = content.parse()?;
+        let profile = config
+            .get("profile")
+            .and_then(|profile| profile.get("default"))
+            .and_then(toml::Value::as_table);
+
+        let mut paths = Self::default();
+        if let Some(profile) = profile {
+            let get = |key: &str| profile.get(key).and_then(toml::Value::as_str).map(normalize);
+            paths.src = get("src").unwrap_or(paths.src);
+            paths.test = get("test").unwrap_or(paths.test);
+            paths.script = get("script").unwrap_or(paths.script);
+        }
+        Ok(paths)
+    }
+}
+
+// ==================================
+// ======== Scopelint config ========
+// ==================================
+
+/// The configuration used to check a project, combining the project paths from `foundry.toml`,
+/// the settings from `.scopelint.toml`, and the excluded paths from `.scopelintignore`.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The directories of the project.
+    pub paths: FoundryPaths,
+    /// Overrides the regex that constant names must match, and immutable names too unless
+    /// `immutable_name_pattern` is set.
+    pub constant_name_pattern: Option<Regex>,
+    /// Overrides the regex that immutable names must match.
+    pub immutable_name_pattern: Option<Regex>,
+    /// Replaces both constant and immutable patterns with custom validation logic. This can only
+    /// be set through the library API, and `--fix` does not rename names checked by a rule.
+    pub constant_name_rule: Option<Box<dyn ConstantNameRule>>,
+    /// Overrides the regex that test names must match.
+    pub test_name_pattern: Option<Regex>,
+    /// Enables the abstract contract name check, requiring names to match this regex.
+    pub abstract_contract_pattern: Option<Regex>,
+    /// Overrides the regex that mutable state variable names must match.
+    pub state_variable_pattern: Option<Regex>,
+    /// Enables the indexed event parameter name check, requiring names to match this regex.
+    pub indexed_event_parameter_pattern: Option<Regex>,
+    /// Enables the constructor parameter name check, requiring names to match this regex.
+    pub constructor_parameter_pattern: Option<Regex>,
+    /// Enables the enum member name check, requiring names to match this regex.
+    pub enum_member_pattern: Option<Regex>,
+    /// Overrides the names of the public methods allowed in scripts, which is just `run` by
+    /// default.
+    pub script_public_methods: Option<Vec<String>>,
+    /// Overrides which functions count as the public methods of scripts, which are both public and
+    /// external functions by default.
+    pub script_entrypoint_visibility: Option<ScriptEntrypointVisibility>,
+    /// Enables the test contract name check, which is disabled by default.
+    pub test_contract_names: Option<TestContractNames>,
+    /// Enables the mock contract name check, which is disabled by default.
+    pub mock_contract_names: Option<MockContractNames>,
+    /// Enables checking that unit test names reference a function of the contract under test,
+    /// which is disabled by default.
+    pub test_function_names: Option<bool>,
+    /// Enables checking that test contracts inherit from this contract, e.g. `Test` or `BaseTest`.
+    pub test_base_contract: Option<String>,
+    /// Enables checking that state variables do not shadow inherited ones, which is disabled by
+    /// default.
+    pub shadowing: Option<bool>,
+    /// Enables checking that contracts inheriting from any of these contracts, e.g.
+    /// `Initializable`, declare a `__gap` array.
+    pub upgradeable_bases: Option<Vec<String>>,
+    /// Enables checking that test functions are not empty, which is disabled by default.
+    pub empty_tests: Option<bool>,
+    /// Enables checking that test functions are `external` rather than `public`, which is disabled
+    /// by default.
+    pub external_tests: Option<bool>,
+    /// Enables checking that internal and private functions in test files start with `_`, which is
+    /// disabled by default.
+    pub test_helper_prefix: Option<bool>,
+    /// Enables checking that src files declare at most one contract, which is disabled by
+    /// default.
+    pub contracts_per_file: Option<bool>,
+    /// Enables checking that src files are named after one of their contracts, which is disabled
+    /// by default.
+    pub contract_file_names: Option<bool>,
+    /// Enables checking that inline assembly blocks have a comment directly above them, which is
+    /// disabled by default.
+    pub assembly_comments: Option<bool>,
+    /// Enables checking that names are not longer than the limit for their kind, which is disabled
+    /// by default.
+    pub max_identifier_length: Option<MaxIdentifierLength>,
+    /// Enables checking that lines are not longer than this many characters, which is disabled by
+    /// default.
+    pub max_line_length: Option<usize>,
+    /// Enables checking that public and external src functions have a NatSpec comment, which is
+    /// disabled by default.
+    pub missing_docs: Option<bool>,
+    /// Enables checking that public and external src functions that are not `view` or `pure` emit
+    /// an event, which is disabled by default.
+    pub missing_events: Option<bool>,
+    /// Enables checking that NatSpec tags match the function signature, which is disabled by
+    /// default.
+    pub natspec_tags: Option<bool>,
+    /// The symbols of each contract in the project, when a check that resolves names across files
+    /// is enabled. It is shared with the configs of subdirectories.
+    pub symbols: Arc<SymbolIndex>,
+    /// Disables the interface name prefix check when `Some(false)`, which is enabled by default.
+    pub interface_prefix: Option<bool>,
+    /// Overrides the severity of findings of each kind, which is `Severity::Error` by default.
+    pub severity: HashMap<ValidatorKind, Severity>,
+    /// Overrides the details shown for findings of each kind with a template, see
+    /// [`InvalidItem::with_template`](crate::check::utils::InvalidItem::with_template).
+    pub messages: HashMap<ValidatorKind, String>,
+    /// The only kinds of findings that are reported, or `None` to report all kinds.
+    pub enabled: Option<Vec<ValidatorKind>>,
+    /// Kinds of findings that are not reported, even if they are in `enabled`.
+    pub disabled: Vec<ValidatorKind>,
+    /// Enables checking the local directories from `remappings.txt`, which is disabled by default.
+    pub include_remappings: Option<bool>,
+    /// Local directories from `remappings.txt` that are checked in addition to the project
+    /// directories, when `include_remappings` is enabled.
+    pub remapped_dirs: Vec<String>,
+    /// Overrides the names of directories that are never walked, see [`DEFAULT_SKIP_DIRS`].
+    pub skip_dirs: Option<Vec<String>>,
+    /// Files and directories that are not checked, or `None` to check all files.
+    pub ignore: Option<Gitignore>,
+    /// The configs of subdirectories with their own `.scopelint.toml`, deepest first, which apply
+    /// to the files under them instead of this one. See [`Config::for_file`].
+    pub dir_configs: Vec<(PathBuf, Self)>,
+}
+
+/// The name of the scopelint config file, in the project root or any subdirectory.
+pub const CONFIG_FILE: &str = ".scopelint.toml";
+
+// Keys that apply to the whole project, so they can only be set in the root `.scopelint.toml`.
+const PROJECT_KEYS: [&str; 4] =
+    ["include_remappings", "skip_dirs", "test_function_names", "shadowing"];
+
+/// Names of directories that contain dependencies or build artifacts, which are skipped wherever
+/// they are found unless `skip_dirs` is configured.
+pub const DEFAULT_SKIP_DIRS: [&str; 3] = ["lib", "node_modules", "out"];
+
+/// How test contracts must be named, see `test_contract_names` in `.scopelint.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestContractNames {
+    /// Test contracts end with `Test`, e.g. `CounterTest` or `Counter_Test`.
+    Suffix,
+    /// Test contracts end with `Test` and start with the file name, e.g. `CounterIncrementTest` in
+    /// `Counter.t.sol`.
+    File,
+}
+
+/// Which functions are the entrypoints of scripts, see `script_entrypoint_visibility` in
+/// `.scopelint.toml`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptEntrypointVisibility {
+    #[default]
+    /// Public and external functions are entrypoints.
+    Public,
+    /// Only external functions are entrypoints, so public helpers are allowed.
+    External,
+}
+
+/// Where mock contracts have the `Mock` affix, see `mock_contract_names` in `.scopelint.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MockContractNames {
+    /// Mock contracts start with `Mock`, e.g. `MockERC20`.
+    Prefix,
+    /// Mock contracts end with `Mock`, e.g. `ERC20Mock`.
+    Suffix,
+}
+
+/// The maximum length of names of each kind, see `[max_identifier_length]` in `.scopelint.toml`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaxIdentifierLength {
+    /// The limit for the kinds that do not set their own, if any.
+    pub default: Option<usize>,
+    /// The limit for constant and immutable names.
+    pub constant: Option<usize>,
+    /// The limit for function and modifier names.
+    pub function: Option<usize>,
+    /// The limit for mutable state variable names.
+    pub variable: Option<usize>,
+}
+
+/// How a finding affects the result of `check`, see `[severity]` in `.scopelint.toml`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The finding is reported, but does not fail the check.
+    Warning,
+    #[default]
+    /// The finding is reported and fails the check.
+    Error,
+}
+
+// The raw contents of a `.scopelint.toml` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScopelintToml {
+    constant_name_pattern: Option<String>,
+    immutable_name_pattern: Option<String>,
+    test_name_pattern: Option<String>,
+    abstract_contract_pattern: Option<String>,
+    state_variable_pattern: Option<String>,
+    indexed_event_parameter_pattern: Option<String>,
+    constructor_parameter_pattern: Option<String>,
+    enum_member_pattern: Option<String>,
+    script_public_methods: Option<Vec<String>>,
+    script_entrypoint_visibility: Option<ScriptEntrypointVisibility>,
+    test_contract_names: Option<TestContractNames>,
+    mock_contract_names: Option<MockContractNames>,
+    test_function_names: Option<bool>,
+    test_base_contract: Option<String>,
+    shadowing: Option<bool>,
+    upgradeable_bases: Option<Vec<String>>,
+    empty_tests: Option<bool>,
+    external_tests: Option<bool>,
+    test_helper_prefix: Option<bool>,
+    contracts_per_file: Option<bool>,
+    contract_file_names: Option<bool>,
+    assembly_comments: Option<bool>,
+    max_identifier_length: Option<MaxIdentifierLength>,
+    max_line_length: Option<usize>,
+    missing_docs: Option<bool>,
+    missing_events: Option<bool>,
+    natspec_tags: Option<bool>,
+    interface_prefix: Option<bool>,
+    severity: Option<HashMap<ValidatorKind, Severity>>,
+    messages: Option<HashMap<ValidatorKind, String>>,
+    enabled: Option<Vec<ValidatorKind>>,
+    disabled: Option<Vec<ValidatorKind>>,
+    include_remappings: Option<bool>,
+    skip_dirs: Option<Vec<String>>,
+}
+
+impl Config {
+    /// Loads the configuration from `./foundry.toml`, `./.scopelint.toml`, and
+    /// `./.scopelintignore`, along with `./remappings.txt` when `include_remappings` is enabled.
+    /// All files are optional, and defaults are used for anything that is not configured.
+    ///
+    /// The config can also be set in a `[scopelint]` or `[profile.default.scopelint]` table of
+    /// `foundry.toml`, see [`foundry_config`]. Keys set in `.scopelint.toml` take precedence.
+    ///
+    /// A `.scopelint.toml` in a subdirectory overrides the config of its parent directories for
+    /// the files under it, see [`Config::dir_configs`].
+    /// # Errors
+    /// Errors if any file cannot be read or parsed, or if a configured regex or glob is invalid.
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let config_path = Path::new(".").join(CONFIG_FILE);
+        let mut content =
+            if config_path.exists() { fs::read_to_string(&config_path)? } else { String::new() };
+        let foundry_path = Path::new("./foundry.toml");
+        if foundry_path.exists() {
+            let mut table = foundry_config(&fs::read_to_string(foundry_path)?)?;
+            if !table.is_empty() {
+                let overrides: toml::Table =
+                    content.parse().map_err(|err| format!("Invalid {CONFIG_FILE}: {err}"))?;
+                merge(&mut table, overrides);
+                content = toml::to_string(&table)?;
+            }
+        }
+        let mut config = Self::from_toml(FoundryPaths::load()?, &content)?;
+        config.dir_configs = config.load_dir_configs(&content)?;
+
+        let ignore_path = Path::new("./.scopelintignore");
+        if ignore_path.exists() {
+            config.ignore = Some(parse_ignore(&fs::read_to_string(ignore_path)?)?);
+        }
+
+        let remappings_path = Path::new("./remappings.txt");
+        if config.include_remappings == Some(true) && remappings_path.exists() {
+            let content = fs::read_to_string(remappings_path)?;
+            let mut dirs = parse_remappings(&content, &config.paths);
+            // Remappings can point to directories that are generated later, e.g. by a build step.
+            dirs.retain(|dir| Path::new(dir).is_dir());
+            config.remapped_dirs = dirs;
+        }
+        Ok(config)
+    }
+
+    // Loads the `.scopelint.toml` files in subdirectories, skipping the directories that are never
+    // walked. Each one is merged over the `root` config contents and those of its parent
+    // directories, so keys it does not set are inherited.
+    fn load_dir_configs(&self, root: &str) -> Result<Vec<(PathBuf, Self)>, Box<dyn Error>> {
+        let mut tables: Vec<(PathBuf, toml::Table)> = Vec::new();
+        let walker = WalkDir::new(".").sort_by_file_name().into_iter().filter_entry(|dent| {
+            let is_dir = dent.file_type().is_dir();
+            let is_hidden = dent.file_name().to_str().is_some_and(|name| name.starts_with('.'));
+            let is_skipped = is_dir && (is_hidden || self.is_skipped_dir(dent.file_name()));
+            dent.depth() == 0 || (!is_skipped && !self.is_ignored(dent.path(), is_dir))
+        });
+        for dent in walker.filter_map(Result::ok) {
+            let path = dent.path().join(CONFIG_FILE);
+            if dent.depth() == 0 || !dent.file_type().is_dir() || !path.is_file() {
+                continue
+            }
+            let invalid = |err: &dyn fmt::Display| format!("Invalid {}: {err}", path.display());
+            let table: toml::Table =
+                fs::read_to_string(&path)?.parse().map_err(|err| invalid(&err))?;
+            if let Some(key) = PROJECT_KEYS.iter().find(|key| table.contains_key(**key)) {
+                let err = format!("`{key}` can only be set in the root {CONFIG_FILE}");
+                return Err(invalid(&err).into())
+            }
+            tables.push((dent.into_path(), table));
+        }
+
+        let root: toml::Table = root.parse()?;
+        let mut dir_configs = Vec::new();
+        for (dir, _) in &tables {
+            // Directories are sorted, so parents come before their subdirectories.
+            let mut merged = root.clone();
+            for (_, table) in tables.iter().filter(|(parent, _)| dir.starts_with(parent)) {
+                merge(&mut merged, table.clone());
+            }
+            let path = dir.join(CONFIG_FILE);
+            let config: ScopelintToml = toml::Value::Table(merged)
+                .try_into()
+                .map_err(|err| format!("Invalid {}: {err}", path.display()))?;
+            // The root config and those of parent directories are already valid, so any error is in
+            // this file.
+            let source = path.display().to_string();
+            dir_configs.push((dir.clone(), Self::from_raw(self.paths.clone(), config, &source)?));
+        }
+        dir_configs.reverse();
+        Ok(dir_configs)
+    }
+
+    #[must_use]
+    /// Returns the config that applies to `file`, which is the config of the deepest directory
+    /// containing it that has its own `.scopelint.toml`, or this config if there is none.
+    ///
+    /// Settings that can only be set through the library API, such as
+    /// [`Config::constant_name_rule`], are not inherited by the configs of subdirectories.
+    pub fn for_file(&self, file: &Path) -> &Self {
+        self.dir_configs.iter().find(|(dir, _)| file.starts_with(dir)).map_or(self, |(_, c)| c)
+    }
+
+    #[must_use]
+    /// Returns `true` if findings of the given kind are reported.
+    pub fn is_enabled(&self, kind: ValidatorKind) -> bool {
+        let is_enabled = self.enabled.as_ref().is_none_or(|enabled| enabled.contains(&kind));
+        is_enabled && !self.disabled.contains(&kind)
+    }
+
+    #[must_use]
+    /// Returns the severity of findings of the given kind.
+    pub fn severity(&self, kind: ValidatorKind) -> Severity {
+        self.severity.get(&kind).copied().unwrap_or_else(|| kind.default_severity())
+    }
+
+    #[must_use]
+    /// Returns the template configured for the details of findings of the given kind, if any.
+    pub fn message_template(&self, kind: ValidatorKind) -> Option<&str> {
+        self.messages.get(&kind).map(String::as_str)
+    }
+
+    #[must_use]
+    /// Returns `true` if directories with the given `name` are skipped, such as `lib`.
+    pub fn is_skipped_dir(&self, name: &OsStr) -> bool {
+        let default_skip_dirs = || DEFAULT_SKIP_DIRS.iter().any(|dir| name == *dir);
+        self.skip_dirs
+            .as_ref()
+            .map_or_else(default_skip_dirs, |dirs| dirs.iter().any(|dir| name == dir.as_str()))
+    }
+
+    #[must_use]
+    /// Returns `true` if the file or directory at `path` is excluded by `.scopelintignore`.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.ignore.as_ref().is_some_and(|ignore| ignore.matched(path, is_dir).is_ignore())
+    }
+
+    /// Builds the configuration from the project `paths` and the contents of a `.scopelint.toml`
+    /// file.
+    /// # Errors
+    /// Errors if the content is not a valid config, or if a configured regex is invalid.
+    pub fn from_toml(paths: FoundryPaths, content: &str) -> Result<Self, Box<dyn Error>> {
+        let config: ScopelintToml =
+            toml::from_str(content).map_err(|err| format!("Invalid {CONFIG_FILE}: {err}"))?;
+        Self::from_raw(paths, config, CONFIG_FILE)
+    }
+
+    // Builds the configuration from a parsed config, where errors name the `source` file it was
+    // read from.
+    fn from_raw(
+        paths: FoundryPaths,
+        config: ScopelintToml,
+        source: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let invalid = |err: &str| -> Box<dyn Error> { format!("Invalid {source}: {err}").into() };
+        if config.script_public_methods.as_ref().is_some_and(Vec::is_empty) {
+            return Err(invalid("`script_public_methods` must not be empty"))
+        }
+        if config.upgradeable_bases.as_ref().is_some_and(Vec::is_empty) {
+            return Err(invalid("`upgradeable_bases` must not be empty"))
+        }
+        if config.max_line_length == Some(0) {
+            return Err(invalid("`max_line_length` must be positive"))
+        }
+        if let Some(max) = &config.max_identifier_length {
+            let limits = [max.default, max.constant, max.function, max.variable];
+            if limits.iter().all(Option::is_none) {
+                return Err(invalid("`max_identifier_length` must set a limit"))
+            }
+            if limits.contains(&Some(0)) {
+                return Err(invalid("`max_identifier_length` limits must be positive"))
+            }
+        }
+        let messages = config.messages.unwrap_or_default();
+        for (kind, template) in &messages {
+            let value =
+                |placeholder: &str| TEMPLATE_PLACEHOLDERS.contains(&placeholder).then(String::new);
+            render_template(template, value)
+                .map_err(|err| invalid(&format!("{err} in the message template of `{kind:?}`")))?;
+        }
+        Ok(Self {
+            paths,
+            constant_name_pattern: compile(
+                source,
+                "constant_name_pattern",
+                config.constant_name_pattern,
+            )?,
+            immutable_name_pattern: compile(
+                source,
+                "immutable_name_pattern",
+                config.immutable_name_pattern,
+            )?,
+            constant_name_rule: None,
+            test_name_pattern: compile(source, "test_name_pattern", config.test_name_pattern)?,
+            abstract_contract_pattern: compile(
+                source,
+                "abstract_contract_pattern",
+                config.abstract_contract_pattern,
+            )?,
+            state_variable_pattern: compile(
+                source,
+                "state_variable_pattern",
+                config.state_variable_pattern,
+            )?,
+            indexed_event_parameter_pattern: compile(
+                source,
+                "indexed_event_parameter_pattern",
+                config.indexed_event_parameter_pattern,
+            )?,
+            constructor_parameter_pattern: compile(
+                source,
+                "constructor_parameter_pattern",
+                config.constructor_parameter_pattern,
+            )?,
+            enum_member_pattern: compile(
+                source,
+                "enum_member_pattern",
+                config.enum_member_pattern,
+            )?,
+            script_public_methods: config.script_public_methods,
+            script_entrypoint_visibility: config.script_entrypoint_visibility,
+            test_contract_names: config.test_contract_names,
+            mock_contract_names: config.mock_contract_names,
+            test_function_names: config.test_function_names,
+            test_base_contract: config.test_base_contract,
+            shadowing: config.shadowing,
+            upgradeable_bases: config.upgradeable_bases,
+            empty_tests: config.empty_tests,
+            external_tests: config.external_tests,
+            test_helper_prefix: config.test_helper_prefix,
+            contracts_per_file: config.contracts_per_file,
+            contract_file_names: config.contract_file_names,
+            assembly_comments: config.assembly_comments,
+            max_identifier_length: config.max_identifier_length,
+            max_line_length: config.max_line_length,
+            missing_docs: config.missing_docs,
+            missing_events: config.missing_events,
+            natspec_tags: config.natspec_tags,
+            symbols: Arc::default(),
+            interface_prefix: config.interface_prefix,
+            severity: config.severity.unwrap_or_default(),
+            messages,
+            enabled: config.enabled,
+            disabled: config.disabled.unwrap_or_default(),
+            include_remappings: config.include_remappings,
+            remapped_dirs: Vec::new(),
+            skip_dirs: config.skip_dirs,
+            ignore: None,
+            dir_configs: Vec::new(),
+        })
+    }
+}
+
+/// Returns the scopelint config set in the contents of a `foundry.toml` file.
+///
+/// The `[scopelint]` table is merged with the `[profile.default.scopelint]` table, which takes
+/// precedence. The config is empty if neither is set.
+/// # Errors
+/// Errors if the content is not valid TOML, or if the tables are not a valid config.
+pub fn foundry_config(content: &str) -> Result<toml::Table, Box<dyn Error>> {
+    let foundry: toml::Table = content.parse()?;
+    let profile = foundry.get("profile").and_then(|profile| profile.get("default"));
+    let mut config = toml::Table::new();
+    for table in [foundry.get("scopelint"), profile.and_then(|profile| profile.get("scopelint"))]
+        .into_iter()
+        .flatten()
+    {
+        let toml::Value::Table(table) = table else {
+            return Err("Invalid foundry.toml: `scopelint` must be a table".into())
+        };
+        merge(&mut config, table.clone());
+    }
+    // Checks the config here, so errors point to `foundry.toml` rather than `.scopelint.toml`.
+    let raw: ScopelintToml = toml::Value::Table(config.clone())
+        .try_into()
+        .map_err(|err| format!("Invalid scopelint config in foundry.toml: {err}"))?;
+    Config::from_raw(FoundryPaths::default(), raw, "foundry.toml")?;
+    Ok(config)
+}
+
+// Merges the keys of `overrides` over the inherited ones, e.g. for a subdirectory's
+// `.scopelint.toml`. Keys replace the inherited value, except for the `[severity]` and `[messages]`
+// tables, which are merged by kind.
+fn merge(config: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (config.get_mut(&key), value) {
+            (Some(toml::Value::Table(inherited)), toml::Value::Table(table))
+                if key == "severity" || key == "messages" =>
+            {
+                inherited.extend(table);
+            }
+            (_, value) => {
+                config.insert(key, value);
+            }
+        }
+    }
+}
+
+// Compiles the regex configured for `key` in the `source` file, if any.
+fn compile(
+    source: &str,
+    key: &str,
+    pattern: Option<String>,
+) -> Result<Option<Regex>, Box<dyn Error>> {
+    pattern
+        .map(|pattern| {
+            Regex::new(&pattern)
+                .map_err(|err| format!("Invalid regex for `{key}` in {source}: {err}").into())
+        })
+        .transpose()
+}
+
+// Parses the contents of a `.scopelintignore` file, which uses the same syntax as `.gitignore`
+// with paths relative to the project root.
+fn parse_ignore(content: &str) -> Result<Gitignore, Box<dyn Error>> {
+    let mut builder = GitignoreBuilder::new(".");
+    for line in content.lines() {
+        builder
+            .add_line(None, line)
+            .map_err(|err| format!("Invalid pattern in .scopelintignore: {err}"))?;
+    }
+    Ok(builder.build()?)
+}
+
+// Returns the local directories that the remappings in a `remappings.txt` file point to, such as
+// `./contracts` for `@project/=contracts/`. Dependencies in `lib/` and `node_modules/`, paths
+// outside the project, and the project directories themselves are skipped.
+fn parse_remappings(content: &str, paths: &FoundryPaths) -> Vec<String> {
+    let mut dirs: Vec<String> = Vec::new();
+    for line in content.lines().map(str::trim) {
+        // Remappings are formatted as `[context:]prefix=target`.
+        let Some((_, target)) = line.split_once('=') else { continue };
+        let dir = normalize(target.trim());
+        let path = Path::new(&dir);
+        let is_dependency = ["./lib", "./node_modules"].iter().any(|dep| path.starts_with(dep));
+        let is_outside = path.is_absolute() || path.components().any(|c| c.as_os_str() == "..");
+        let is_project_dir =
+            [&paths.src, &paths.test, &paths.script].iter().any(|dir| path.starts_with(dir));
+        if !is_dependency && !is_outside && !is_project_dir && !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    dirs
+}
+
+// Formats a configured directory the same way as the defaults, e.g. `contracts/` becomes
+// `./contracts`, so reported file paths are consistent regardless of how they were configured.
+fn normalize(dir: &str) -> String {
+    let dir = dir.trim_end_matches('/');
+    if dir.starts_with("./") || dir.starts_with('/') {
+        dir.to_string()
+    } else {
+        format!("./{dir}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        // No profile section uses the defaults.
+        let content = r"
+            [fmt]
+            line_length = 100
+        ";
+        assert_eq!(FoundryPaths::from_toml(content).unwrap(), FoundryPaths::default());
+
+        // Overriding all paths.
+        let content = r#"
+            [profile.default]
+            src = "contracts"
+            test = "./tests/"
+            script = "scripts"
+        "#;
+        let expected = FoundryPaths {
+            src: "./contracts".to_string(),
+            test: "./tests".to_string(),
+            script: "./scripts".to_string(),
+        };
+        assert_eq!(FoundryPaths::from_toml(content).unwrap(), expected);
+
+        // Overriding a single path keeps the defaults for the others.
+        let content = r#"
+            [profile.default]
+            src = "contracts"
+            optimizer = true
+        "#;
+        let expected = FoundryPaths { src: "./contracts".to_string(), ..FoundryPaths::default() };
+        assert_eq!(FoundryPaths::from_toml(content).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_foundry_config() {
+        assert!(foundry_config("[profile.default]\nsrc = \"src\"").unwrap().is_empty());
+
+        // The profile table takes precedence over the top-level one, and tables are merged by kind.
+        let content = r#"
+            [scopelint]
+            shadowing = true
+            missing_docs = true
+            severity = { Src = "warning", Test = "warning" }
+            [profile.default.scopelint]
+            missing_docs = false
+            severity = { Test = "error" }
+        "#;
+        let table = foundry_config(content).unwrap();
+        let config: ScopelintToml = toml::Value::Table(table).try_into().unwrap();
+        let config = Config::from_raw(FoundryPaths::default(), config, "foundry.toml").unwrap();
+        assert_eq!(config.shadowing, Some(true));
+        assert_eq!(config.missing_docs, Some(false));
+        assert_eq!(config.severity(ValidatorKind::Src), Severity::Warning);
+        assert_eq!(config.severity(ValidatorKind::Test), Severity::Error);
+
+        // Invalid values are reported in `foundry.toml`, where they are set.
+        let err = foundry_config("[scopelint]\ntest_name_pattern = \"(\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid regex for `test_name_pattern` in foundry.toml"));
+        let err = foundry_config("[scopelint]\nmax_line_length = 0").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid foundry.toml: `max_line_length` must be positive");
+        assert!(foundry_config("scopelint = true").is_err());
+        assert!(foundry_config("[scopelint]\nshadow = true").is_err());
+    }
+
+    #[test]
+    fn test_config_from_toml() {
+        // An empty config uses the defaults.
+        let config = Config::from_toml(FoundryPaths::default(), "").unwrap();
+        assert!(config.constant_name_pattern.is_none());
+        assert!(config.test_name_pattern.is_none());
+
+        // Overriding a single pattern.
+        let content = r#"constant_name_pattern = "^[A-Z_]+$""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.constant_name_pattern.unwrap().as_str(), "^[A-Z_]+$");
+        assert!(config.immutable_name_pattern.is_none());
+        assert!(config.test_name_pattern.is_none());
+
+        let content = r#"immutable_name_pattern = "^i[A-Z]\\w*$""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert!(config.constant_name_pattern.is_none());
+        assert_eq!(config.immutable_name_pattern.unwrap().as_str(), r"^i[A-Z]\w*$");
+
+        // Invalid regexes and unknown keys are errors.
+        let content = r#"test_name_pattern = "^test(_""#;
+        let err = Config::from_toml(FoundryPaths::default(), content).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid regex for `test_name_pattern`"));
+        let content = r#"constant_pattern = "^[A-Z_]+$""#;
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        // Allowed script methods must be a non-empty list.
+        let content = r#"script_public_methods = ["deploy", "upgrade"]"#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.script_public_methods.unwrap(), vec!["deploy", "upgrade"]);
+        let content = "script_public_methods = []";
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        // The test contract name check is enabled with one of the naming styles.
+        let content = r#"test_contract_names = "file""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.test_contract_names, Some(TestContractNames::File));
+        let content = r#"test_contract_names = "prefix""#;
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        let content = r#"script_entrypoint_visibility = "external""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.script_entrypoint_visibility, Some(ScriptEntrypointVisibility::External));
+        let content = r#"script_entrypoint_visibility = "internal""#;
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        // The mock contract name check is enabled with the position of `Mock`.
+        let content = r#"mock_contract_names = "suffix""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.mock_contract_names, Some(MockContractNames::Suffix));
+        let content = r#"mock_contract_names = "file""#;
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        let content = r#"state_variable_pattern = "^s_[a-z]\\w*$""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.state_variable_pattern.unwrap().as_str(), r"^s_[a-z]\w*$");
+
+        // The indexed event parameter name check is enabled with a pattern.
+        let content = r#"indexed_event_parameter_pattern = "^_""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.indexed_event_parameter_pattern.unwrap().as_str(), "^_");
+
+        // The constructor parameter name check is enabled with a pattern.
+        let content = r#"constructor_parameter_pattern = "_$""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.constructor_parameter_pattern.unwrap().as_str(), "_$");
+
+        // The enum member name check is enabled with a pattern.
+        let content = r#"enum_member_pattern = "^[A-Z_]+$""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.enum_member_pattern.unwrap().as_str(), "^[A-Z_]+$");
+
+        // The abstract contract name check is enabled with a pattern.
+        let content = r#"abstract_contract_pattern = "^Base""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.abstract_contract_pattern.unwrap().as_str(), "^Base");
+
+        // Severities are configured per kind, and default to errors, except for a few kinds
+        // that are warnings.
+        let content = "[severity]\nConstant = \"warning\"\nTest = \"error\"";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.severity(ValidatorKind::Constant), Severity::Warning);
+        assert_eq!(config.severity(ValidatorKind::Test), Severity::Error);
+        assert_eq!(config.severity(ValidatorKind::Script), Severity::Error);
+        assert_eq!(config.severity(ValidatorKind::MutableConstant), Severity::Warning);
+        let content = "[severity]\nMutableConstant = \"error\"";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.severity(ValidatorKind::MutableConstant), Severity::Error);
+        let content = "[severity]\nConstants = \"warning\"";
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+        let content = "[severity]\nConstant = \"info\"";
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+
+        // Remapped directories are only checked when enabled.
+        let config = Config::from_toml(FoundryPaths::default(), "").unwrap();
+        assert_eq!(config.include_remappings, None);
+        let config =
+            Config::from_toml(FoundryPaths::default(), "include_remappings = true").unwrap();
+        assert_eq!(config.include_remappings, Some(true));
+
+        // Kinds are enabled and disabled by name.
+        let content = "enabled = [\"Constant\", \"Test\"]\ndisabled = [\"Test\"]";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert!(config.is_enabled(ValidatorKind::Constant));
+        assert!(!config.is_enabled(ValidatorKind::Test));
+        assert!(!config.is_enabled(ValidatorKind::Script));
+        assert!(Config::default().is_enabled(ValidatorKind::Script));
+
+        // The interface prefix check can be disabled.
+        let config =
+            Config::from_toml(FoundryPaths::default(), "interface_prefix = false").unwrap();
+        assert_eq!(config.interface_prefix, Some(false));
+    }
+
+    #[test]
+    fn test_config_opt_in_checks() {
+        // Checks that are disabled by default are enabled with a boolean.
+        let content = "shadowing = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.shadowing, Some(true));
+
+        let content = "empty_tests = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.empty_tests, Some(true));
+        let content = "external_tests = true\ntest_helper_prefix = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.external_tests, Some(true));
+        assert_eq!(config.test_helper_prefix, Some(true));
+        let content = "contracts_per_file = true\ncontract_file_names = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.contracts_per_file, Some(true));
+        assert_eq!(config.contract_file_names, Some(true));
+        let config =
+            Config::from_toml(FoundryPaths::default(), "assembly_comments = true").unwrap();
+        assert_eq!(config.assembly_comments, Some(true));
+
+        let content = "missing_docs = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.missing_docs, Some(true));
+        let content = "missing_events = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.missing_events, Some(true));
+
+        let content = "natspec_tags = true";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.natspec_tags, Some(true));
+
+        let content = r#"test_base_contract = "BaseTest""#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.test_base_contract.as_deref(), Some("BaseTest"));
+
+        let content = r#"upgradeable_bases = ["Initializable"]"#;
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(config.upgradeable_bases, Some(vec!["Initializable".to_string()]));
+        assert!(Config::from_toml(FoundryPaths::default(), "upgradeable_bases = []").is_err());
+    }
+
+    #[test]
+    fn test_max_identifier_length() {
+        // Limits are configured per kind, and findings are warnings by default.
+        let content = "[max_identifier_length]\ndefault = 32\nconstant = 48";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        let max =
+            MaxIdentifierLength { default: Some(32), constant: Some(48), ..Default::default() };
+        assert_eq!(config.max_identifier_length, Some(max));
+        assert_eq!(config.severity(ValidatorKind::LongName), Severity::Warning);
+        let content = "[max_identifier_length]\nfunction = 0";
+        assert!(Config::from_toml(FoundryPaths::default(), content).is_err());
+        assert!(Config::from_toml(FoundryPaths::default(), "[max_identifier_length]").is_err());
+
+        let config = Config::from_toml(FoundryPaths::default(), "max_line_length = 120").unwrap();
+        assert_eq!(config.max_line_length, Some(120));
+        assert!(Config::from_toml(FoundryPaths::default(), "max_line_length = 0").is_err());
+    }
+
+    #[test]
+    fn test_message_templates() {
+        // Message templates are configured per kind, and only use known placeholders.
+        let content = "[messages]\nConstant = \"{name} breaks rule C-1\"";
+        let config = Config::from_toml(FoundryPaths::default(), content).unwrap();
+        assert_eq!(
+            config.message_template(ValidatorKind::Constant),
+            Some("{name} breaks rule C-1")
+        );
+        assert_eq!(config.message_template(ValidatorKind::Test), None);
+        let content = "[messages]\nConstant = \"{identifier} breaks rule C-1\"";
+        let err = Config::from_toml(FoundryPaths::default(), content).unwrap_err();
+        let expected = "Invalid .scopelint.toml: unknown placeholder `{identifier}` in the \
+                        message template of `Constant`";
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut config: toml::Table = r#"
+            disabled = ["Script"]
+            test_name_pattern = "^test_"
+            [severity]
+            Constant = "warning"
+            Script = "warning"
+            [messages]
+            Constant = "{name} is not a constant name"
+        "#
+        .parse()
+        .unwrap();
+        let overrides: toml::Table = r#"
+            disabled = ["Src"]
+            [severity]
+            Script = "error"
+            [messages]
+            Script = "{name} is not a script entrypoint"
+        "#
+        .parse()
+        .unwrap();
+        merge(&mut config, overrides);
+
+        // Keys replace the inherited value, but severities and messages are merged by kind.
+        let expected: toml::Table = r#"
+            disabled = ["Src"]
+            test_name_pattern = "^test_"
+            [severity]
+            Constant = "warning"
+            Script = "error"
+            [messages]
+            Constant = "{name} is not a constant name"
+            Script = "{name} is not a script entrypoint"
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    fn test_parse_remappings() {
+        let content = r"
+            @project/=contracts/
+            @shared/=./shared
+            src/:@interfaces/=interfaces/
+            forge-std/=lib/forge-std/src/
+            @openzeppelin/=node_modules/@openzeppelin/
+            @outside/=../other/contracts/
+            @src/=src/
+            @duplicate/=contracts/
+            not a remapping
+        ";
+        let dirs = parse_remappings(content, &FoundryPaths::default());
+        assert_eq!(dirs, ["./contracts", "./shared", "./interfaces"]);
+    }
+
+    #[test]
+    fn test_is_skipped_dir() {
+        let config = Config::default();
+        assert!(config.is_skipped_dir(OsStr::new("lib")));
+        assert!(config.is_skipped_dir(OsStr::new("node_modules")));
+        assert!(config.is_skipped_dir(OsStr::new("out")));
+        assert!(!config.is_skipped_dir(OsStr::new("src")));
+
+        // Configured directories replace the defaults.
+        let config =
+            Config::from_toml(FoundryPaths::default(), r#"skip_dirs = ["vendor"]"#).unwrap();
+        assert!(config.is_skipped_dir(OsStr::new("vendor")));
+        assert!(!config.is_skipped_dir(OsStr::new("lib")));
+        let config = Config::from_toml(FoundryPaths::default(), "skip_dirs = []").unwrap();
+        assert!(!config.is_skipped_dir(OsStr::new("lib")));
+    }
+
+    #[test]
+    fn test_is_ignored() {
+        let content = [
+            "# Directories match at any depth unless anchored.",
+            "generated/",
+            "/src/vendor/",
+            "# Negations re-include files excluded by an earlier pattern.",
+            "*.g.sol",
+            "!Keep.g.sol",
+        ]
+        .join("\n");
+        let config = Config { ignore: Some(parse_ignore(&content).unwrap()), ..Config::default() };
+
+        let ignored = [
+            ("./src/generated", true),
+            ("./test/helpers/generated", true),
+            ("./src/vendor", true),
+            ("./src/Token.g.sol", false),
+            ("./test/mocks/Mock.g.sol", false),
+        ];
+        let not_ignored = [
+            ("./src/Token.sol", false),
+            ("./src/generated.sol", false),
+            ("./test/vendor", true),
+            ("./src/Keep.g.sol", false),
+        ];
+
+        for (path, is_dir) in ignored {
+            assert!(config.is_ignored(Path::new(path), is_dir), "{path}");
+        }
+        for (path, is_dir) in not_ignored {
+            assert!(!config.is_ignored(Path::new(path), is_dir), "{path}");
+        }
+        assert!(!Config::default().is_ignored(Path::new("./src/Token.sol"), false));
+    }
 }
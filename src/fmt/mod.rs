@@ -1,9 +1,21 @@
+use crate::{
+    check::validators::formatting,
+    config::{CheckOpts, FmtOpts},
+};
 use std::{error::Error, fs, process};
 
-/// Format the code.
+/// Format the code, or only check that it is formatted with `--check`.
 /// # Errors
-/// Errors if `forge fmt` fails, or if `taplo` fails to format `foundry.toml`.
-pub fn run(taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>> {
+/// Errors if `forge fmt` cannot be run or fails, or if `taplo` fails to format `foundry.toml`.
+/// With `--check`, errors with
+/// [`CheckError::FormattingFailed`](crate::check::error::CheckError::FormattingFailed) if any file
+/// is not formatted.
+pub fn run(opts: &FmtOpts, taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>> {
+    // The check is the same one `scopelint check` runs, with its default output.
+    if opts.check {
+        return Ok(formatting::validate(&CheckOpts::default(), taplo_opts)?)
+    }
+
     // Format Solidity with forge
     let forge_status = process::Command::new("forge").arg("fmt").output()?;
 
@@ -11,10 +23,15 @@ pub fn run(taplo_opts: taplo::formatter::Options) -> Result<(), Box<dyn Error>>
     if !forge_status.stderr.is_empty() {
         print!("{}", String::from_utf8(forge_status.stderr)?);
     }
+    if !forge_status.status.success() {
+        return Err("`forge fmt` failed, see details above".into())
+    }
 
     // Format `foundry.toml` with taplo.
     let config_orig = fs::read_to_string("./foundry.toml")?;
     let config_fmt = taplo::formatter::format(&config_orig, taplo_opts);
-    fs::write("./foundry.toml", config_fmt)?;
+    if config_fmt != config_orig {
+        fs::write("./foundry.toml", config_fmt)?;
+    }
     Ok(())
 }
@@ -1,7 +1,7 @@
 #![doc = include_str!("../README.md")]
 #![warn(unreachable_pub, unused, rust_2021_compatibility)]
 #![warn(clippy::all, clippy::pedantic, clippy::cargo, clippy::nursery)]
-#![allow(clippy::multiple_crate_versions)]
+#![allow(clippy::doc_markdown, clippy::multiple_crate_versions)]
 use std::error::Error;
 
 /// Runs validators on Solidity files.
@@ -13,6 +13,9 @@ pub mod config;
 /// Formats Solidity and TOML files.
 pub mod fmt;
 
+/// Lists and explains the rules that `check` enforces.
+pub mod rules;
+
 /// Generates a specification for the current project from test names.
 pub mod spec;
 
@@ -24,6 +27,10 @@ pub mod spec;
 /// # Errors
 /// Errors if the provided mode fails to run.
 pub fn run(opts: &config::Opts) -> Result<(), Box<dyn Error>> {
+    if !opts.use_color() {
+        colored::control::set_override(false);
+    }
+
     // Configure formatting options, https://taplo.tamasfe.dev/.
     let taplo_opts = taplo::formatter::Options {
         allowed_blank_lines: 1,
@@ -34,8 +41,10 @@ pub fn run(opts: &config::Opts) -> Result<(), Box<dyn Error>> {
 
     // Execute commands.
     match opts.subcommand {
-        config::Subcommands::Check => check::run(taplo_opts),
-        config::Subcommands::Fmt => fmt::run(taplo_opts),
+        config::Subcommands::Check(ref check_opts) => Ok(check::run(check_opts, taplo_opts)?),
+        config::Subcommands::Fmt(ref fmt_opts) => fmt::run(fmt_opts, taplo_opts),
         config::Subcommands::Spec => spec::run(),
+        config::Subcommands::Rules(ref rules_opts) => rules::run(rules_opts),
+        config::Subcommands::Explain(ref explain_opts) => rules::explain(explain_opts),
     }
 }
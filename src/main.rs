@@ -1,16 +1,17 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs, unreachable_pub, unused, rust_2021_compatibility)]
 #![warn(clippy::all, clippy::pedantic, clippy::cargo, clippy::nursery)]
-#![allow(clippy::multiple_crate_versions)]
+#![allow(clippy::doc_markdown, clippy::multiple_crate_versions)]
 use clap::Parser;
-use scopelint::config::Opts;
+use scopelint::{check::error::CheckError, config::Opts};
 use std::process;
 
 fn main() {
     let opts = Opts::parse();
 
-    if let Err(_err) = scopelint::run(&opts) {
-        // All warnings/errors have already been logged.
-        process::exit(1);
+    if let Err(err) = scopelint::run(&opts) {
+        // All warnings/errors have already been logged. Failed checks use the exit code to indicate
+        // which checks failed.
+        process::exit(err.downcast_ref::<CheckError>().map_or(1, CheckError::exit_code));
     }
 }
@@ -0,0 +1,313 @@
+use crate::{
+    check::{
+        utils::ValidatorKind,
+        validators::{constant_names, state_variable_names, test_names, Explanation, REGISTRY},
+    },
+    config::{Config, ExplainOpts, RulesOpts, Severity},
+};
+use serde::Serialize;
+use std::{error::Error, fmt::Write};
+
+/// A kind of finding that `scopelint check` reports, along with how it is configured.
+#[derive(Debug, Serialize)]
+pub struct Rule {
+    /// The kind, named as in the `check` JSON output and `.scopelint.toml`.
+    pub kind: ValidatorKind,
+    /// The label of findings of this kind.
+    pub label: &'static str,
+    /// The convention this kind enforces.
+    pub description: &'static str,
+    /// The severity of findings of this kind when `[severity]` does not set one.
+    pub default_severity: Severity,
+    /// Whether findings of this kind are reported without setting `config_key`.
+    pub enabled_by_default: bool,
+    /// The `.scopelint.toml` key that configures this kind, if any.
+    pub config_key: Option<&'static str>,
+    /// The regex that names must match unless `config_key` overrides it, if any.
+    pub default_pattern: Option<String>,
+}
+
+// Returns the `.scopelint.toml` key that configures `kind`, and whether setting it is what enables
+// the check.
+const fn config_key(kind: ValidatorKind) -> Option<(&'static str, bool)> {
+    match kind {
+        ValidatorKind::AbstractContract => Some(("abstract_contract_pattern", true)),
+        ValidatorKind::Assembly => Some(("assembly_comments", true)),
+        ValidatorKind::Constant => Some(("constant_name_pattern", false)),
+        ValidatorKind::ConstructorParameter => Some(("constructor_parameter_pattern", true)),
+        ValidatorKind::ContractFileName => Some(("contract_file_names", true)),
+        ValidatorKind::ContractPerFile => Some(("contracts_per_file", true)),
+        ValidatorKind::EmptyTest => Some(("empty_tests", true)),
+        ValidatorKind::EnumMember => Some(("enum_member_pattern", true)),
+        ValidatorKind::EventParameter => Some(("indexed_event_parameter_pattern", true)),
+        ValidatorKind::Interface => Some(("interface_prefix", false)),
+        ValidatorKind::LongLine => Some(("max_line_length", true)),
+        ValidatorKind::LongName => Some(("max_identifier_length", true)),
+        ValidatorKind::MissingDoc => Some(("missing_docs", true)),
+        ValidatorKind::MissingEvent => Some(("missing_events", true)),
+        ValidatorKind::MockContractName => Some(("mock_contract_names", true)),
+        ValidatorKind::NatspecTag => Some(("natspec_tags", true)),
+        ValidatorKind::Script => Some(("script_public_methods", false)),
+        ValidatorKind::Shadowing => Some(("shadowing", true)),
+        ValidatorKind::StateVariable => Some(("state_variable_pattern", false)),
+        ValidatorKind::StorageGap => Some(("upgradeable_bases", true)),
+        ValidatorKind::Test => Some(("test_name_pattern", false)),
+        ValidatorKind::TestBase => Some(("test_base_contract", true)),
+        ValidatorKind::TestContractName => Some(("test_contract_names", true)),
+        ValidatorKind::TestHelper => Some(("test_helper_prefix", true)),
+        ValidatorKind::TestPublic => Some(("external_tests", true)),
+        ValidatorKind::TestSubject => Some(("test_function_names", true)),
+        _ => None,
+    }
+}
+
+// Returns the regex that names of `kind` must match with `config`, taken from the validators. With
+// the default config, this is `None` for the name checks that are disabled until a pattern is set.
+fn pattern(kind: ValidatorKind, config: &Config) -> Option<String> {
+    let pattern = match kind {
+        ValidatorKind::AbstractContract => config.abstract_contract_pattern.as_ref()?,
+        ValidatorKind::Constant => constant_names::constant_name_pattern(config),
+        ValidatorKind::ConstructorParameter => config.constructor_parameter_pattern.as_ref()?,
+        ValidatorKind::EnumMember => config.enum_member_pattern.as_ref()?,
+        ValidatorKind::EventParameter => config.indexed_event_parameter_pattern.as_ref()?,
+        ValidatorKind::StateVariable => state_variable_names::state_variable_pattern(config),
+        ValidatorKind::Test => test_names::test_name_pattern(config),
+        _ => return None,
+    };
+    Some(pattern.as_str().to_string())
+}
+
+// Returns the explanation of `kind` from the validator that reports it. Parse errors are not
+// reported by a validator, so they have none.
+fn explanation(kind: ValidatorKind) -> Option<&'static Explanation> {
+    REGISTRY
+        .iter()
+        .flat_map(|validator| validator.explanations)
+        .find(|explanation| explanation.kind == kind)
+}
+
+#[must_use]
+/// Returns a rule for every kind of finding, in the order of [`ValidatorKind::ALL`].
+pub fn rules() -> Vec<Rule> {
+    let config = Config::default();
+    ValidatorKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let setting = config_key(kind);
+            Rule {
+                kind,
+                label: kind.label(),
+                description: kind.description(),
+                default_severity: config.severity(kind),
+                enabled_by_default: !setting.is_some_and(|(_, enables)| enables),
+                config_key: setting.map(|(key, _)| key),
+                default_pattern: pattern(kind, &config),
+            }
+        })
+        .collect()
+}
+
+/// Prints every rule that `scopelint check` enforces, along with its default severity and how to
+/// configure it. With `--json`, the rules are printed as a JSON array for tools and editors.
+/// # Errors
+/// Errors if the rules cannot be serialized.
+pub fn run(opts: &RulesOpts) -> Result<(), Box<dyn Error>> {
+    let rules = rules();
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&rules)?);
+        return Ok(())
+    }
+
+    let width = rules.iter().map(|rule| format!("{:?}", rule.kind).len()).max().unwrap_or(0);
+    for rule in &rules {
+        let kind = format!("{:?}", rule.kind);
+        let setting = match rule.config_key {
+            Some(key) if !rule.enabled_by_default => {
+                format!(" Disabled by default, enable with `{key}`.")
+            }
+            Some(key) => format!(" Configure with `{key}`."),
+            None => String::new(),
+        };
+        println!("{kind:<width$}  {}{setting}", rule.description);
+    }
+    Ok(())
+}
+
+// Formats the explanation of `kind` printed by `scopelint explain`, with the pattern, severity,
+// and whether it is enabled as configured in `config`.
+fn format_explanation(kind: ValidatorKind, config: &Config) -> String {
+    let mut out = format!("{kind:?}: {}\n\n{}\n", kind.label(), kind.description());
+    let explanation = explanation(kind);
+    if let Some(explanation) = explanation {
+        let _ = writeln!(out, "\n{}", explanation.rationale);
+    }
+
+    out.push('\n');
+    if let Some(pattern) = pattern(kind, config) {
+        let _ = writeln!(out, "Pattern: {pattern}");
+    }
+    let severity = match config.severity(kind) {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    };
+    let _ = writeln!(out, "Severity: {severity}");
+    let _ = writeln!(out, "Enabled: {}", if is_reported(kind, config) { "yes" } else { "no" });
+    if let Some((key, _)) = config_key(kind) {
+        let _ = writeln!(out, "Configured with: `{key}`");
+    }
+
+    // Snippets can span several lines, so each line of an example is indented.
+    let examples = |out: &mut String, title: &str, examples: &[&str]| {
+        let _ = writeln!(out, "\n{title}:");
+        for example in examples {
+            for line in example.lines() {
+                let _ = writeln!(out, "    {line}");
+            }
+        }
+    };
+    if let Some(explanation) = explanation {
+        examples(&mut out, "Valid", explanation.valid);
+        examples(&mut out, "Invalid", explanation.invalid);
+    }
+    out
+}
+
+// Returns `true` if findings of `kind` are reported with `config`, which for the checks that are
+// disabled by default means that their `.scopelint.toml` key is set.
+fn is_reported(kind: ValidatorKind, config: &Config) -> bool {
+    let is_configured = match kind {
+        ValidatorKind::AbstractContract => config.abstract_contract_pattern.is_some(),
+        ValidatorKind::Assembly => config.assembly_comments == Some(true),
+        ValidatorKind::ConstructorParameter => config.constructor_parameter_pattern.is_some(),
+        ValidatorKind::ContractFileName => config.contract_file_names == Some(true),
+        ValidatorKind::ContractPerFile => config.contracts_per_file == Some(true),
+        ValidatorKind::EmptyTest => config.empty_tests == Some(true),
+        ValidatorKind::EnumMember => config.enum_member_pattern.is_some(),
+        ValidatorKind::EventParameter => config.indexed_event_parameter_pattern.is_some(),
+        ValidatorKind::Interface => config.interface_prefix != Some(false),
+        ValidatorKind::LongLine => config.max_line_length.is_some(),
+        ValidatorKind::LongName => config.max_identifier_length.is_some(),
+        ValidatorKind::MissingDoc => config.missing_docs == Some(true),
+        ValidatorKind::MissingEvent => config.missing_events == Some(true),
+        ValidatorKind::StorageGap => config.upgradeable_bases.is_some(),
+        ValidatorKind::MockContractName => config.mock_contract_names.is_some(),
+        ValidatorKind::NatspecTag => config.natspec_tags == Some(true),
+        ValidatorKind::Shadowing => config.shadowing == Some(true),
+        ValidatorKind::TestBase => config.test_base_contract.is_some(),
+        ValidatorKind::TestContractName => config.test_contract_names.is_some(),
+        ValidatorKind::TestHelper => config.test_helper_prefix == Some(true),
+        ValidatorKind::TestPublic => config.external_tests == Some(true),
+        ValidatorKind::TestSubject => config.test_function_names == Some(true),
+        _ => true,
+    };
+    is_configured && config.is_enabled(kind)
+}
+
+/// Prints an explanation of the rule behind a kind of finding, with examples of valid and invalid
+/// code, and the pattern and severity configured in `.scopelint.toml`.
+/// # Errors
+/// Errors if the config is invalid.
+pub fn explain(opts: &ExplainOpts) -> Result<(), Box<dyn Error>> {
+    let config = Config::load()?;
+    print!("{}", format_explanation(opts.kind, &config));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FoundryPaths;
+
+    #[test]
+    fn test_rules() {
+        let rules = rules();
+        assert_eq!(rules.len(), ValidatorKind::ALL.len());
+
+        // Every kind that a validator reports is listed.
+        for validator in &REGISTRY {
+            assert!(validator.kinds.iter().all(|kind| rules.iter().any(|rule| rule.kind == *kind)));
+        }
+        let constant = rules.iter().find(|rule| rule.kind == ValidatorKind::Constant).unwrap();
+        assert!(constant.enabled_by_default);
+        assert_eq!(constant.config_key, Some("constant_name_pattern"));
+        assert_eq!(constant.default_pattern.as_deref(), Some(r"^(?:[$_]*[A-Z0-9][$_]*){1,}$"));
+        let shadowing = rules.iter().find(|rule| rule.kind == ValidatorKind::Shadowing).unwrap();
+        assert!(!shadowing.enabled_by_default);
+        assert_eq!(shadowing.default_pattern, None);
+
+        // Each key is a `.scopelint.toml` key, so an invalid value is rejected for its type
+        // rather than as an unknown field.
+        for key in rules.iter().filter_map(|rule| rule.config_key) {
+            let err = Config::from_toml(FoundryPaths::default(), &format!("{key} = {{}}"));
+            assert!(!err.unwrap_err().to_string().contains("unknown field"), "{key}");
+        }
+
+        // Each validator explains the kinds it reports, in the same order.
+        for validator in &REGISTRY {
+            let kinds: Vec<ValidatorKind> = validator.explanations.iter().map(|e| e.kind).collect();
+            assert_eq!(kinds, validator.kinds);
+            for explanation in validator.explanations {
+                let kind = explanation.kind;
+                assert!(explanation.rationale.ends_with('.'), "{kind:?}");
+                assert!(
+                    !explanation.valid.is_empty() && !explanation.invalid.is_empty(),
+                    "{kind:?}"
+                );
+            }
+        }
+
+        // The JSON output names kinds and severities as in `.scopelint.toml`.
+        let json = serde_json::to_value(constant).unwrap();
+        assert_eq!(json["kind"], "Constant");
+        assert_eq!(json["default_severity"], "error");
+    }
+
+    #[test]
+    fn test_format_explanation() {
+        let config = Config::default();
+        let text = format_explanation(ValidatorKind::Constant, &config);
+        let expected = [
+            "Constant: Invalid constant or immutable name",
+            "",
+            "Constant and immutable names are in `ALL_CAPS`.",
+            "",
+        ];
+        assert!(text.starts_with(&expected.join("\n")), "{text}");
+        let expected = [
+            "Pattern: ^(?:[$_]*[A-Z0-9][$_]*){1,}$",
+            "Severity: error",
+            "Enabled: yes",
+            "Configured with: `constant_name_pattern`",
+            "",
+            "Valid:",
+            "    MAX_UINT256",
+        ];
+        assert!(text.contains(&expected.join("\n")), "{text}");
+        assert!(text.contains("Invalid:\n    variable\n"), "{text}");
+
+        // The examples of names that follow a default pattern match it. Test names are also
+        // checked after the pattern, so some invalid examples match it.
+        for kind in [ValidatorKind::Constant, ValidatorKind::StateVariable, ValidatorKind::Test] {
+            let regex = regex::Regex::new(&pattern(kind, &config).unwrap()).unwrap();
+            let explanation = explanation(kind).unwrap();
+            assert!(explanation.valid.iter().all(|name| regex.is_match(name)), "{kind:?}");
+            if kind != ValidatorKind::Test {
+                assert!(!explanation.invalid.iter().any(|name| regex.is_match(name)), "{kind:?}");
+            }
+        }
+        let invalid = explanation(ValidatorKind::Test).unwrap().invalid;
+        assert!(!invalid.iter().any(|name| test_names::is_valid_test_name(name, &config)));
+
+        // Checks that are disabled by default show the configured pattern once enabled, and
+        // parse errors have no examples.
+        let text = format_explanation(ValidatorKind::EnumMember, &config);
+        assert!(text.contains("Enabled: no\n") && !text.contains("Pattern:"));
+        let config =
+            Config::from_toml(FoundryPaths::default(), r#"enum_member_pattern = "^[A-Z]+$""#)
+                .unwrap();
+        let text = format_explanation(ValidatorKind::EnumMember, &config);
+        assert!(text.contains("Pattern: ^[A-Z]+$\nSeverity: error\nEnabled: yes\n"));
+        let text = format_explanation(ValidatorKind::Parse, &config);
+        assert!(!text.contains("Valid:"), "{text}");
+    }
+}
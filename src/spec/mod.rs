@@ -3,7 +3,10 @@
 // extensions manually with `ends_with`.
 #![allow(clippy::case_sensitive_file_extension_comparisons)]
 
-use crate::check::utils::{Name, VisibilitySummary};
+use crate::{
+    check::utils::{parse, Name, VisibilitySummary},
+    config::FoundryPaths,
+};
 use colored::Colorize;
 use solang_parser::pt::{
     ContractDefinition, ContractPart, ContractTy, FunctionDefinition, SourceUnitPart,
@@ -17,7 +20,8 @@ use walkdir::WalkDir;
 
 /// Generates a specification for the current project from test names.
 /// # Errors
-/// Returns an error if the specification could not be generated from the Solidity code.
+/// Returns an error if `foundry.toml` could not be read, or if the specification could not be
+/// generated from the Solidity code.
 /// # Panics
 /// Panics when a file path could not be unwrapped.
 pub fn run() -> Result<(), Box<dyn Error>> {
@@ -26,9 +30,11 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     // =================================
 
     // First, parse all source and test files to collect the contracts and their methods. All free
-    // functions are added under a special contract called `FreeFunctions`.
-    let src_contracts = get_contracts_for_dir("./src", ".sol");
-    let test_contracts = get_contracts_for_dir("./test", ".t.sol");
+    // functions are added under a special contract called `FreeFunctions`. The directories are read
+    // from `foundry.toml`, like for `scopelint check`.
+    let paths = FoundryPaths::load()?;
+    let src_contracts = get_contracts_for_dir(&paths.src, ".sol");
+    let test_contracts = get_contracts_for_dir(&paths.test, ".t.sol");
 
     // ========================================
     // ======== Generate Specification ========
@@ -75,7 +81,7 @@ impl ParsedContract {
     fn contract_name(&self) -> String {
         self.contract
             .as_ref()
-            .map_or("FreeFunctions".to_string(), |c| c.name.as_ref().unwrap().name.clone())
+            .map_or_else(|| "FreeFunctions".to_string(), |c| c.name.as_ref().unwrap().name.clone())
     }
 
     fn contract_name_from_file(&self) -> String {
@@ -118,7 +124,7 @@ impl ContractSpecification {
                 .iter()
                 .find(|tc| {
                     // Find the test contract with the same name
-                    tc.contract_name().to_ascii_lowercase() == src_fn.name().to_ascii_lowercase()
+                    tc.contract_name().eq_ignore_ascii_case(&src_fn.name())
                 })
                 .map_or_else(
                     // If there's no matching test contract, print the name of the source function
@@ -216,7 +222,7 @@ fn get_contracts_for_dir<P: AsRef<Path>>(dir: P, extension: &str) -> Vec<ParsedC
 
 fn parse_contracts(file: &Path) -> Vec<ParsedContract> {
     let content = fs::read_to_string(file).unwrap();
-    let (pt, _comments) = solang_parser::parse(&content, 0).expect("Parsing failed");
+    let (pt, _comments) = parse(&content).expect("Parsing failed");
     let mut contracts: Vec<ParsedContract> = Vec::new();
 
     for element in &pt.0 {
@@ -253,7 +259,6 @@ fn trimmed_fn_name_to_requirement(trimmed_fn_name: &str) -> String {
     trimmed_fn_name
         .replace('_', ":")
         .chars()
-        .enumerate()
-        .map(|(_i, c)| if c.is_uppercase() { format!(" {c}") } else { c.to_string() })
+        .map(|c| if c.is_uppercase() { format!(" {c}") } else { c.to_string() })
         .collect::<String>()
 }
@@ -27,5 +27,34 @@ contract Counter {
   function _privateHasLeadingUnderscore() private {
     number += 1000;
   }
+
+  // Findings can be disabled with comments.
+  // scopelint-disable-next-line
+  function disabledNextLine() internal {}
+  function disabledLine() internal {} // scopelint-disable-line Allowed for this test.
+
+  function _publicShouldNotHaveLeadingUnderscore() public {}
+
+  event counter_incremented();
+  error counter_overflow();
+  struct counter_state { uint256 number; }
+  enum counter_mode { Up, Down }
+
+  modifier OnlyPositive() {
+    _;
+  }
+
+  function setNumber(uint newNumber) external {
+    number = newNumber;
+  }
+}
+
+
+interface CounterLike {
+  function number() external view returns (uint256);
 }
 
+contract CounterConfig {
+  uint256 internal MAX_COUNT = 10;
+  uint256 internal Min_Count;
+}
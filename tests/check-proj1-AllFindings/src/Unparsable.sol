@@ -0,0 +1,5 @@
+pragma solidity ^0.8.17;
+
+contract Unparsable {
+  uint256 public number = 1 # 2;
+}
@@ -0,0 +1,8 @@
+pragma solidity ^0.8.17;
+
+// This file is excluded by `.scopelintignore`, so these invalid names are not reported.
+contract Generated {
+  uint256 public constant generatedConstant = 1;
+
+  function generatedInternal() internal {}
+}
@@ -18,12 +18,13 @@ contract CounterTest is Test {
     assertEq(counter.number(), 1);
   }
 
-  function test_SetNumber_GoodName(uint256 x) public {
+  function testFuzz_SetNumber_GoodName(uint256 x) public {
     counter.setNumber(x);
     assertEq(counter.number(), x);
   }
 
   function test_RevertIf_Overflow() public {}
+  function test_NeverRuns() internal {}
 
   function internalShouldHaveLeadingUnderscore() internal {}
 
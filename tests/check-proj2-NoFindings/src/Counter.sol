@@ -6,6 +6,15 @@ contract Counter {
 
   uint256 public number;
 
+  event NumberSet(uint256 newNumber);
+  error CounterOverflow();
+  struct CounterState { uint256 number; }
+  enum CounterMode { Up, Down }
+
+  modifier onlyPositive() {
+    _;
+  }
+
   constructor() {
     _GOOD__IMMUTABLE_ = 2000;
   }
@@ -24,3 +33,7 @@ contract Counter {
 
   function _privateHasLeadingUnderscore() private {}
 }
+
+interface ICounter {
+  function number() external view returns (uint256);
+}
@@ -2,11 +2,13 @@
 /// terminal. Therefore, most testing is done by running the binary against a sample forge
 /// project and checking the output.
 use std::{
-    env,
-    process::{Command, Output},
+    env, fs,
+    io::Write,
+    path::Path,
+    process::{Command, Output, Stdio},
 };
 
-fn run_scopelint(test_folder: &str) -> Output {
+fn run_scopelint(test_folder: &str, args: &[&str]) -> Output {
     let cwd = env::current_dir().unwrap();
     let project_path = cwd.join("tests").join(test_folder);
     let binary_path = cwd.join("target/debug/scopelint");
@@ -14,30 +16,47 @@ fn run_scopelint(test_folder: &str) -> Output {
     Command::new(binary_path)
         .current_dir(project_path)
         .arg("check")
+        .args(args)
         .output()
         .expect("Failed to execute command")
 }
 
 #[test]
 fn test_check_proj1_all_findings() {
-    let output = run_scopelint("check-proj1-AllFindings");
+    let output = run_scopelint("check-proj1-AllFindings", &[]);
+    // Both the conventions and the formatting checks fail.
+    assert_eq!(output.status.code(), Some(3));
     let stderr = String::from_utf8(output.stderr).unwrap();
     let findings: Vec<&str> = stderr.split("\n").collect();
 
     let expected_findings = [
-        "Invalid constant or immutable name in ./script/Counter.s.sol on line 7: VERY_bad_constant",
-        "Invalid constant or immutable name in ./script/Counter.s.sol on line 6: bad_constant",
-        "Invalid constant or immutable name in ./script/Counter.s.sol on line 8: sorryBadName",
-        "Invalid constant or immutable name in ./script/ScriptHelpers.sol on line 4: stillNeedGoodNames",
-        "Invalid constant or immutable name in ./src/Counter.sol on line 5: badImmutable",
-        "Invalid constant or immutable name in ./src/Counter.sol on line 6: bad_constant",
-        "Invalid constant or immutable name in ./test/Counter.t.sol on line 7: testVal",
-        r#"Invalid script interface in ./script/Counter.s.sol: Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: ["run", "runExternal"]"#,
-        r#"Invalid script interface in ./script/Counter2.s.sol: Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: ["run", "anotherPublic", "thirdPublic"]"#,
-        "Invalid src method name in ./src/Counter.sol on line 23: internalShouldHaveLeadingUnderscore",
-        "Invalid src method name in ./src/Counter.sol on line 25: privateShouldHaveLeadingUnderscore",
-        "Invalid test name in ./test/Counter.t.sol on line 16: testIncrementBadName",
+        r#"Invalid script interface in ./script/Counter.s.sol:5:10: Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: ["run", "runExternal"]"#,
+        "Invalid constant or immutable name in ./script/Counter.s.sol:6:27: bad_constant",
+        "Invalid constant or immutable name in ./script/Counter.s.sol:7:29: VERY_bad_constant",
+        "Invalid constant or immutable name in ./script/Counter.s.sol:8:27: sorryBadName",
+        r#"Invalid script interface in ./script/Counter2.s.sol:5:10: Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: ["run", "anotherPublic", "thirdPublic"]"#,
+        "Invalid constant or immutable name in ./script/ScriptHelpers.sol:4:20: stillNeedGoodNames",
+        "Invalid constant or immutable name in ./src/Counter.sol:5:28: badImmutable",
+        "Invalid constant or immutable name in ./src/Counter.sol:6:27: bad_constant",
+        "Invalid src method name in ./src/Counter.sol:23:12: internalShouldHaveLeadingUnderscore()",
+        "Invalid src method name in ./src/Counter.sol:25:12: privateShouldHaveLeadingUnderscore()",
+        "Invalid public or external src method name in ./src/Counter.sol:36:12: _publicShouldNotHaveLeadingUnderscore()",
+        "Invalid event name in ./src/Counter.sol:38:9: counter_incremented",
+        "Invalid custom error name in ./src/Counter.sol:39:9: counter_overflow",
+        "Invalid struct name in ./src/Counter.sol:40:10: counter_state",
+        "Invalid enum name in ./src/Counter.sol:41:8: counter_mode",
+        "Invalid modifier name in ./src/Counter.sol:43:12: OnlyPositive",
+        "Duplicate function in ./src/Counter.sol:47:12: setNumber(uint256)",
+        "Invalid interface name in ./src/Counter.sol:53:11: CounterLike",
+        "warning: Invalid mutable variable name in ./src/Counter.sol:58:20: MAX_COUNT is named like a constant, so declare it `constant` or `immutable`, or rename it",
+        "Invalid state variable name in ./src/Counter.sol:59:20: Min_Count",
+        "Failed to parse ./src/Unparsable.sol:4:29: unrecognised token '#' (file declares `pragma solidity ^0.8.17`)",
+        "Invalid constant or immutable name in ./test/Counter.t.sol:7:20: testVal",
+        "Invalid test name in ./test/Counter.t.sol:16:12: testIncrementBadName()",
+        "Non-public test function in ./test/Counter.t.sol:27:12: test_NeverRuns()",
+        "summary: Constant: 7, DuplicateFunction: 1, Enum: 1, Error: 1, Event: 1, Interface: 1, Modifier: 1, MutableConstant: 1, Parse: 1, Script: 2, Src: 2, SrcPublic: 1, StateVariable: 1, Struct: 1, Test: 1, TestVisibility: 1",
         "error: Convention checks failed, see details above",
+        "scopelint: 24 issues across 7 files",
         "error: Formatting validation failed, run `scopelint fmt` to fix",
         "",
     ];
@@ -48,16 +67,1064 @@ fn test_check_proj1_all_findings() {
     assert_eq!(findings.len(), expected_findings.len());
 }
 
+#[test]
+fn test_check_proj1_all_findings_quiet() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--quiet"]);
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = [
+        "error: Convention checks failed, run without `--quiet` for details",
+        "error: Formatting validation failed, run `scopelint fmt` to fix",
+        "",
+    ];
+    assert_eq!(stderr.split('\n').collect::<Vec<&str>>(), expected);
+}
+
+#[test]
+fn test_check_proj1_no_color() {
+    let cwd = env::current_dir().unwrap();
+    let check = |args: &[&str], no_color: Option<&str>| {
+        let mut command = Command::new(cwd.join("target/debug/scopelint"));
+        command.current_dir(cwd.join("tests/check-proj1-AllFindings")).arg("check").args(args);
+        // Forces colors even though the output is not a terminal, so disabling them is visible.
+        command.env("CLICOLOR_FORCE", "1").env_remove("NO_COLOR");
+        if let Some(value) = no_color {
+            command.env("NO_COLOR", value);
+        }
+        String::from_utf8(command.output().expect("Failed to execute command").stderr).unwrap()
+    };
+
+    assert!(check(&[], None).contains('\u{1b}'));
+    assert!(check(&[], Some("")).contains('\u{1b}'));
+    assert!(!check(&["--no-color"], None).contains('\u{1b}'));
+    assert!(!check(&[], Some("1")).contains('\u{1b}'));
+}
+
+#[test]
+fn test_check_proj2_no_findings_verbose() {
+    // Skip the cache so every file is validated, regardless of what earlier runs cached.
+    let output = run_scopelint("check-proj2-NoFindings", &["--verbose", "--no-cache"]);
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert!(lines.iter().any(|line| line.starts_with("checked ./src/Counter.sol in ")));
+    assert!(lines.iter().any(|line| line.starts_with("checked ./test/Counter.t.sol in ")));
+    // Helpers only get the checks for all files, which is noted so they are not assumed checked.
+    let helpers = lines.iter().find(|line| line.starts_with("checked ./script/ScriptHelpers.sol"));
+    assert!(helpers.unwrap().ends_with(" (not a src, script, or test file)"), "{stderr}");
+    assert_eq!(lines[lines.len() - 2], "checked 5 files");
+    assert_eq!(lines[lines.len() - 1], "scopelint: 0 issues across 5 files");
+    assert!(!stderr.contains("(cached)"));
+}
+
+#[test]
+fn test_check_proj1_all_findings_verbose() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--verbose", "--no-cache"]);
+    assert!(!output.status.success());
+
+    // Each finding is followed by its source line, with the identifier underlined.
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = [
+        "Invalid state variable name in ./src/Counter.sol:59:20: Min_Count",
+        "   |",
+        "59 |   uint256 internal Min_Count;",
+        "   |                    ^^^^^^^^^",
+        "Failed to parse ./src/Unparsable.sol:4:29: unrecognised token '#' (file declares `pragma solidity ^0.8.17`)",
+        "  |",
+        "4 |   uint256 public number = 1 # 2;",
+        "  |                             ^",
+    ];
+    assert!(stderr.contains(&expected.join("\n")), "{stderr}");
+}
+
 #[test]
 fn test_check_proj2_no_findings() {
-    let output = run_scopelint("check-proj2-NoFindings");
+    let output = run_scopelint("check-proj2-NoFindings", &[]);
+    assert_eq!(output.status.code(), Some(0));
     let stderr = String::from_utf8(output.stderr).unwrap();
     let findings: Vec<&str> = stderr.split("\n").collect();
 
-    let expected_findings = [""];
+    // A clean run still reports how many files were checked.
+    let expected_findings = ["scopelint: 0 issues across 5 files", ""];
 
     for (i, expected) in expected_findings.iter().enumerate() {
         assert_eq!(findings[i], *expected);
     }
     assert_eq!(findings.len(), expected_findings.len());
 }
+
+#[test]
+fn test_check_proj1_all_findings_json() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--json"]);
+    assert!(!output.status.success());
+    assert!(output.stderr.is_empty());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    assert_eq!(items.len(), 24);
+    assert_eq!(
+        items[2],
+        serde_json::json!({
+            "kind": "Constant",
+            "file": "./script/Counter.s.sol",
+            "text": "VERY_bad_constant",
+            "line": 7,
+            "col": 29,
+            "severity": "error",
+        })
+    );
+}
+
+#[test]
+fn test_check_proj2_no_findings_json() {
+    let output = run_scopelint("check-proj2-NoFindings", &["--json"]);
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report, serde_json::json!({ "invalid_items": [] }));
+}
+
+#[test]
+fn test_check_proj1_all_findings_github() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--format", "github"]);
+    assert!(!output.status.success());
+    assert!(output.stderr.is_empty());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let annotations: Vec<&str> = stdout.lines().collect();
+    assert_eq!(annotations.len(), 25);
+    assert_eq!(
+        annotations[0],
+        r#"::error file=script/Counter.s.sol,line=5,col=10::Invalid script interface: Scripts must have a single public method named `run` (excluding `setUp`), but the following methods were found: ["run", "runExternal"]"#
+    );
+    assert_eq!(
+        annotations[2],
+        "::error file=script/Counter.s.sol,line=7,col=29::Invalid constant or immutable name: VERY_bad_constant"
+    );
+    assert_eq!(
+        annotations[24],
+        "::error::Formatting validation failed, run `scopelint fmt` to fix"
+    );
+}
+
+// Copies a test project to a temporary directory, so it can be modified by the test.
+fn copy_project(test_folder: &str, name: &str) -> std::path::PathBuf {
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    let cwd = env::current_dir().unwrap();
+    let target = env::temp_dir().join(format!("scopelint-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&target);
+    copy_dir(&cwd.join("tests").join(test_folder), &target);
+    target
+}
+
+// Writes a project where one file uses constants defined in another, through an import and
+// through inheritance, to a temporary directory.
+fn write_fix_project(name: &str) -> std::path::PathBuf {
+    let project_path = env::temp_dir().join(format!("scopelint-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&project_path);
+    for dir in ["src", "script", "test"] {
+        fs::create_dir_all(project_path.join(dir)).unwrap();
+    }
+    let base = r"pragma solidity ^0.8.17;
+
+uint256 constant fileLevelMax = 100;
+
+contract Base {
+  uint256 constant maxSupply = 10;
+  uint256 private constant privateMax = 5;
+
+  function _limit() internal pure returns (uint256) {
+    return privateMax;
+  }
+}
+";
+    let child = r#"pragma solidity ^0.8.17;
+
+import {Base, fileLevelMax} from "./Base.sol";
+
+contract Child is Base {
+  function total() external pure returns (uint256) {
+    return maxSupply + fileLevelMax + _limit();
+  }
+}
+"#;
+    fs::write(project_path.join("src/Base.sol"), base).unwrap();
+    fs::write(project_path.join("src/Child.sol"), child).unwrap();
+    project_path
+}
+
+#[test]
+fn test_check_fix() {
+    let project_path = write_fix_project("fix");
+    let child = fs::read_to_string(project_path.join("src/Child.sol")).unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let output = Command::new(binary_path)
+        .current_dir(&project_path)
+        .args(["check", "--fix"])
+        .output()
+        .expect("Failed to execute command");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("fixed: Renamed 1 constant or immutable name\n"), "{stderr}");
+
+    // Only the private constant is renamed, since `Child.sol` uses the others.
+    let base = fs::read_to_string(project_path.join("src/Base.sol")).unwrap();
+    assert!(base.contains("uint256 private constant PRIVATE_MAX = 5;"));
+    assert!(base.contains("return PRIVATE_MAX;"));
+    assert!(base.contains("uint256 constant fileLevelMax = 100;"));
+    assert!(base.contains("uint256 constant maxSupply = 10;"));
+    assert_eq!(fs::read_to_string(project_path.join("src/Child.sol")).unwrap(), child);
+
+    // The renamed constant is no longer reported, and the others still are.
+    assert!(!stderr.contains("privateMax"));
+    assert!(stderr.contains("./src/Base.sol:3:18: fileLevelMax"), "{stderr}");
+    assert!(stderr.contains("./src/Base.sol:6:20: maxSupply"), "{stderr}");
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj1_baseline() {
+    let project_path = copy_project("check-proj1-AllFindings", "baseline");
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = |baseline: &str, args: &[&str]| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache", "--baseline", baseline])
+            .args(args)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Recording the baseline reports none of the findings, so the convention checks pass. Only the
+    // conventions bit of the exit code is checked, since the formatting check also runs.
+    let output = run("baseline.json", &["--write-baseline"]);
+    assert_eq!(output.status.code().unwrap() & 1, 0);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("baseline: Recorded 24 findings in baseline.json\n"), "{stderr}");
+    assert!(stderr.contains("scopelint: 0 issues across 7 files"), "{stderr}");
+
+    // Moving existing findings does not report them again, but new findings are reported.
+    let src_path = project_path.join("src/Counter.sol");
+    let src = fs::read_to_string(&src_path).unwrap();
+    fs::write(&src_path, src.replacen("\n", "\n  uint256 constant new_constant = 1;\n", 1))
+        .unwrap();
+    let output = run("baseline.json", &[]);
+    assert_eq!(output.status.code().unwrap() & 1, 1);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = [
+        "baseline: Ignored 24 findings recorded in baseline.json",
+        "Invalid constant or immutable name in ./src/Counter.sol:2:20: new_constant",
+        "summary: Constant: 1",
+    ];
+    assert_eq!(stderr.lines().take(expected.len()).collect::<Vec<_>>(), expected, "{stderr}");
+
+    // A missing baseline fails the check.
+    let output = run("missing.json", &[]);
+    assert_eq!(output.status.code(), Some(4));
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_fix_dry_run() {
+    let project_path = write_fix_project("fix-dry-run");
+    let base_path = project_path.join("src/Base.sol");
+    let base = fs::read_to_string(&base_path).unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let output = Command::new(binary_path)
+        .current_dir(&project_path)
+        .args(["check", "--fix", "--dry-run"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(!output.status.success());
+
+    // The same renames as `--fix` are printed, without changing any files.
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = [
+        "would rename: privateMax -> PRIVATE_MAX in ./src/Base.sol:7",
+        "fixable: Would rename 1 constant or immutable name",
+    ];
+    assert_eq!(stderr.lines().take(expected.len()).collect::<Vec<_>>(), expected, "{stderr}");
+    assert_eq!(fs::read_to_string(&base_path).unwrap(), base);
+    assert!(stderr.contains("./src/Base.sol:7:28: privateMax"), "{stderr}");
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_fmt_check() {
+    let cwd = env::current_dir().unwrap();
+    let fmt_check = |test_folder: &str| {
+        Command::new(cwd.join("target/debug/scopelint"))
+            .current_dir(cwd.join("tests").join(test_folder))
+            .args(["fmt", "--check"])
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Unformatted files fail the check with the formatting exit code, and are left unchanged.
+    let project_path = cwd.join("tests/check-proj1-AllFindings");
+    let config = fs::read_to_string(project_path.join("foundry.toml")).unwrap();
+    let output = fmt_check("check-proj1-AllFindings");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr, "error: Formatting validation failed, run `scopelint fmt` to fix\n");
+    assert_eq!(fs::read_to_string(project_path.join("foundry.toml")).unwrap(), config);
+
+    let output = fmt_check("check-proj2-NoFindings");
+    assert!(output.status.success());
+}
+
+fn run_scopelint_stdin(test_folder: &str, args: &[&str], stdin: &str) -> Output {
+    let cwd = env::current_dir().unwrap();
+    let mut child = Command::new(cwd.join("target/debug/scopelint"))
+        .current_dir(cwd.join("tests").join(test_folder))
+        .args(["check", "--stdin"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_check_stdin() {
+    let source = "contract Counter {\n    function increment() internal {}\n}\n";
+
+    // Only the conventions are checked, so the formatting of the project does not fail the check.
+    let output = run_scopelint_stdin(
+        "check-proj1-AllFindings",
+        &["--stdin-filename", "src/Foo.sol"],
+        source,
+    );
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = [
+        "Invalid src method name in ./src/Foo.sol:2:14: increment()",
+        "summary: Src: 1",
+        "error: Convention checks failed, see details above",
+        "scopelint: 1 issues across 1 files",
+        "",
+    ];
+    assert_eq!(stderr, expected.join("\n"));
+
+    // The file kind comes from the file name, so the same source is valid as a test file.
+    let output = run_scopelint_stdin(
+        "check-proj1-AllFindings",
+        &["--stdin-filename", "test/Foo.t.sol"],
+        source,
+    );
+    assert!(output.status.success());
+
+    // Ignored files are not checked.
+    let output = run_scopelint_stdin(
+        "check-proj1-AllFindings",
+        &["--stdin-filename", "src/generated/Foo.sol"],
+        source,
+    );
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_check_proj2_cache() {
+    let project_path = copy_project("check-proj2-NoFindings", "cache");
+    let _ = fs::remove_file(project_path.join(".scopelint-cache"));
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = |args: &[&str]| {
+        let output = Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--verbose"])
+            .args(args)
+            .output()
+            .expect("Failed to execute command");
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    let is_cached = |stderr: &str, file: &str| {
+        stderr.lines().any(|line| {
+            line.starts_with(&format!("checked {file} ")) && line.ends_with(" (cached)")
+        })
+    };
+
+    // Clean files are cached by the first run, and skipped by the next.
+    assert!(!run(&[]).contains("(cached)"));
+    let stderr = run(&[]);
+    assert!(is_cached(&stderr, "./src/Counter.sol"));
+    assert!(is_cached(&stderr, "./test/Counter.t.sol"));
+
+    // Changed files are validated again.
+    let counter = project_path.join("src/Counter.sol");
+    fs::write(&counter, fs::read_to_string(&counter).unwrap() + "\n").unwrap();
+    let stderr = run(&[]);
+    assert!(!is_cached(&stderr, "./src/Counter.sol"));
+    assert!(is_cached(&stderr, "./test/Counter.t.sol"));
+
+    // The cache is bypassed with `--no-cache`.
+    assert!(!run(&["--no-cache"]).contains("(cached)"));
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj1_count() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--count", "--no-cache"]);
+    // The formatting check is skipped, so only the convention checks fail.
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "24\n");
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "");
+
+    let output = run_scopelint("check-proj2-NoFindings", &["--count", "--no-cache"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "0\n");
+}
+
+#[test]
+fn test_check_proj1_fail_fast() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--fail-fast", "--no-cache"]);
+    // The formatting check is skipped once the convention checks fail.
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    // Only the findings of the first file with any are reported.
+    assert!(stderr.contains("./script/Counter.s.sol:8:27: sorryBadName"), "{stderr}");
+    assert!(!stderr.contains("./script/Counter2.s.sol"), "{stderr}");
+    assert!(!stderr.contains("Formatting validation"), "{stderr}");
+    assert!(stderr.ends_with("scopelint: 4 issues across 1 files\n"), "{stderr}");
+}
+
+#[test]
+fn test_check_proj1_paths() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--json", "src/Counter.sol", "test"]);
+    assert!(!output.status.success());
+
+    // Only the given files and directories are checked, and the kind of each file is inferred.
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    let mut files: Vec<&str> = items.iter().map(|item| item["file"].as_str().unwrap()).collect();
+    files.sort();
+    files.dedup();
+    assert_eq!(files, ["./src/Counter.sol", "./test/Counter.t.sol"]);
+    assert!(items.iter().any(|item| item["kind"] == "Src"));
+    assert!(items.iter().any(|item| item["kind"] == "Test"));
+}
+
+#[test]
+fn test_check_proj1_globs() {
+    let output = run_scopelint(
+        "check-proj1-AllFindings",
+        &["--json", "--no-cache", "script/Counter?.s.sol", "src/**/*.sol", "test/Missing*.sol"],
+    );
+    assert!(!output.status.success());
+
+    // Globs are expanded to the files they match, without the ignored files, and `*` and `?` do
+    // not match `/`.
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    let mut files: Vec<&str> = items.iter().map(|item| item["file"].as_str().unwrap()).collect();
+    files.sort();
+    files.dedup();
+    assert_eq!(files, ["./script/Counter2.s.sol", "./src/Counter.sol", "./src/Unparsable.sol"]);
+
+    // Globs that match no files are skipped with a warning.
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("warning: No files match test/Missing*.sol"), "{stderr}");
+}
+
+#[test]
+fn test_check_proj1_dir_configs() {
+    let project_path = copy_project("check-proj1-AllFindings", "dir-configs");
+    fs::write(project_path.join(".scopelint.toml"), "[severity]\nScript = \"warning\"\n").unwrap();
+    let src_config = project_path.join("src/.scopelint.toml");
+    fs::write(&src_config, "disabled = [\"Src\"]\n[severity]\nConstant = \"warning\"\n").unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = || {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache", "--json"])
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Files under `src/` use the merged config, and other files use the root config.
+    let output = run();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    let severities = |kind: &str, file: &str| -> Vec<&str> {
+        items
+            .iter()
+            .filter(|item| item["kind"] == kind && item["file"] == file)
+            .map(|item| item["severity"].as_str().unwrap())
+            .collect()
+    };
+    assert!(severities("Src", "./src/Counter.sol").is_empty());
+    assert_eq!(severities("Constant", "./src/Counter.sol"), ["warning", "warning"]);
+    assert_eq!(severities("Constant", "./script/ScriptHelpers.sol"), ["error"]);
+    assert_eq!(severities("Script", "./script/Counter2.s.sol"), ["warning"]);
+
+    // Settings that apply to the whole project are rejected in subdirectories.
+    fs::write(&src_config, "skip_dirs = []\n").unwrap();
+    let output = run();
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains(
+            "Invalid ./src/.scopelint.toml: `skip_dirs` can only be set in the root .scopelint.toml"
+        ),
+        "{stderr}"
+    );
+    // Invalid values name the file that sets them.
+    fs::write(&src_config, "max_line_length = 0\n").unwrap();
+    let output = run();
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Invalid ./src/.scopelint.toml: `max_line_length` must be positive"),
+        "{stderr}"
+    );
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_timings() {
+    let output = run_scopelint("check-proj2-NoFindings", &["--no-cache", "--timings"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let (summary, table) = stderr.split_once("timings:\n").unwrap();
+    assert!(summary.ends_with("scopelint: 0 issues across 5 files\n"), "{stderr}");
+
+    // The table has a row for each phase, with the validators named by the kinds they report.
+    let phases: Vec<&str> =
+        table.lines().skip(1).map(|line| line.rsplit_once("  ").unwrap().0.trim()).collect();
+    for phase in ["walk", "parse", "formatting", "validator Constant", "validator Struct/Enum"] {
+        assert!(phases.contains(&phase), "{table}");
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_check_proj2_forge_fmt_config() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // A stand-in for forge that records where and how it is run.
+    let project_path = copy_project("check-proj2-NoFindings", "forge-fmt-config");
+    let bin = project_path.join("bin");
+    fs::create_dir(&bin).unwrap();
+    let forge = bin.join("forge");
+    fs::write(&forge, "#!/bin/sh\necho \"$(pwd -P) $*\" >> \"$(pwd -P)/forge-calls\"\n").unwrap();
+    fs::set_permissions(&forge, fs::Permissions::from_mode(0o755)).unwrap();
+    // The `[fmt]` settings are for forge, so a different `tab_width` does not change the TOML
+    // style.
+    let foundry = project_path.join("foundry.toml");
+    let content = fs::read_to_string(&foundry).unwrap().replace("tab_width = 2", "tab_width = 4");
+    fs::write(&foundry, content).unwrap();
+
+    let path = format!("{}:{}", bin.display(), env::var("PATH").unwrap());
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let output = Command::new(binary_path)
+        .current_dir(&project_path)
+        .env("PATH", path)
+        .args(["check", "--no-cache"])
+        .output()
+        .expect("Failed to execute command");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{stderr}");
+
+    // `forge fmt --check` runs in the project root without options, so it reads the `[fmt]`
+    // settings of the `foundry.toml` there itself.
+    let calls = fs::read_to_string(project_path.join("forge-calls")).unwrap();
+    let root = project_path.canonicalize().unwrap();
+    assert_eq!(calls, format!("{} fmt --check\n", root.display()));
+
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj1_absolute_paths() {
+    // Absolute paths within the project are reported like relative ones, so the kind of each file
+    // is inferred and the output is the same however the paths are passed.
+    let project_path = env::current_dir().unwrap().join("tests/check-proj1-AllFindings");
+    let src = project_path.join("src/Counter.sol");
+    let test = project_path.join("test");
+    let absolute = run_scopelint(
+        "check-proj1-AllFindings",
+        &["--json", src.to_str().unwrap(), test.to_str().unwrap()],
+    );
+    let relative =
+        run_scopelint("check-proj1-AllFindings", &["--json", "src/Counter.sol", "./test"]);
+    assert!(!absolute.status.success());
+    assert_eq!(absolute.stdout, relative.stdout);
+
+    let report: serde_json::Value = serde_json::from_slice(&relative.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    assert_eq!(items[0]["file"], "./src/Counter.sol");
+    assert!(items.iter().any(|item| item["kind"] == "Test"));
+}
+
+#[test]
+fn test_check_proj1_files_from() {
+    let cwd = env::current_dir().unwrap();
+    let mut child = Command::new(cwd.join("target/debug/scopelint"))
+        .current_dir(cwd.join("tests/check-proj1-AllFindings"))
+        .args(["check", "--json", "--files-from", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    let list = "src/Counter.sol\n\ntest/Counter.t.sol\nsrc/Missing.sol\nREADME.md\n";
+    child.stdin.take().unwrap().write_all(list.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+
+    // Only the listed Solidity files are checked, and the others are skipped with a warning.
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    let mut files: Vec<&str> = items.iter().map(|item| item["file"].as_str().unwrap()).collect();
+    files.dedup();
+    assert_eq!(files, ["./src/Counter.sol", "./test/Counter.t.sol"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("warning: Skipping src/Missing.sol"), "{stderr}");
+    assert!(stderr.contains("warning: Skipping README.md"), "{stderr}");
+}
+
+#[test]
+fn test_check_proj2_no_files() {
+    // A mistyped path finds no files, which fails instead of passing silently.
+    let output = run_scopelint("check-proj2-NoFindings", &["scr"]);
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("error: No Solidity files found to check"), "{stderr}");
+
+    let output = run_scopelint("check-proj2-NoFindings", &["--allow-empty", "scr"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("scopelint: 0 issues across 0 files"), "{stderr}");
+
+    // Globs that match no files find no files too, instead of checking the whole project.
+    let output = run_scopelint("check-proj2-NoFindings", &["scr/*.sol"]);
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("warning: No files match scr/*.sol"), "{stderr}");
+    assert!(stderr.contains("error: No Solidity files found to check"), "{stderr}");
+
+    let output = run_scopelint("check-proj2-NoFindings", &["--allow-empty", "scr/*.sol"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("scopelint: 0 issues across 0 files"), "{stderr}");
+}
+
+#[test]
+fn test_check_proj2_vyper() {
+    let project_path = copy_project("check-proj2-NoFindings", "vyper");
+    fs::write(project_path.join("src/Vault.vy"), "# @version 0.3.10\n").unwrap();
+    fs::write(project_path.join("src/IVault.vyi"), "").unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = |args: &[&str]| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .args(args)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Vyper files are skipped with a note, and the Solidity files are still checked.
+    let output = run(&[]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("note: Skipped 2 Vyper files, only Solidity is supported"), "{stderr}");
+    assert!(stderr.contains("scopelint: 0 issues across 5 files"), "{stderr}");
+
+    // The note is omitted with `--quiet`.
+    let output = run(&["--quiet"]);
+    assert!(String::from_utf8(output.stderr).unwrap().is_empty());
+
+    // Solidity files outside the project directories can't be categorized, which is noted too.
+    fs::create_dir(project_path.join("other")).unwrap();
+    fs::write(project_path.join("other/Counter.sol"), "contract Counter {}\n").unwrap();
+    let output = run(&["other/Counter.sol", "src/Counter.sol"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = "note: Only the checks for all files apply to 1 Solidity file outside the src, \
+                    script, and test directories";
+    assert!(stderr.contains(expected), "{stderr}");
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_check_proj2_follow_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let project_path = copy_project("check-proj2-NoFindings", "follow-symlinks");
+    // A shared directory outside the project directories, and a symlink to an ancestor.
+    fs::create_dir(project_path.join("shared")).unwrap();
+    fs::write(project_path.join("shared/Shared.sol"), "contract Shared {}\n").unwrap();
+    symlink("../shared", project_path.join("src/shared")).unwrap();
+    symlink(".", project_path.join("src/loop")).unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = |args: &[&str]| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .args(args)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Symlinks are not followed by default.
+    let output = run(&[]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("scopelint: 0 issues across 5 files"), "{stderr}");
+
+    // With `--follow-symlinks`, the shared file is checked, and the cycle is skipped.
+    let output = run(&["--follow-symlinks", "--verbose"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("checked ./src/shared/Shared.sol in "), "{stderr}");
+    assert!(
+        stderr.contains("warning: Skipping ./src/loop, which links to its ancestor ./src"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("scopelint: 0 issues across 6 files"), "{stderr}");
+
+    // Files reached through several paths are only checked once, at the shortest path, so a link
+    // to the project root does not check scripts as src files.
+    symlink("../shared", project_path.join("test/shared")).unwrap();
+    symlink("..", project_path.join("src/root")).unwrap();
+    let output = run(&["--follow-symlinks"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("scopelint: 0 issues across 6 files"), "{stderr}");
+
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj1_disable() {
+    let output =
+        run_scopelint("check-proj1-AllFindings", &["--json", "--disable", "constant,src-public"]);
+    assert!(!output.status.success());
+
+    // Disabled kinds are not reported, and all other kinds still are.
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = report["invalid_items"].as_array().unwrap();
+    assert_eq!(items.len(), 16);
+    assert!(items.iter().all(|item| item["kind"] != "Constant" && item["kind"] != "SrcPublic"));
+
+    // Unknown kinds are rejected.
+    let output = run_scopelint("check-proj1-AllFindings", &["--disable", "constants"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown kind `constants`"), "{stderr}");
+}
+
+#[test]
+fn test_check_proj1_all_findings_sarif() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--format", "sarif"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stderr.is_empty());
+
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 24);
+    assert_eq!(
+        results[2],
+        serde_json::json!({
+            "ruleId": "Constant",
+            "ruleIndex": 2,
+            "level": "error",
+            "message": { "text": "Invalid constant or immutable name: VERY_bad_constant" },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "script/Counter.s.sol" },
+                    "region": { "startLine": 7, "startColumn": 29 },
+                }
+            }],
+        })
+    );
+}
+
+#[test]
+fn test_check_proj1_all_findings_checkstyle() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--format", "checkstyle"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stderr.is_empty());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[1], r#"<checkstyle version="4.3">"#);
+    assert_eq!(lines[2], r#"  <file name="script/Counter.s.sol">"#);
+    assert_eq!(
+        lines[5],
+        r#"    <error line="7" column="29" severity="error" message="Invalid constant or immutable name: VERY_bad_constant" source="scopelint.Constant"/>"#
+    );
+    assert_eq!(lines.iter().filter(|line| line.starts_with("    <error ")).count(), 24);
+    assert_eq!(lines.last(), Some(&"</checkstyle>"));
+}
+
+#[test]
+fn test_check_proj1_all_findings_junit() {
+    let output = run_scopelint("check-proj1-AllFindings", &["--format", "junit"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stderr.is_empty());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    // The mutable constant finding is a warning, which does not count as a failure.
+    assert_eq!(lines[1], r#"<testsuites name="scopelint" tests="24" failures="23">"#);
+    assert_eq!(lines[2], r#"  <testsuite name="script/Counter.s.sol" tests="4" failures="4">"#);
+    assert_eq!(lines.iter().filter(|line| line.starts_with("      <failure ")).count(), 23);
+    assert_eq!(lines.iter().filter(|line| line.starts_with("      <system-out>")).count(), 1);
+    assert_eq!(lines.last(), Some(&"</testsuites>"));
+}
+
+#[test]
+fn test_check_proj2_foundry_config() {
+    let project_path = copy_project("check-proj2-NoFindings", "foundry-config");
+    let foundry = project_path.join("foundry.toml");
+    let content =
+        fs::read_to_string(&foundry).unwrap() + "\n[scopelint.severity]\n  Src = \"warning\"\n";
+    fs::write(&foundry, content).unwrap();
+    let extra = "\ncontract Extra {\n  function noUnderscore() internal {}\n}\n";
+    let counter = project_path.join("src/Counter.sol");
+    fs::write(&counter, fs::read_to_string(&counter).unwrap() + extra).unwrap();
+
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let check = || {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // The config in `foundry.toml` applies, and `.scopelint.toml` takes precedence over it.
+    let output = check();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8(output.stderr).unwrap().starts_with("warning: Invalid src method"));
+    fs::write(project_path.join(".scopelint.toml"), "[severity]\nSrc = \"error\"\n").unwrap();
+    assert!(!check().status.success());
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_gitignore() {
+    let project_path = copy_project("check-proj2-NoFindings", "gitignore");
+    fs::create_dir(project_path.join("src/generated")).unwrap();
+    let generated = "contract Generated {\n  function noUnderscore() internal {}\n}\n";
+    fs::write(project_path.join("src/generated/Generated.sol"), generated).unwrap();
+    fs::write(project_path.join(".gitignore"), "generated/\n").unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let check = |args: &[&str]| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .args(args)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Like in git, `.gitignore` only applies inside a git repository.
+    let output = check(&[]);
+    assert_eq!(output.status.code(), Some(1));
+    let status = Command::new("git")
+        .current_dir(&project_path)
+        .args(["init", "--quiet"])
+        .status()
+        .expect("Failed to execute git");
+    assert!(status.success());
+
+    // Ignored files are skipped, unless `--no-ignore` is given.
+    let output = check(&[]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.ends_with("scopelint: 0 issues across 5 files\n"), "{stderr}");
+    let output = check(&["--no-ignore"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Invalid src method name in ./src/generated/Generated.sol:"));
+
+    // Ignored files passed explicitly are still checked.
+    let output = check(&["src/generated/Generated.sol"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_since() {
+    let project_path = copy_project("check-proj2-NoFindings", "since");
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let check = |since: &str| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache", "--since", since])
+            .output()
+            .expect("Failed to execute command")
+    };
+    let git = |args: &[&str]| {
+        let config = ["-c", "user.name=scopelint", "-c", "user.email=scopelint@example.com"];
+        let status = Command::new("git")
+            .current_dir(&project_path)
+            .args(config)
+            .args(args)
+            .output()
+            .expect("Failed to execute git")
+            .status;
+        assert!(status.success(), "git {args:?}");
+    };
+
+    // Outside of a git repository, all files are checked.
+    let output = check("HEAD");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("warning: Not in a git repository"), "{stderr}");
+    assert!(stderr.ends_with("scopelint: 0 issues across 5 files\n"), "{stderr}");
+
+    // Changed, renamed, and untracked files are checked, and deleted files are skipped.
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "--no-gpg-sign", "-m", "Initial commit"]);
+    let extra = "\ncontract Extra {\n  function noUnderscore() internal {}\n}\n";
+    let counter = project_path.join("src/Counter.sol");
+    fs::write(&counter, fs::read_to_string(&counter).unwrap() + extra).unwrap();
+    git(&["mv", "src/Library.sol", "src/Renamed.sol"]);
+    fs::copy(project_path.join("script/ScriptHelpers.sol"), project_path.join("script/New.sol"))
+        .unwrap();
+    git(&["rm", "--quiet", "script/ScriptHelpers.sol"]);
+    let output = check("HEAD");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Invalid src method name in ./src/Counter.sol:"), "{stderr}");
+    assert!(stderr.ends_with("scopelint: 1 issues across 3 files\n"), "{stderr}");
+
+    let output = check("unknown-ref");
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Cannot list the files changed since `unknown-ref`"), "{stderr}");
+
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_warnings() {
+    let project_path = copy_project("check-proj2-NoFindings", "warnings");
+    fs::write(project_path.join(".scopelint.toml"), "[severity]\nSrc = \"warning\"\n").unwrap();
+    let extra = "\ncontract Extra {\n  function noUnderscore() internal {}\n}\n";
+    let counter = project_path.join("src/Counter.sol");
+    fs::write(&counter, fs::read_to_string(&counter).unwrap() + extra).unwrap();
+
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let output = Command::new(&binary_path)
+        .current_dir(&project_path)
+        .args(["check", "--no-cache"])
+        .output()
+        .expect("Failed to execute command");
+
+    // Warnings are reported, but do not fail the check.
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert!(lines[0].starts_with("warning: Invalid src method name in ./src/Counter.sol:"));
+    assert!(lines[0].ends_with(": noUnderscore()"));
+    assert_eq!(
+        lines[1..],
+        [
+            "summary: Src: 1",
+            "warning: Convention checks found warnings, see details above",
+            "scopelint: 1 issues across 5 files",
+        ]
+    );
+
+    // With `--max-warnings`, the check fails only above the limit.
+    let run = |max: &str| {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache", "--max-warnings", max])
+            .output()
+            .expect("Failed to execute command")
+    };
+    assert!(run("1").status.success());
+    let output = run("0");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = "error: Convention checks found 1 warning, more than the maximum of 0, see \
+                    details above";
+    assert!(stderr.contains(expected), "{stderr}");
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_skip_dirs() {
+    let project_path = copy_project("check-proj2-NoFindings", "skip-dirs");
+    let dependency = project_path.join("src/node_modules/dep");
+    fs::create_dir_all(&dependency).unwrap();
+    fs::write(dependency.join("Dep.sol"), "contract Dep { uint256 constant bad = 1; }\n").unwrap();
+
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let run = || {
+        Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    // Dependency directories are skipped by default.
+    assert!(run().status.success());
+
+    // Configuring `skip_dirs` replaces the defaults.
+    fs::write(project_path.join(".scopelint.toml"), "skip_dirs = []\n").unwrap();
+    let stderr = String::from_utf8(run().stderr).unwrap();
+    assert!(stderr.contains("./src/node_modules/dep/Dep.sol:1:33: bad"), "{stderr}");
+    fs::remove_dir_all(project_path).unwrap();
+}
+
+#[test]
+fn test_check_proj2_shadowing() {
+    let project_path = copy_project("check-proj2-NoFindings", "shadowing");
+    fs::write(project_path.join(".scopelint.toml"), "shadowing = true\n").unwrap();
+    fs::write(project_path.join("src/Base.sol"), "contract Base {\n  uint256 internal count;\n}\n")
+        .unwrap();
+    let child = "import \"./Base.sol\";\ncontract Child is Base {\n  uint256 internal count;\n}\n";
+    fs::write(project_path.join("src/Child.sol"), child).unwrap();
+    fs::write(project_path.join("src/Unparsable.sol"), "contract Unparsable {\n").unwrap();
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
+    let check = |args: &[&str]| {
+        let output = Command::new(&binary_path)
+            .current_dir(&project_path)
+            .args(["check", "--no-cache"])
+            .args(args)
+            .output()
+            .expect("Failed to execute command");
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    // Base contracts are resolved in the other project files, also when only some are checked,
+    // and files that cannot be parsed are only reported once.
+    let expected = "Shadowed state variable in ./src/Child.sol:3:20: count shadows the state \
+                    variable inherited from `Base`";
+    let stderr = check(&[]);
+    assert!(stderr.contains(expected), "{stderr}");
+    assert_eq!(stderr.matches("./src/Unparsable.sol").count(), 1, "{stderr}");
+    let stderr = check(&["src/Child.sol"]);
+    assert!(stderr.contains(expected), "{stderr}");
+    assert!(stderr.ends_with("scopelint: 1 issues across 1 files\n"), "{stderr}");
+
+    fs::remove_dir_all(project_path).unwrap();
+}
@@ -2,14 +2,17 @@
 /// Therefore, most testing is done by running the binary against a sample forge project and
 /// checking the output.
 use std::{
-    env,
+    env, fs,
+    path::Path,
     process::{Command, Output},
 };
 
 fn run_scopelint(test_folder: &str) -> Output {
-    let cwd = env::current_dir().unwrap();
-    let project_path = cwd.join("tests").join(test_folder);
-    let binary_path = cwd.join("target/debug/scopelint");
+    run_scopelint_in(&env::current_dir().unwrap().join("tests").join(test_folder))
+}
+
+fn run_scopelint_in(project_path: &Path) -> Output {
+    let binary_path = env::current_dir().unwrap().join("target/debug/scopelint");
 
     Command::new(binary_path)
         .current_dir(project_path)
@@ -18,11 +21,7 @@ fn run_scopelint(test_folder: &str) -> Output {
         .expect("Failed to execute command")
 }
 
-#[test]
-fn test_spec_proj1() {
-    let output = run_scopelint("spec-proj1");
-    let stdout = String::from_utf8(output.stdout).unwrap();
-    let expected_spec = r#"
+const EXPECTED_SPEC_PROJ1: &str = r#"
 Contract Specification: ERC20
 ├── constructor
 │   ├──  Stored Name Matches Constructor Input
@@ -48,5 +47,40 @@ Contract Specification: ERC20
 ├── _mint
 └── _burn
 "#;
-    assert_eq!(stdout, expected_spec);
+
+#[test]
+fn test_spec_proj1() {
+    let output = run_scopelint("spec-proj1");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, EXPECTED_SPEC_PROJ1);
+}
+
+#[test]
+fn test_spec_proj1_foundry_paths() {
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    // Move the source and test contracts to the directories configured in `foundry.toml`.
+    let project_path = env::temp_dir().join(format!("scopelint-spec-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&project_path);
+    let source = env::current_dir().unwrap().join("tests/spec-proj1");
+    copy_dir(&source.join("src"), &project_path.join("contracts"));
+    copy_dir(&source.join("test"), &project_path.join("tests"));
+    let foundry_toml = "[profile.default]\nsrc = \"contracts\"\ntest = \"tests\"\n";
+    fs::write(project_path.join("foundry.toml"), foundry_toml).unwrap();
+
+    let output = run_scopelint_in(&project_path);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, EXPECTED_SPEC_PROJ1);
+    fs::remove_dir_all(project_path).unwrap();
 }